@@ -0,0 +1,206 @@
+import crypto from 'crypto';
+import fs from 'fs';
+import path from 'path';
+import { fileURLToPath } from 'url';
+import { BaselineEntry, ResultItem, ScanResults, Violation } from './types';
+
+/**
+ * Current baseline file format version
+ */
+export const BASELINE_VERSION = 1;
+
+/**
+ * Contents of a baseline file
+ */
+export interface BaselineFile {
+  version: number;
+  /** When the baseline was written */
+  createdAt: string;
+  /** Recorded violations */
+  entries: BaselineEntry[];
+}
+
+/**
+ * Scan results for one page, used when writing a baseline
+ */
+export interface BaselinePage {
+  /** Page identifier, see baselinePage() */
+  page: string;
+  results: ScanResults;
+}
+
+/**
+ * Normalize an HTML snippet so whitespace and truncation changes do not alter fingerprints
+ * @param snippet HTML snippet
+ * @returns Normalized snippet
+ */
+export function normalizeSnippet(snippet = ''): string {
+  return snippet
+    .replace(/\.\.\.$/, '')
+    .replace(/>\s+</g, '><')
+    .replace(/\s+/g, ' ')
+    .trim();
+}
+
+/**
+ * Build the selector used to fingerprint a result
+ * @param item Result item
+ * @returns Selector such as "img#logo.hero.wide"
+ */
+export function resultSelector(item: ResultItem): string {
+  const element = item.element;
+  if (!element) return '';
+
+  const classes = (element.className || '').trim().split(/\s+/).filter(Boolean).sort();
+  return (element.tagName || '').toLowerCase() +
+    (element.id ? `#${element.id}` : '') +
+    classes.map(name => `.${name}`).join('');
+}
+
+/**
+ * Compute the stable fingerprint of a result
+ * @param item Result item
+ * @returns Hex digest of rule, selector and normalized snippet
+ */
+export function fingerprint(item: ResultItem): string {
+  return crypto
+    .createHash('sha1')
+    .update([item.rule, resultSelector(item), normalizeSnippet(item.snippet)].join('\n'))
+    .digest('hex');
+}
+
+/**
+ * Identify a page within a baseline. Local files are stored relative to the
+ * baseline file so the baseline can be committed and used on other machines.
+ * @param documentUrl URL of the scanned document
+ * @param baselinePath Path of the baseline file
+ * @returns Page identifier
+ */
+export function baselinePage(documentUrl: string, baselinePath: string): string {
+  const url = new URL(documentUrl);
+  if (url.protocol !== 'file:') return url.href;
+
+  const relative = path.relative(path.dirname(path.resolve(baselinePath)), fileURLToPath(url));
+  return relative.split(path.sep).join('/');
+}
+
+/**
+ * Create baseline contents from scan results
+ * @param pages Scan results per page
+ * @returns Baseline file contents
+ */
+export function createBaseline(pages: BaselinePage[]): BaselineFile {
+  const entries = pages.reduce<BaselineEntry[]>((all, { page, results }) => all.concat(
+    results.violations.map(violation => ({
+      page,
+      rule: violation.rule,
+      selector: resultSelector(violation),
+      snippet: normalizeSnippet(violation.snippet),
+      fingerprint: fingerprint(violation),
+    }))
+  ), []);
+
+  return {
+    version: BASELINE_VERSION,
+    createdAt: new Date().toISOString(),
+    entries,
+  };
+}
+
+/**
+ * Write a baseline file
+ * @param filePath Baseline file path
+ * @param pages Scan results per page
+ * @returns The written baseline
+ */
+export function writeBaseline(filePath: string, pages: BaselinePage[]): BaselineFile {
+  const baseline = createBaseline(pages);
+  fs.writeFileSync(filePath, `${JSON.stringify(baseline, null, 2)}\n`);
+  return baseline;
+}
+
+/**
+ * Read a baseline file
+ * @param filePath Baseline file path
+ * @returns Baseline contents
+ */
+export function loadBaseline(filePath: string): BaselineFile {
+  if (!fs.existsSync(filePath)) {
+    throw new Error(`Baseline file not found: ${filePath}`);
+  }
+
+  let baseline: BaselineFile;
+  try {
+    baseline = JSON.parse(fs.readFileSync(filePath, 'utf8'));
+  } catch (error) {
+    throw new Error(`Baseline file ${filePath} is not valid JSON: ${(error as Error).message}`);
+  }
+
+  if (!baseline || baseline.version !== BASELINE_VERSION || !Array.isArray(baseline.entries)) {
+    throw new Error(`Baseline file ${filePath} is not a version ${BASELINE_VERSION} wcag-scanner baseline`);
+  }
+
+  return baseline;
+}
+
+/**
+ * Mark each violation as new or existing and list baseline entries that were fixed.
+ * Identical violations are matched one-to-one, so a duplicated issue counts as new.
+ * @param results Scan results for one page
+ * @param baseline Baseline contents
+ * @param page Page identifier of the scanned document
+ * @param file Baseline file path, for the summary
+ * @returns Scan results with the baseline comparison attached
+ */
+export function compareWithBaseline(
+  results: ScanResults,
+  baseline: BaselineFile,
+  page: string,
+  file = '',
+): ScanResults {
+  const remaining = new Map<string, BaselineEntry[]>();
+  for (const entry of baseline.entries) {
+    if (entry.page !== page) continue;
+    const matches = remaining.get(entry.fingerprint) || [];
+    matches.push(entry);
+    remaining.set(entry.fingerprint, matches);
+  }
+
+  let existing = 0;
+  const violations = results.violations.map<Violation>(violation => {
+    const matches = remaining.get(fingerprint(violation));
+    if (matches && matches.length > 0) {
+      matches.shift();
+      existing++;
+      return { ...violation, baselineStatus: 'existing' };
+    }
+    return { ...violation, baselineStatus: 'new' };
+  });
+
+  const fixed: BaselineEntry[] = [];
+  remaining.forEach(entries => fixed.push(...entries));
+
+  return {
+    ...results,
+    violations,
+    baseline: {
+      file,
+      new: violations.length - existing,
+      existing,
+      fixed,
+    },
+  };
+}
+
+/**
+ * Drop violations that are already recorded in the baseline
+ * @param results Scan results compared against a baseline
+ * @returns Scan results containing only new violations
+ */
+export function filterNewViolations(results: ScanResults): ScanResults {
+  if (!results.baseline) return results;
+  return {
+    ...results,
+    violations: results.violations.filter(violation => violation.baselineStatus !== 'existing'),
+  };
+}
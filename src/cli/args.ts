@@ -0,0 +1,329 @@
+import { ImpactLevel, RulePreset, ScannerOptions, Standard } from '../types';
+import { ReporterFormat } from '../reporters';
+import { FetchOptions } from '../fetcher';
+import { CrawlOptions, DEFAULT_MAX_DEPTH, DEFAULT_MAX_PAGES } from '../crawler';
+
+/**
+ * Impact threshold used to decide the CLI exit code
+ */
+export type FailOnLevel = ImpactLevel | 'none';
+
+/**
+ * Parsed command-line options
+ */
+export interface CliOptions {
+  /** Files, directories or glob patterns to scan */
+  inputs: string[];
+  /** Options forwarded to the scanner and reporters */
+  scanner: ScannerOptions;
+  /** Request options used when scanning URLs */
+  fetch: FetchOptions;
+  /** Crawl same-origin links from URL inputs instead of scanning only those pages */
+  crawl: boolean;
+  /** Page and depth limits used when crawling */
+  crawlLimits: Pick<CrawlOptions, 'maxPages' | 'maxDepth'>;
+  /** Report format */
+  format: ReporterFormat;
+  /** Write the report to this file instead of stdout */
+  output?: string;
+  /** Lowest violation impact that makes the process exit non-zero */
+  failOn: FailOnLevel;
+  /** Write the scan results to the baseline file instead of comparing against it */
+  updateBaseline: boolean;
+  /** Print usage and exit */
+  help: boolean;
+  /** Print the package version and exit */
+  version: boolean;
+}
+
+/**
+ * Thrown when the command line cannot be parsed
+ */
+export class CliUsageError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'CliUsageError';
+  }
+}
+
+export const LEVELS = ['A', 'AA', 'AAA'] as const;
+export const PRESETS: RulePreset[] = ['fast', 'full'];
+export const STANDARDS: Standard[] = ['wcag20', 'wcag21', 'wcag22', 'en301549', 'section508'];
+export const FORMATS: ReporterFormat[] = ['json', 'console', 'html', 'sarif', 'junit', 'markdown'];
+export const FAIL_ON_LEVELS: FailOnLevel[] = ['critical', 'serious', 'moderate', 'minor', 'none'];
+export const DEFAULT_BASELINE_FILE = 'wcag-baseline.json';
+
+export const USAGE = `Usage: wcag-scanner [options] <file|directory|glob|url...>
+
+Scan HTML files and web pages for WCAG accessibility issues.
+
+Options:
+  -l, --level <level>      WCAG level to check against: ${LEVELS.join(', ')} (default: AA)
+      --standard <standard>
+                           Standard to check conformance with: ${STANDARDS.join(', ')}
+                           (default: wcag21)
+      --no-best-practices  Report only rules that a success criterion requires
+  -p, --preset <preset>    Built-in rule preset: ${PRESETS.join(', ')} (default: fast)
+  -r, --rules <rules>      Comma-separated rule modules to run (overrides --preset)
+      --rule-pack <package>
+                           Load custom rules from an npm package or path (repeatable)
+      --base-url <url>     Base URL used to resolve relative paths
+  -v, --verbose            Include passes and code snippets in the report
+      --load-stylesheets   Load linked stylesheets from disk before scanning files
+      --run-scripts        Execute page scripts before scanning (client-rendered pages)
+      --wait-for <css>     With --run-scripts, scan once this selector matches
+      --settle-timeout <ms>
+                           Maximum time to wait for scripts to settle or stylesheets
+                           to load (default: 10000)
+      --ignore-script-errors
+                           Scan even when page scripts throw
+  -f, --format <format>    Report format: ${FORMATS.join(', ')} (default: console)
+  -o, --output <file>      Write the report to a file instead of stdout
+      --junit-warnings <mode>
+                           Report warnings in JUnit output as: skipped, system-out
+                           (default: system-out)
+      --markdown-max-length <chars>
+                           Truncate markdown output to this many characters
+                           (default: 65000)
+      --fail-on <impact>   Exit with code 1 when a violation of this impact or
+                           higher is found: ${FAIL_ON_LEVELS.join(', ')} (default: minor)
+      --fail-on-rule-error
+                           Exit with an error when a rule throws, instead of
+                           reporting the scan as incomplete
+  -c, --config <file>      Use this config file instead of searching for one
+      --no-config          Ignore config files
+      --baseline <file>    Mark violations as new or existing against a baseline file
+      --update-baseline    Write the current violations to the baseline file
+                           (default: ${DEFAULT_BASELINE_FILE})
+      --only-new           Report and fail only on violations not in the baseline
+  -H, --header <header>    Request header for URL inputs, as "Name: value" (repeatable)
+      --cookie <cookie>    Cookie for URL inputs, as "name=value" (repeatable)
+      --timeout <ms>       Timeout for fetching each URL (default: 30000)
+      --crawl              Follow same-origin links from URL inputs and scan
+                           every page found (honors robots.txt)
+      --max-pages <n>      Maximum pages to scan per crawl (default: ${DEFAULT_MAX_PAGES})
+      --max-depth <n>      Maximum link depth to crawl (default: ${DEFAULT_MAX_DEPTH})
+  -h, --help               Show this help
+      --version            Show the package version
+
+Directories are searched recursively for .html and .htm files.
+Inputs starting with http:// or https:// are fetched and scanned.
+With --crawl, a URL ending in .xml is read as a sitemap.
+`;
+
+const ALIASES: Record<string, string> = {
+  '-l': '--level',
+  '-p': '--preset',
+  '-r': '--rules',
+  '-v': '--verbose',
+  '-f': '--format',
+  '-o': '--output',
+  '-h': '--help',
+  '-c': '--config',
+  '-H': '--header',
+};
+
+const BOOLEAN_FLAGS = new Set(['--verbose', '--help', '--version', '--no-config', '--update-baseline', '--only-new', '--crawl', '--load-stylesheets', '--run-scripts', '--ignore-script-errors', '--no-best-practices', '--fail-on-rule-error']);
+
+/**
+ * Parse command-line arguments
+ * @param argv Arguments without the node executable and script path
+ * @returns Parsed CLI options
+ */
+export function parseArgs(argv: string[]): CliOptions {
+  const cli: CliOptions = {
+    inputs: [],
+    scanner: {},
+    fetch: {},
+    crawl: false,
+    crawlLimits: {},
+    format: 'console',
+    failOn: 'minor',
+    updateBaseline: false,
+    help: false,
+    version: false,
+  };
+
+  for (let i = 0; i < argv.length; i++) {
+    const arg = argv[i];
+
+    if (arg === '--') {
+      cli.inputs.push(...argv.slice(i + 1));
+      break;
+    }
+
+    if (!arg.startsWith('-') || arg === '-') {
+      cli.inputs.push(arg);
+      continue;
+    }
+
+    const eqIndex = arg.indexOf('=');
+    const rawFlag = eqIndex > -1 ? arg.slice(0, eqIndex) : arg;
+    const flag = ALIASES[rawFlag] || rawFlag;
+    let value: string | undefined = eqIndex > -1 ? arg.slice(eqIndex + 1) : undefined;
+
+    if (BOOLEAN_FLAGS.has(flag)) {
+      if (value !== undefined) {
+        throw new CliUsageError(`Option ${rawFlag} does not take a value`);
+      }
+    } else if (value === undefined) {
+      value = argv[++i];
+      if (value === undefined || (value.startsWith('-') && value !== '-')) {
+        throw new CliUsageError(`Option ${rawFlag} requires a value`);
+      }
+    }
+
+    switch (flag) {
+      case '--level':
+        cli.scanner.level = oneOf(rawFlag, value as string, LEVELS);
+        break;
+      case '--standard':
+        cli.scanner.standard = oneOf(rawFlag, value as string, STANDARDS);
+        break;
+      case '--no-best-practices':
+        cli.scanner.bestPractices = false;
+        break;
+      case '--preset':
+        cli.scanner.preset = oneOf(rawFlag, value as string, PRESETS);
+        break;
+      case '--rules':
+        cli.scanner.rules = [
+          ...(cli.scanner.rules || []),
+          ...splitList(value as string),
+        ];
+        break;
+      case '--rule-pack':
+        cli.scanner.rulePacks = [...(cli.scanner.rulePacks || []), value as string];
+        break;
+      case '--base-url':
+        cli.scanner.baseUrl = value;
+        break;
+      case '--verbose':
+        cli.scanner.verbose = true;
+        break;
+      case '--load-stylesheets':
+        cli.scanner.loadStylesheets = true;
+        break;
+      case '--run-scripts':
+        cli.scanner.runScripts = true;
+        break;
+      case '--wait-for':
+        cli.scanner.settle = { ...cli.scanner.settle, selector: value };
+        break;
+      case '--settle-timeout':
+        cli.scanner.settle = { ...cli.scanner.settle, timeout: integer(rawFlag, value as string, 1, 'a positive number of milliseconds') };
+        break;
+      case '--ignore-script-errors':
+        cli.scanner.ignoreScriptErrors = true;
+        break;
+      case '--format':
+        cli.format = oneOf(rawFlag, value as string, FORMATS);
+        break;
+      case '--output':
+        cli.output = value;
+        break;
+      case '--junit-warnings':
+        cli.scanner.junitWarnings = oneOf(rawFlag, value as string, ['skipped', 'system-out'] as const);
+        break;
+      case '--markdown-max-length':
+        cli.scanner.markdownMaxLength = integer(rawFlag, value as string, 1, 'a positive number of characters');
+        break;
+      case '--fail-on':
+        cli.failOn = oneOf(rawFlag, value as string, FAIL_ON_LEVELS);
+        break;
+      case '--fail-on-rule-error':
+        cli.scanner.failOnRuleError = true;
+        break;
+      case '--config':
+        cli.scanner.config = value;
+        break;
+      case '--no-config':
+        cli.scanner.config = false;
+        break;
+      case '--baseline':
+        cli.scanner.baseline = value;
+        break;
+      case '--update-baseline':
+        cli.updateBaseline = true;
+        break;
+      case '--only-new':
+        cli.scanner.onlyNewViolations = true;
+        break;
+      case '--header': {
+        const colon = (value as string).indexOf(':');
+        if (colon < 1) {
+          throw new CliUsageError(`Invalid value for ${rawFlag}: "${value}" (expected "Name: value")`);
+        }
+        cli.fetch.headers = {
+          ...cli.fetch.headers,
+          [(value as string).slice(0, colon).trim()]: (value as string).slice(colon + 1).trim(),
+        };
+        break;
+      }
+      case '--cookie':
+        cli.fetch.cookies = cli.fetch.cookies ? `${cli.fetch.cookies}; ${value}` : value;
+        break;
+      case '--timeout':
+        cli.fetch.timeout = integer(rawFlag, value as string, 1, 'a positive number of milliseconds');
+        break;
+      case '--crawl':
+        cli.crawl = true;
+        break;
+      case '--max-pages':
+        cli.crawlLimits.maxPages = integer(rawFlag, value as string, 1, 'a positive number');
+        break;
+      case '--max-depth':
+        cli.crawlLimits.maxDepth = integer(rawFlag, value as string, 0, 'zero or a positive number');
+        break;
+      case '--help':
+        cli.help = true;
+        break;
+      case '--version':
+        cli.version = true;
+        break;
+      default:
+        throw new CliUsageError(`Unknown option: ${rawFlag}`);
+    }
+  }
+
+  return cli;
+}
+
+/**
+ * Split a comma-separated option value
+ * @param value Raw option value
+ * @returns Trimmed, non-empty entries
+ */
+function splitList(value: string): string[] {
+  return value.split(',').map(item => item.trim()).filter(Boolean);
+}
+
+/**
+ * Validate that an option value is one of the allowed choices
+ * @param flag Flag name used in the error message
+ * @param value Raw option value
+ * @param choices Allowed values
+ * @returns The value, narrowed to the allowed type
+ */
+function oneOf<T extends string>(flag: string, value: string, choices: readonly T[]): T {
+  if (!(choices as readonly string[]).includes(value)) {
+    throw new CliUsageError(`Invalid value for ${flag}: "${value}" (expected one of ${choices.join(', ')})`);
+  }
+  return value as T;
+}
+
+/**
+ * Parse an integer option value
+ * @param flag Flag name used in the error message
+ * @param value Raw option value
+ * @param min Smallest allowed value
+ * @param expected Description of valid values for the error message
+ * @returns The parsed integer
+ */
+function integer(flag: string, value: string, min: number, expected: string): number {
+  const parsed = Number(value);
+  if (!Number.isInteger(parsed) || parsed < min) {
+    throw new CliUsageError(`Invalid value for ${flag}: "${value}" (expected ${expected})`);
+  }
+  return parsed;
+}
@@ -0,0 +1,96 @@
+import fs from 'fs';
+import path from 'path';
+import { globToRegExp } from '../utils/glob';
+import { CliUsageError } from './args';
+
+export { globToRegExp };
+
+const HTML_EXTENSIONS = ['.html', '.htm'];
+const IGNORED_DIRECTORIES = new Set(['node_modules', '.git']);
+const GLOB_PATTERN = /[*?[\]{}]/;
+
+/**
+ * Expand CLI inputs (files, directories and glob patterns) into a list of files
+ * @param inputs Raw inputs from the command line
+ * @param cwd Directory relative inputs are resolved against
+ * @returns Absolute file paths, de-duplicated, in input order
+ */
+export function expandInputs(inputs: string[], cwd: string = process.cwd()): string[] {
+  const files = new Set<string>();
+
+  for (const input of inputs) {
+    if (isGlob(input)) {
+      matchGlob(input, cwd).forEach(file => files.add(file));
+      continue;
+    }
+
+    const resolved = path.resolve(cwd, input);
+    if (!fs.existsSync(resolved)) {
+      throw new CliUsageError(`No such file or directory: ${input}`);
+    }
+
+    if (fs.statSync(resolved).isDirectory()) {
+      walk(resolved)
+        .filter(file => HTML_EXTENSIONS.includes(path.extname(file).toLowerCase()))
+        .forEach(file => files.add(file));
+    } else {
+      files.add(resolved);
+    }
+  }
+
+  return [...files];
+}
+
+/**
+ * Check whether an input contains glob syntax
+ * @param input CLI input
+ */
+export function isGlob(input: string): boolean {
+  return GLOB_PATTERN.test(input);
+}
+
+/**
+ * Find files matching a glob pattern
+ * @param pattern Glob pattern, absolute or relative to cwd
+ * @param cwd Base directory
+ * @returns Sorted absolute paths of matching files
+ */
+function matchGlob(pattern: string, cwd: string): string[] {
+  const absolute = toPosix(path.resolve(cwd, pattern));
+  const segments = absolute.split('/');
+  const firstGlob = segments.findIndex(segment => isGlob(segment));
+  const base = segments.slice(0, firstGlob).join('/') || '/';
+
+  if (!fs.existsSync(base) || !fs.statSync(base).isDirectory()) {
+    return [];
+  }
+
+  const matcher = globToRegExp(absolute);
+  return walk(path.resolve(base)).filter(file => matcher.test(toPosix(file)));
+}
+
+/**
+ * Recursively list files below a directory, skipping dependency and VCS folders
+ * @param dir Directory to walk
+ * @returns Sorted absolute file paths
+ */
+function walk(dir: string): string[] {
+  const files: string[] = [];
+
+  for (const entry of fs.readdirSync(dir, { withFileTypes: true })) {
+    const fullPath = path.join(dir, entry.name);
+    if (entry.isDirectory()) {
+      if (!IGNORED_DIRECTORIES.has(entry.name)) {
+        files.push(...walk(fullPath));
+      }
+    } else if (entry.isFile()) {
+      files.push(fullPath);
+    }
+  }
+
+  return files.sort();
+}
+
+function toPosix(filePath: string): string {
+  return filePath.split(path.sep).join('/');
+}
@@ -0,0 +1,260 @@
+#!/usr/bin/env node
+import fs from 'fs';
+import path from 'path';
+import { pathToFileURL } from 'url';
+import { scanFile, scanPage, formatReport, saveReport, ReporterFormat } from '../index';
+import { fetchPage, isHttpUrl } from '../fetcher';
+import { crawlSite, summarizeSite } from '../crawler';
+import { resolveOptions } from '../config';
+import { htmlReporter, junitReporter, markdownReporter, sarifReporter } from '../reporters';
+import { baselinePage, filterNewViolations, writeBaseline } from '../baseline';
+import { ScanResults, ImpactLevel, ScannerOptions } from '../types';
+import { CliOptions, CliUsageError, DEFAULT_BASELINE_FILE, FailOnLevel, parseArgs, USAGE } from './args';
+import { expandInputs } from './files';
+
+export { parseArgs, CliUsageError, USAGE } from './args';
+export type { CliOptions, FailOnLevel } from './args';
+export { expandInputs, globToRegExp, isGlob } from './files';
+
+/** No violations at or above the threshold */
+export const EXIT_OK = 0;
+/** Violations at or above the threshold were found */
+export const EXIT_VIOLATIONS = 1;
+/** Invalid usage or the scan could not be completed */
+export const EXIT_ERROR = 2;
+
+const IMPACT_ORDER: ImpactLevel[] = ['critical', 'serious', 'moderate', 'minor'];
+
+/**
+ * Output streams used by the CLI
+ */
+export interface CliIO {
+  stdout: (text: string) => void;
+  stderr: (text: string) => void;
+}
+
+interface FileScan {
+  /** Input as shown in reports: a path relative to cwd, or a URL */
+  file: string;
+  /** URL of the scanned document */
+  url: string;
+  results: ScanResults;
+}
+
+const processIO: CliIO = {
+  stdout: text => { process.stdout.write(text); },
+  stderr: text => { process.stderr.write(text); },
+};
+
+/**
+ * Run the wcag-scanner command line
+ * @param argv Arguments without the node executable and script path
+ * @param io Output streams
+ * @param cwd Directory relative paths are resolved against
+ * @returns Process exit code
+ */
+export async function runCli(argv: string[], io: CliIO = processIO, cwd: string = process.cwd()): Promise<number> {
+  let cli: CliOptions;
+  try {
+    cli = parseArgs(argv);
+  } catch (error) {
+    if (error instanceof CliUsageError) {
+      io.stderr(`wcag-scanner: ${error.message}\n\n${USAGE}`);
+      return EXIT_ERROR;
+    }
+    throw error;
+  }
+
+  if (cli.help) {
+    io.stdout(USAGE);
+    return EXIT_OK;
+  }
+
+  if (cli.version) {
+    io.stdout(`${getVersion()}\n`);
+    return EXIT_OK;
+  }
+
+  if (cli.inputs.length === 0) {
+    io.stderr(`wcag-scanner: no input files given\n\n${USAGE}`);
+    return EXIT_ERROR;
+  }
+
+  try {
+    const urls = cli.inputs.filter(isHttpUrl);
+    const files = expandInputs(cli.inputs.filter(input => !isHttpUrl(input)), cwd);
+    if (files.length === 0 && urls.length === 0) {
+      io.stderr('wcag-scanner: no files matched the given inputs\n');
+      return EXIT_ERROR;
+    }
+
+    // Resolve the config file once; the scanner does not need to search again
+    const options: ScannerOptions = { ...resolveOptions(cli.scanner, cwd), config: false };
+    if (cli.updateBaseline) {
+      options.baseline = path.resolve(cwd, options.baseline || DEFAULT_BASELINE_FILE);
+    } else if (options.baseline) {
+      options.baseline = path.resolve(cwd, options.baseline);
+    }
+    if (options.rulePacks) {
+      // Relative pack paths are relative to cwd, like inputs
+      options.rulePacks = options.rulePacks.map(pack => (pack.startsWith('.') ? path.resolve(cwd, pack) : pack));
+    }
+
+    // When updating, scan without comparing so every violation is recorded
+    const scanOptions: ScannerOptions = cli.updateBaseline ? { ...options, baseline: undefined } : options;
+
+    const scans: FileScan[] = [];
+    const failures: Record<string, string> = {};
+    for (const file of files) {
+      scans.push({
+        file: path.relative(cwd, file),
+        url: options.baseUrl || pathToFileURL(path.resolve(file)).href,
+        results: await scanFile(file, scanOptions),
+      });
+    }
+    for (const url of urls) {
+      if (cli.crawl) {
+        const site = await crawlSite(url, { ...scanOptions, ...cli.fetch, ...cli.crawlLimits });
+        if (site.summary.pages === 0) {
+          const reason = site.errors[url] || (site.skipped || {})[url]
+            || Object.keys(site.errors).map(page => site.errors[page])[0];
+          throw new Error(`No pages could be scanned from ${url}${reason ? `: ${reason}` : ''}`);
+        }
+        Object.keys(site.pages).forEach(page => {
+          scans.push({ file: page, url: page, results: site.pages[page] });
+        });
+        Object.keys(site.errors).forEach(page => {
+          failures[page] = site.errors[page];
+          io.stderr(`wcag-scanner: skipped ${page}: ${site.errors[page]}\n`);
+        });
+        continue;
+      }
+
+      const page = await fetchPage(url, cli.fetch);
+      scans.push({
+        file: url,
+        url: options.baseUrl || page.url,
+        results: await scanPage(page, { ...scanOptions, ...cli.fetch }),
+      });
+    }
+
+    if (cli.updateBaseline && options.baseline) {
+      const baselinePath = options.baseline;
+      const baseline = writeBaseline(baselinePath, scans.map(scan => ({
+        page: baselinePage(scan.url, baselinePath),
+        results: scan.results,
+      })));
+      io.stderr(`Baseline written to ${baselinePath} (${baseline.entries.length} violations)\n`);
+      return EXIT_OK;
+    }
+
+    const report = renderReport(scans, cli.format, options, cwd, failures);
+    if (cli.output) {
+      const outputPath = path.resolve(cwd, cli.output);
+      saveReport(report, outputPath);
+      io.stderr(`Report written to ${outputPath}\n`);
+    } else {
+      io.stdout(report.endsWith('\n') ? report : `${report}\n`);
+    }
+
+    const failing = scans.some(scan => exceedsThreshold(reportedResults(scan.results, options), cli.failOn));
+    return failing ? EXIT_VIOLATIONS : EXIT_OK;
+  } catch (error) {
+    const message = error instanceof Error ? error.message : String(error);
+    io.stderr(`wcag-scanner: ${message}\n`);
+    return EXIT_ERROR;
+  }
+}
+
+/**
+ * Check whether any violation meets the fail-on impact threshold
+ * @param results Scan results
+ * @param failOn Lowest impact that should fail the run
+ */
+export function exceedsThreshold(results: ScanResults, failOn: FailOnLevel): boolean {
+  if (failOn === 'none') return false;
+
+  const threshold = IMPACT_ORDER.indexOf(failOn);
+  return results.violations.some(violation => {
+    const rank = IMPACT_ORDER.indexOf(violation.impact || 'minor');
+    return rank !== -1 && rank <= threshold;
+  });
+}
+
+/**
+ * Render the report for one or more scanned files
+ * @param scans Per-file scan results
+ * @param format Report format
+ * @param options Resolved scanner options
+ * @param cwd Directory file locations are reported relative to
+ * @param failures Errors for crawled pages that could not be scanned
+ * @returns Report string
+ */
+function renderReport(
+  scans: FileScan[],
+  format: ReporterFormat,
+  options: ScannerOptions,
+  cwd: string,
+  failures: Record<string, string> = {}
+): string {
+  if (format === 'sarif') {
+    // One log for every page, with file locations relative to cwd
+    return sarifReporter.formatAll(scans.map(scan => reportedResults(scan.results, options)), options, cwd);
+  }
+
+  if (format === 'junit') {
+    // One test suite per page; pages that could not be scanned are not test results
+    return junitReporter.formatAll(scans.map(scan => ({ name: scan.file, results: reportedResults(scan.results, options) })), options);
+  }
+
+  if (format === 'markdown') {
+    // One comment-sized document for every page, sharing the size budget
+    return markdownReporter.formatAll(scans.map(scan => ({ name: scan.file, results: reportedResults(scan.results, options) })), options);
+  }
+
+  if (scans.length === 1 && Object.keys(failures).length === 0) {
+    return formatReport(scans[0].results, format, options);
+  }
+
+  switch (format) {
+    case 'json':
+      return JSON.stringify(scans.map(scan => ({
+        file: scan.file,
+        ...JSON.parse(formatReport(scan.results, 'json', options)),
+      })), null, 2);
+    case 'html': {
+      // A single HTML document covering every scanned file, with a site-wide rollup
+      const pages: Record<string, ScanResults> = {};
+      scans.forEach(scan => {
+        pages[scan.file] = reportedResults(scan.results, options);
+      });
+      return htmlReporter.formatSite(summarizeSite(pages, failures), options);
+    }
+    default:
+      return scans
+        .map(scan => `\n${scan.file}\n${formatReport(scan.results, format, options)}`)
+        .join('\n');
+  }
+}
+
+/**
+ * Leave out baseline violations when only new ones are reported
+ */
+function reportedResults(results: ScanResults, options: ScannerOptions): ScanResults {
+  return options.onlyNewViolations ? filterNewViolations(results) : results;
+}
+
+function getVersion(): string {
+  try {
+    const pkg = JSON.parse(fs.readFileSync(path.join(__dirname, '..', '..', 'package.json'), 'utf8'));
+    return pkg.version || 'unknown';
+  } catch {
+    return 'unknown';
+  }
+}
+
+if (require.main === module) {
+  void runCli(process.argv.slice(2)).then(code => {
+    process.exitCode = code;
+  });
+}
@@ -0,0 +1,130 @@
+import fs from 'fs';
+import path from 'path';
+import { ScannerOptions } from '../types';
+import { ConfigError, mergeOptions, validateConfig } from './validate';
+
+export { ConfigError, OPTION_VALIDATORS, mergeOptions, validateConfig } from './validate';
+
+/**
+ * Config file names, in lookup order within a directory
+ */
+export const CONFIG_FILE_NAMES = ['.wcagscannerrc', 'wcag-scanner.config.js', 'wcag-scanner.config.json'];
+
+/**
+ * package.json key holding the scanner configuration
+ */
+export const PACKAGE_JSON_KEY = 'wcagScanner';
+
+/**
+ * Options for loading configuration
+ */
+export interface LoadConfigOptions {
+  /** Directory to start searching from */
+  cwd?: string;
+  /** Explicit config file path, or false to skip loading configuration */
+  configPath?: string | false;
+}
+
+const discoveryCache = new Map<string, ScannerOptions>();
+
+/**
+ * Find the nearest configuration file, searching upwards from a directory.
+ * The search stops at the first directory containing a package.json.
+ * @param cwd Directory to start from
+ * @returns Path to the config file (or package.json holding the config key), or null
+ */
+export function findConfigFile(cwd: string = process.cwd()): string | null {
+  let dir = path.resolve(cwd);
+
+  while (true) {
+    for (const name of CONFIG_FILE_NAMES) {
+      const candidate = path.join(dir, name);
+      if (fs.existsSync(candidate)) return candidate;
+    }
+
+    const packageJson = path.join(dir, 'package.json');
+    if (fs.existsSync(packageJson)) {
+      const pkg = readJson(packageJson);
+      return pkg && typeof pkg === 'object' && PACKAGE_JSON_KEY in pkg ? packageJson : null;
+    }
+
+    const parent = path.dirname(dir);
+    if (parent === dir) return null;
+    dir = parent;
+  }
+}
+
+/**
+ * Read and validate a configuration file
+ * @param filePath Path to a config file or package.json
+ * @returns Validated scanner options
+ */
+export function readConfigFile(filePath: string): ScannerOptions {
+  const resolved = path.resolve(filePath);
+  if (!fs.existsSync(resolved)) {
+    throw new ConfigError(resolved, ['file does not exist']);
+  }
+
+  let raw: unknown;
+  if (path.basename(resolved) === 'package.json') {
+    raw = (readJson(resolved) as Record<string, unknown>)[PACKAGE_JSON_KEY];
+  } else if (resolved.endsWith('.js') || resolved.endsWith('.cjs')) {
+    try {
+      delete require.cache[require.resolve(resolved)];
+      const loaded = require(resolved);
+      raw = loaded && loaded.__esModule && 'default' in loaded ? loaded.default : loaded;
+    } catch (error) {
+      throw new ConfigError(resolved, [`could not be loaded: ${(error as Error).message}`]);
+    }
+  } else {
+    raw = readJson(resolved);
+  }
+
+  return validateConfig(raw, resolved);
+}
+
+/**
+ * Load the scanner configuration, either from an explicit path or by discovery
+ * @param options Where to look for configuration
+ * @returns Validated scanner options (empty when no config exists)
+ */
+export function loadConfig(options: LoadConfigOptions = {}): ScannerOptions {
+  const cwd = path.resolve(options.cwd || process.cwd());
+
+  if (options.configPath === false) return {};
+  if (options.configPath) return readConfigFile(path.resolve(cwd, options.configPath));
+
+  const cached = discoveryCache.get(cwd);
+  if (cached) return cached;
+
+  const configFile = findConfigFile(cwd);
+  const config = configFile ? readConfigFile(configFile) : {};
+  discoveryCache.set(cwd, config);
+  return config;
+}
+
+/**
+ * Merge discovered configuration under explicitly passed options
+ * @param explicit Options passed by the caller
+ * @param cwd Directory to start config discovery from
+ * @returns Options with config file values filled in
+ */
+export function resolveOptions<T extends ScannerOptions>(explicit: T, cwd?: string): T {
+  const config = loadConfig({ cwd, configPath: explicit.config });
+  return mergeOptions<T>(config as Partial<T>, explicit);
+}
+
+/**
+ * Forget previously discovered configuration files
+ */
+export function clearConfigCache(): void {
+  discoveryCache.clear();
+}
+
+function readJson(filePath: string): unknown {
+  try {
+    return JSON.parse(fs.readFileSync(filePath, 'utf8'));
+  } catch (error) {
+    throw new ConfigError(filePath, [`is not valid JSON: ${(error as Error).message}`]);
+  }
+}
@@ -0,0 +1,182 @@
+import { ScannerOptions } from '../types';
+
+/**
+ * Returns a problem description, or null when the value is valid
+ */
+type Validator = (value: unknown) => string | null;
+
+/**
+ * Thrown when a configuration file cannot be read or fails validation
+ */
+export class ConfigError extends Error {
+  /** Where the configuration came from */
+  readonly source: string;
+  /** Individual validation problems */
+  readonly problems: string[];
+
+  constructor(source: string, problems: string[]) {
+    super(`Invalid wcag-scanner config in ${source}:\n${problems.map(problem => `  - ${problem}`).join('\n')}`);
+    this.name = 'ConfigError';
+    this.source = source;
+    this.problems = problems;
+  }
+}
+
+const oneOf = (...choices: string[]): Validator => value =>
+  typeof value === 'string' && choices.includes(value)
+    ? null
+    : `must be one of ${choices.map(choice => `"${choice}"`).join(', ')}`;
+
+const isBoolean: Validator = value => typeof value === 'boolean' ? null : 'must be true or false';
+
+const isString: Validator = value => typeof value === 'string' ? null : 'must be a string';
+
+const isPositiveNumber: Validator = value =>
+  typeof value === 'number' && value >= 0 && isFinite(value) ? null : 'must be a non-negative number';
+
+const isPositiveInteger: Validator = value =>
+  typeof value === 'number' && Number.isInteger(value) && value >= 1 ? null : 'must be a positive integer';
+
+const isFunction: Validator = value => typeof value === 'function' ? null : 'must be a function';
+
+const isStringArray: Validator = value =>
+  Array.isArray(value) && value.every(item => typeof item === 'string')
+    ? null
+    : 'must be an array of strings';
+
+const RULE_OVERRIDE_FIELDS: Record<string, Validator> = {
+  enabled: isBoolean,
+  type: oneOf('violation', 'warning'),
+  impact: oneOf('critical', 'serious', 'moderate', 'minor'),
+};
+
+const SETTLE_FIELDS: Record<string, Validator> = {
+  selector: isString,
+  networkIdle: isPositiveNumber,
+  timeout: isPositiveNumber,
+};
+
+const isSettleOptions: Validator = value => {
+  if (value === null || typeof value !== 'object' || Array.isArray(value)) {
+    return `must be an object with ${Object.keys(SETTLE_FIELDS).join(', ')}`;
+  }
+
+  for (const [key, field] of Object.entries(value as Record<string, unknown>)) {
+    const validate = SETTLE_FIELDS[key];
+    if (!validate) return `has unknown key "${key}"`;
+
+    const problem = field === undefined ? null : validate(field);
+    if (problem) return `${key} ${problem}`;
+  }
+
+  return null;
+};
+
+const isRuleOverrides: Validator = value => {
+  if (value === null || typeof value !== 'object' || Array.isArray(value)) {
+    return 'must be an object keyed by rule id';
+  }
+
+  for (const [rule, override] of Object.entries(value as Record<string, unknown>)) {
+    if (override === 'off') continue;
+    if (override === null || typeof override !== 'object' || Array.isArray(override)) {
+      return `entry "${rule}" must be "off" or an object with ${Object.keys(RULE_OVERRIDE_FIELDS).join(', ')}`;
+    }
+
+    for (const [key, field] of Object.entries(override as Record<string, unknown>)) {
+      const validate = RULE_OVERRIDE_FIELDS[key];
+      if (!validate) return `entry "${rule}" has unknown key "${key}"`;
+
+      const problem = field === undefined ? null : validate(field);
+      if (problem) return `entry "${rule}" ${key} ${problem}`;
+    }
+  }
+
+  return null;
+};
+
+/**
+ * Validators for every scanner option that may appear in a config file
+ */
+export const OPTION_VALIDATORS: Record<keyof ScannerOptions, Validator> = {
+  level: oneOf('A', 'AA', 'AAA'),
+  standard: oneOf('wcag20', 'wcag21', 'wcag22', 'en301549', 'section508'),
+  bestPractices: isBoolean,
+  preset: oneOf('fast', 'full'),
+  rules: isStringArray,
+  rulePacks: isStringArray,
+  ruleOverrides: isRuleOverrides,
+  ai: isBoolean,
+  baseUrl: isString,
+  verbose: isBoolean,
+  runScripts: isBoolean,
+  settle: isSettleOptions,
+  ignoreScriptErrors: isBoolean,
+  failOnRuleError: isBoolean,
+  config: () => 'cannot be set inside a config file',
+  baseline: isString,
+  onlyNewViolations: isBoolean,
+  loadStylesheets: isBoolean,
+  resourceLoader: isFunction,
+  junitWarnings: oneOf('skipped', 'system-out'),
+  markdownMaxLength: isPositiveInteger,
+};
+
+/**
+ * Validate a raw configuration object against the scanner options schema
+ * @param raw Parsed configuration
+ * @param source Where the configuration came from, used in error messages
+ * @returns The configuration typed as scanner options
+ */
+export function validateConfig(raw: unknown, source: string): ScannerOptions {
+  if (raw === null || typeof raw !== 'object' || Array.isArray(raw)) {
+    throw new ConfigError(source, [`config must be an object (received ${describe(raw)})`]);
+  }
+
+  const problems: string[] = [];
+  for (const [key, value] of Object.entries(raw as Record<string, unknown>)) {
+    if (!Object.prototype.hasOwnProperty.call(OPTION_VALIDATORS, key)) {
+      problems.push(`unknown option "${key}" (expected one of ${Object.keys(OPTION_VALIDATORS).join(', ')})`);
+      continue;
+    }
+
+    const problem = value === undefined ? null : OPTION_VALIDATORS[key as keyof ScannerOptions](value);
+    if (problem) {
+      problems.push(`"${key}" ${problem} (received ${describe(value)})`);
+    }
+  }
+
+  if (problems.length > 0) {
+    throw new ConfigError(source, problems);
+  }
+
+  return raw as ScannerOptions;
+}
+
+/**
+ * Merge option layers from lowest to highest precedence, ignoring undefined values
+ * @param layers Option objects, later layers win
+ * @returns Merged options
+ */
+export function mergeOptions<T extends object>(...layers: Array<Partial<T> | undefined>): T {
+  const merged: Partial<T> = {};
+
+  for (const layer of layers) {
+    if (!layer) continue;
+    for (const key of Object.keys(layer) as Array<keyof T>) {
+      if (layer[key] !== undefined) {
+        merged[key] = layer[key];
+      }
+    }
+  }
+
+  return merged as T;
+}
+
+function describe(value: unknown): string {
+  if (value === null) return 'null';
+  if (Array.isArray(value)) return 'an array';
+  if (typeof value === 'object') return 'an object';
+  if (typeof value === 'function') return 'a function';
+  return JSON.stringify(value);
+}
@@ -0,0 +1,277 @@
+import { WCAGScanner } from '../scanner';
+import { fetchPage, fetchText, NotHtmlError, pageScanOptions, ScanUrlOptions } from '../fetcher';
+import { globToRegExp } from '../utils/glob';
+import { ScanResults, SiteResults, SiteRuleSummary } from '../types';
+import { parseRobotsTxt, RobotsTxt } from './robots';
+import { parseSitemap, Sitemap } from './sitemap';
+
+export { parseRobotsTxt, CRAWLER_USER_AGENT } from './robots';
+export type { RobotsTxt } from './robots';
+export { parseSitemap } from './sitemap';
+export type { Sitemap } from './sitemap';
+
+/**
+ * URL filter: a glob matched against the URL path, or a regular expression matched against the full URL
+ */
+export type UrlPattern = string | RegExp;
+
+/**
+ * Options for crawling a site
+ */
+export interface CrawlOptions extends ScanUrlOptions {
+  /** Maximum number of links to follow from the start page (default: 2) */
+  maxDepth?: number;
+  /** Maximum number of pages to fetch (default: 50) */
+  maxPages?: number;
+  /** Number of pages fetched and scanned at the same time (default: 4) */
+  concurrency?: number;
+  /** Only follow links matching at least one of these patterns */
+  include?: UrlPattern[];
+  /** Never follow links matching any of these patterns */
+  exclude?: UrlPattern[];
+  /** Honor the site's robots.txt (default: true) */
+  robotsTxt?: boolean;
+  /** Called after each page is scanned */
+  onPage?: (url: string, results: ScanResults) => void;
+}
+
+export const DEFAULT_MAX_DEPTH = 2;
+export const DEFAULT_MAX_PAGES = 50;
+export const DEFAULT_CONCURRENCY = 4;
+
+/** How deep nested sitemap indexes are followed */
+const MAX_SITEMAP_NESTING = 3;
+
+/** Links to documents, media and archives, which are never fetched */
+const NON_HTML_EXTENSIONS = /\.(pdf|zip|gz|tgz|tar|rar|7z|dmg|exe|msi|pkg|apk|iso|docx?|xlsx?|pptx?|odt|ods|odp|csv|json|xml|rss|txt|css|js|mjs|map|jpe?g|png|gif|webp|avif|svg|ico|bmp|tiff?|mp3|mp4|m4a|m4v|mov|avi|mkv|webm|wav|ogg|flac|woff2?|ttf|otf|eot)$/i;
+
+interface QueuedPage {
+  url: string;
+  depth: number;
+}
+
+/**
+ * Crawl a site from a start URL or sitemap.xml and scan every same-origin page found.
+ * The start URL is always scanned; include/exclude patterns filter the links followed from it.
+ * @param start Start page URL, or the URL of a sitemap (path ending in .xml)
+ * @param options Crawl, fetch and scanner options
+ * @returns Per-page results, fetch errors, skipped non-HTML links and a site-wide rollup
+ */
+export async function crawlSite(start: string, options: CrawlOptions = {}): Promise<SiteResults> {
+  const startUrl = new URL(start);
+  const origin = startUrl.origin;
+  const maxDepth = options.maxDepth ?? DEFAULT_MAX_DEPTH;
+  const maxPages = options.maxPages ?? DEFAULT_MAX_PAGES;
+  const concurrency = Math.max(1, options.concurrency ?? DEFAULT_CONCURRENCY);
+  const include = (options.include || []).map(toMatcher);
+  const exclude = (options.exclude || []).map(toMatcher);
+  const robots = options.robotsTxt === false ? null : await fetchRobotsTxt(origin, options);
+
+  const pages: Record<string, ScanResults> = {};
+  const errors: Record<string, string> = {};
+  const skipped: Record<string, string> = {};
+  const seen = new Set<string>();
+  const scanned = new Set<string>();
+  const queue: QueuedPage[] = [];
+
+  const isCrawlable = (url: URL): boolean =>
+    url.origin === origin && (!robots || robots.isAllowed(url.pathname + url.search));
+
+  const matchesFilters = (url: URL): boolean =>
+    (include.length === 0 || include.some(match => match(url))) && !exclude.some(match => match(url));
+
+  const enqueue = (href: string, depth: number, filter: boolean): void => {
+    const url = new URL(href);
+    url.hash = '';
+    if (seen.has(url.href) || !isCrawlable(url) || (filter && !matchesFilters(url))) return;
+    if (filter && NON_HTML_EXTENSIONS.test(url.pathname)) return;
+    seen.add(url.href);
+    queue.push({ url: url.href, depth });
+  };
+
+  if (/\.xml$/i.test(startUrl.pathname)) {
+    const listed = await sitemapPages(startUrl.href, options, maxPages, errors);
+    listed.forEach(url => enqueue(url, 0, true));
+  } else {
+    enqueue(startUrl.href, 0, false);
+  }
+
+  const visit = async ({ url, depth }: QueuedPage): Promise<void> => {
+    try {
+      const page = await fetchPage(url, options);
+      const finalUrl = new URL(page.url);
+      finalUrl.hash = '';
+
+      if (finalUrl.origin !== origin) {
+        errors[url] = `Redirected to another origin: ${page.url}`;
+        return;
+      }
+      // Several URLs can redirect to the same page; scan it once
+      if (scanned.has(finalUrl.href)) return;
+      scanned.add(finalUrl.href);
+      seen.add(finalUrl.href);
+
+      const scanner = new WCAGScanner(pageScanOptions(page, options));
+      try {
+        await scanner.loadHTML(page.body, page.url);
+        const results = await scanner.scan();
+        pages[finalUrl.href] = results;
+        options.onPage?.(finalUrl.href, results);
+
+        const document = scanner.getDocument();
+        if (depth < maxDepth && document) {
+          extractLinks(document).forEach(link => enqueue(link, depth + 1, true));
+        }
+      } finally {
+        scanner.close();
+      }
+    } catch (error) {
+      if (error instanceof NotHtmlError) {
+        skipped[url] = error.message;
+      } else {
+        errors[url] = error instanceof Error ? error.message : String(error);
+      }
+    }
+  };
+
+  // Bounded concurrency: keep up to `concurrency` pages in flight
+  const active = new Set<Promise<void>>();
+  let started = 0;
+  while (queue.length > 0 || active.size > 0) {
+    while (queue.length > 0 && active.size < concurrency && started < maxPages) {
+      const next = queue.shift() as QueuedPage;
+      started++;
+      const task: Promise<void> = visit(next).finally(() => active.delete(task));
+      active.add(task);
+    }
+    if (active.size === 0) break;
+    await Promise.race(active);
+  }
+
+  return { ...summarizeSite(pages, errors), skipped };
+}
+
+/**
+ * Collect the followable links in a document
+ * Skips rel="nofollow" and download links, resolves against the document base URL
+ * and drops fragments.
+ * @param document Loaded document
+ * @returns Absolute http(s) URLs in document order
+ */
+export function extractLinks(document: Document): string[] {
+  const links: string[] = [];
+
+  document.querySelectorAll('a[href], area[href]').forEach(element => {
+    const rel = (element.getAttribute('rel') || '').toLowerCase().split(/\s+/);
+    if (rel.includes('nofollow') || element.hasAttribute('download')) return;
+
+    try {
+      const url = new URL(element.getAttribute('href') || '', document.baseURI);
+      if (url.protocol !== 'http:' && url.protocol !== 'https:') return;
+      url.hash = '';
+      links.push(url.href);
+    } catch {
+      // ignore malformed hrefs
+    }
+  });
+
+  return links;
+}
+
+/**
+ * Build site results with a rollup from per-page results
+ * @param pages Scan results keyed by page URL or file
+ * @param errors Error messages for pages that failed
+ * @returns Site results
+ */
+export function summarizeSite(pages: Record<string, ScanResults>, errors: Record<string, string> = {}): SiteResults {
+  const rules = new Map<string, SiteRuleSummary>();
+  const summary = {
+    pages: 0,
+    failedPages: Object.keys(errors).length,
+    pagesWithViolations: 0,
+    violations: 0,
+    warnings: 0,
+    incomplete: 0,
+    passes: 0,
+  };
+
+  Object.keys(pages).forEach(page => {
+    const results = pages[page];
+    summary.pages++;
+    summary.violations += results.violations.length;
+    summary.warnings += results.warnings.length;
+    summary.incomplete += results.incomplete.length;
+    summary.passes += results.passes.length;
+    if (results.violations.length > 0) summary.pagesWithViolations++;
+
+    const seenRules = new Set<string>();
+    const count = (rule: string, field: 'violations' | 'warnings') => {
+      const entry = rules.get(rule) || { rule, violations: 0, warnings: 0, pages: 0 };
+      entry[field]++;
+      if (!seenRules.has(rule)) {
+        seenRules.add(rule);
+        entry.pages++;
+      }
+      rules.set(rule, entry);
+    };
+    results.violations.forEach(violation => count(violation.rule, 'violations'));
+    results.warnings.forEach(warning => count(warning.rule, 'warnings'));
+  });
+
+  return {
+    pages,
+    errors,
+    summary: {
+      ...summary,
+      rules: Array.from(rules.values()).sort((a, b) =>
+        b.violations - a.violations || b.warnings - a.warnings || a.rule.localeCompare(b.rule)
+      ),
+    },
+  };
+}
+
+async function fetchRobotsTxt(origin: string, options: CrawlOptions): Promise<RobotsTxt | null> {
+  try {
+    const robots = await fetchText(`${origin}/robots.txt`, options, 'text/plain');
+    return parseRobotsTxt(robots.body);
+  } catch {
+    // A missing or unreachable robots.txt allows everything
+    return null;
+  }
+}
+
+async function sitemapPages(
+  url: string,
+  options: CrawlOptions,
+  limit: number,
+  errors: Record<string, string>,
+  nesting = 0
+): Promise<string[]> {
+  let sitemap: Sitemap;
+  try {
+    const response = await fetchText(url, options, 'application/xml,text/xml;q=0.9,*/*;q=0.8');
+    sitemap = parseSitemap(response.body, response.url);
+  } catch (error) {
+    // The start sitemap must load; nested ones are reported and skipped
+    if (nesting === 0) throw error;
+    errors[url] = error instanceof Error ? error.message : String(error);
+    return [];
+  }
+
+  const pages = sitemap.pages.slice(0, limit);
+  for (const nested of sitemap.sitemaps) {
+    if (pages.length >= limit || nesting >= MAX_SITEMAP_NESTING) break;
+    pages.push(...await sitemapPages(nested, options, limit - pages.length, errors, nesting + 1));
+  }
+
+  return pages;
+}
+
+function toMatcher(pattern: UrlPattern): (url: URL) => boolean {
+  if (pattern instanceof RegExp) {
+    return url => pattern.test(url.href);
+  }
+  const regex = globToRegExp(pattern);
+  return url => regex.test(url.pathname);
+}
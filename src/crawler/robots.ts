@@ -0,0 +1,85 @@
+/**
+ * User agent the crawler identifies as in robots.txt
+ */
+export const CRAWLER_USER_AGENT = 'wcag-scanner';
+
+interface RobotsRule {
+  allow: boolean;
+  pattern: RegExp;
+  /** Pattern length, used to pick the most specific rule */
+  length: number;
+}
+
+/**
+ * Parsed robots.txt rules for one user agent
+ */
+export interface RobotsTxt {
+  /** Check whether a URL path (with query string) may be crawled */
+  isAllowed(pathWithQuery: string): boolean;
+}
+
+/**
+ * Parse robots.txt, keeping the group for our user agent or, failing that, the `*` group.
+ * Supports `Allow`/`Disallow` with `*` and `$` wildcards; the longest matching rule wins
+ * and `Allow` wins ties.
+ * @param text robots.txt contents
+ * @param userAgent User agent to select rules for
+ * @returns Parsed rules
+ */
+export function parseRobotsTxt(text: string, userAgent: string = CRAWLER_USER_AGENT): RobotsTxt {
+  const groups: Array<{ agents: string[]; rules: RobotsRule[] }> = [];
+  let current: { agents: string[]; rules: RobotsRule[] } | null = null;
+  let lastWasAgent = false;
+
+  for (const rawLine of text.split(/\r?\n/)) {
+    const line = rawLine.replace(/#.*$/, '').trim();
+    const colon = line.indexOf(':');
+    if (colon === -1) continue;
+
+    const key = line.slice(0, colon).trim().toLowerCase();
+    const value = line.slice(colon + 1).trim();
+
+    if (key === 'user-agent') {
+      if (!current || !lastWasAgent) {
+        current = { agents: [], rules: [] };
+        groups.push(current);
+      }
+      // A blank agent would match every user agent
+      if (value) current.agents.push(value.toLowerCase());
+      lastWasAgent = true;
+      continue;
+    }
+
+    lastWasAgent = false;
+    if (!current || (key !== 'allow' && key !== 'disallow') || value === '') continue;
+
+    current.rules.push({ allow: key === 'allow', pattern: robotsPattern(value), length: value.length });
+  }
+
+  const agent = userAgent.toLowerCase();
+  const group = groups.find(g => g.agents.some(name => name !== '*' && agent.includes(name)))
+    || groups.find(g => g.agents.includes('*'));
+  const rules = group ? group.rules : [];
+
+  return {
+    isAllowed(pathWithQuery: string): boolean {
+      let best: RobotsRule | null = null;
+      for (const rule of rules) {
+        if (!rule.pattern.test(pathWithQuery)) continue;
+        if (!best || rule.length > best.length || (rule.length === best.length && rule.allow)) {
+          best = rule;
+        }
+      }
+      return !best || best.allow;
+    },
+  };
+}
+
+function robotsPattern(value: string): RegExp {
+  const anchored = value.endsWith('$');
+  const body = (anchored ? value.slice(0, -1) : value)
+    .split('*')
+    .map(part => part.replace(/[.+?^${}()|[\]\\]/g, '\\$&'))
+    .join('.*');
+  return new RegExp(`^${body}${anchored ? '$' : ''}`);
+}
@@ -0,0 +1,52 @@
+/**
+ * URLs listed in a sitemap
+ */
+export interface Sitemap {
+  /** Page URLs from `<urlset>` entries */
+  pages: string[];
+  /** Nested sitemap URLs from a `<sitemapindex>` */
+  sitemaps: string[];
+}
+
+const XML_ENTITIES: Record<string, string> = {
+  amp: '&',
+  lt: '<',
+  gt: '>',
+  quot: '"',
+  apos: "'",
+};
+
+/**
+ * Extract the URLs from a sitemap or sitemap index
+ * @param xml Sitemap XML
+ * @param baseUrl URL the sitemap was fetched from, for resolving relative locations
+ * @returns Page and nested sitemap URLs
+ */
+export function parseSitemap(xml: string, baseUrl: string): Sitemap {
+  const isIndex = /<sitemapindex[\s>]/i.test(xml);
+  const locations: string[] = [];
+  const pattern = /<loc>\s*(?:<!\[CDATA\[)?([\s\S]*?)(?:\]\]>)?\s*<\/loc>/gi;
+
+  let match: RegExpExecArray | null;
+  while ((match = pattern.exec(xml)) !== null) {
+    const location = decodeEntities(match[1].trim());
+    try {
+      locations.push(new URL(location, baseUrl).href);
+    } catch {
+      // skip malformed locations
+    }
+  }
+
+  return isIndex ? { pages: [], sitemaps: locations } : { pages: locations, sitemaps: [] };
+}
+
+function decodeEntities(value: string): string {
+  return value.replace(/&(#x[0-9a-f]+|#\d+|\w+);/gi, (entity, name: string) => {
+    if (name[0] === '#') {
+      const code = name[1].toLowerCase() === 'x' ? parseInt(name.slice(2), 16) : parseInt(name.slice(1), 10);
+      // Leave references to code points that do not exist as they are
+      return code <= 0x10ffff ? String.fromCodePoint(code) : entity;
+    }
+    return XML_ENTITIES[name.toLowerCase()] ?? entity;
+  });
+}
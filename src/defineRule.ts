@@ -0,0 +1,162 @@
+import {
+  DefinedRule,
+  ElementInfo,
+  ImpactLevel,
+  RuleContext,
+  RuleDefinition,
+  ScannerOptions,
+  ScanResults,
+} from './types';
+import { registerRuleMetadata } from './metadata';
+import { isHidden } from './utils/accname';
+import { elementContext } from './utils/elements';
+
+/**
+ * Tag that marks a defined rule as a best practice
+ */
+export const BEST_PRACTICE_TAG = 'best-practice';
+
+const SNIPPET_LENGTH = 150;
+
+/**
+ * Define a rule outside the scanner, e.g. in a design system's rule pack.
+ * The rule's criteria, level and tags are registered so its results are filtered by
+ * level and standard like those of the built-in rules.
+ * @param definition Rule id, module, criteria, level, tags and check
+ * @returns A rule that can be passed to WCAGScanner.registerRule or exported from a rule pack
+ * @throws Error when the id is missing or taken by a built-in rule, or a criterion is unknown
+ */
+export function defineRule(definition: RuleDefinition): DefinedRule {
+  if (!definition || typeof definition.id !== 'string' || definition.id.trim() === '') {
+    throw new Error('defineRule requires a rule id');
+  }
+  if (typeof definition.check !== 'function') {
+    throw new Error(`Rule "${definition.id}" requires a check function`);
+  }
+
+  const { check, ...rest } = definition;
+  const meta = {
+    ...rest,
+    module: definition.module || definition.id,
+    wcag: definition.wcag || [],
+    tags: definition.tags || [],
+  };
+
+  registerRuleMetadata(meta.id, {
+    wcag: meta.wcag,
+    level: meta.level,
+    bestPractice: meta.tags.includes(BEST_PRACTICE_TAG),
+  });
+
+  return {
+    meta,
+    async check(document: Document, window: Window, options: ScannerOptions): Promise<ScanResults> {
+      const results: ScanResults = {
+        passes: [],
+        violations: [],
+        warnings: [],
+        incomplete: []
+      };
+      await check(createRuleContext(meta, results, document, window, options));
+      return results;
+    }
+  };
+}
+
+/**
+ * Create the context a defined rule reports its results through
+ * @param meta Rule definition, whose id, criteria, impact and help fill in each result
+ * @param results Scan results the builders push to
+ */
+export function createRuleContext(
+  meta: DefinedRule['meta'],
+  results: ScanResults,
+  document: Document,
+  window: Window,
+  options: ScannerOptions,
+): RuleContext {
+  const describe = (element: Element | null) => element
+    ? { element: elementInfo(element), snippet: snippet(element), ...elementContext(element) }
+    : {};
+
+  // Fill in what the details leave out from the rule's definition
+  const issue = (element: Element | null, details: { description: string; impact?: ImpactLevel; wcag?: string[] }) => {
+    const impact: ImpactLevel = details.impact || meta.impact || 'moderate';
+    const wcag = details.wcag || meta.wcag;
+    return {
+      rule: meta.id,
+      description: details.description,
+      impact,
+      ...(wcag.length > 0 ? { wcag } : {}),
+      ...describe(element),
+    };
+  };
+
+  return {
+    document,
+    window,
+    options,
+    pass(element, description) {
+      results.passes.push({ rule: meta.id, description, ...describe(element) });
+    },
+    violation(element, details) {
+      const help = details.help || meta.help;
+      const helpUrl = details.helpUrl || meta.helpUrl;
+      results.violations.push({
+        ...issue(element, details),
+        ...(help ? { help } : {}),
+        ...(helpUrl ? { helpUrl } : {}),
+        ...(details.fix ? { fix: details.fix } : {}),
+      });
+    },
+    warning(element, details) {
+      const help = details.help || meta.help;
+      results.warnings.push({ ...issue(element, details), ...(help ? { help } : {}) });
+    },
+    incomplete(element, details) {
+      results.incomplete.push({ ...issue(element, details), reason: details.reason, review: details.review });
+    },
+    snippet,
+    isVisible: element => !isHidden(element),
+  };
+}
+
+/**
+ * Defined rules selected by the options: all of them, or when rules are listed, those whose
+ * id, module or one of whose tags is listed
+ * @param rules Defined rules
+ * @param options Scanner options
+ */
+export function selectDefinedRules(rules: DefinedRule[], options: Pick<ScannerOptions, 'rules'>): DefinedRule[] {
+  const selected = options.rules || [];
+  if (selected.length === 0) return rules;
+
+  return rules.filter(rule =>
+    selected.includes(rule.meta.id)
+      || selected.includes(rule.meta.module)
+      || rule.meta.tags.some(tag => selected.includes(tag)));
+}
+
+/**
+ * Whether a value is a rule created by defineRule
+ */
+export function isDefinedRule(value: unknown): value is DefinedRule {
+  return typeof value === 'object'
+    && value !== null
+    && typeof (value as DefinedRule).check === 'function'
+    && typeof (value as DefinedRule).meta === 'object'
+    && typeof (value as DefinedRule).meta?.id === 'string';
+}
+
+function snippet(element: Element): string {
+  const html = element.outerHTML;
+  return html.slice(0, SNIPPET_LENGTH) + (html.length > SNIPPET_LENGTH ? '...' : '');
+}
+
+function elementInfo(element: Element): ElementInfo {
+  return {
+    tagName: element.tagName.toLowerCase(),
+    id: element.id || null,
+    className: element.getAttribute('class') || null,
+  };
+}
@@ -0,0 +1,344 @@
+import http from 'http';
+import https from 'https';
+import zlib from 'zlib';
+import { ScannerOptions, SubresourceLoader } from './types';
+
+/**
+ * Options for fetching a page over HTTP(S)
+ */
+export interface FetchOptions {
+  /** Extra request headers */
+  headers?: Record<string, string>;
+  /** Cookies sent with the request, as a name/value map or a Cookie header string */
+  cookies?: Record<string, string> | string;
+  /** Total time allowed for the request and any redirects, in milliseconds (default: 30000) */
+  timeout?: number;
+  /** Maximum number of redirects to follow (default: 5) */
+  maxRedirects?: number;
+  /** Largest response body accepted, before and after decompression, in bytes (default: 10 MiB) */
+  maxBytes?: number;
+}
+
+/**
+ * Options for scanning a page by URL
+ */
+export interface ScanUrlOptions extends ScannerOptions, FetchOptions {}
+
+/**
+ * A fetched page or other text resource
+ */
+export interface FetchedPage {
+  /** URL the page was served from, after redirects */
+  url: string;
+  /** HTTP status code */
+  status: number;
+  /** Response headers */
+  headers: http.IncomingHttpHeaders;
+  /** Decoded response body */
+  body: string;
+  /** URLs that redirected to the final URL, in order */
+  redirects: string[];
+}
+
+/**
+ * Thrown when a page cannot be fetched
+ */
+export class FetchError extends Error {
+  /** URL that failed */
+  readonly url: string;
+  /** HTTP status code, when a response was received */
+  readonly status?: number;
+
+  constructor(message: string, url: string, status?: number) {
+    super(message);
+    this.name = 'FetchError';
+    this.url = url;
+    this.status = status;
+  }
+}
+
+/**
+ * Thrown by fetchPage when a URL serves something other than HTML. The body is not downloaded.
+ */
+export class NotHtmlError extends FetchError {
+  /** Content type the URL was served with */
+  readonly contentType: string;
+
+  constructor(contentType: string, url: string, status?: number) {
+    super(`Expected an HTML page but received ${contentType}`, url, status);
+    this.name = 'NotHtmlError';
+    this.contentType = contentType;
+  }
+}
+
+export const DEFAULT_TIMEOUT = 30000;
+export const DEFAULT_MAX_REDIRECTS = 5;
+export const DEFAULT_MAX_BYTES = 10 * 1024 * 1024;
+
+const REDIRECT_STATUSES = [301, 302, 303, 307, 308];
+const HTML_CONTENT_TYPES = ['text/html', 'application/xhtml+xml'];
+
+/**
+ * Check whether a string is an http(s) URL
+ * @param value String to check
+ */
+export function isHttpUrl(value: string): boolean {
+  return /^https?:\/\//i.test(value);
+}
+
+/**
+ * Fetch an HTML page, following redirects
+ * @param url Page URL
+ * @param options Request options
+ * @returns The fetched page
+ * @throws NotHtmlError when the page's content type is not HTML, before its body is read
+ */
+export async function fetchPage(url: string, options: FetchOptions = {}): Promise<FetchedPage> {
+  return fetchResource(url, options, 'text/html,application/xhtml+xml;q=0.9,*/*;q=0.8', (pageUrl, status, headers) => {
+    const contentType = (headers['content-type'] || '').toLowerCase();
+    return contentType && !HTML_CONTENT_TYPES.some(type => contentType.startsWith(type))
+      ? new NotHtmlError(contentType, pageUrl, status)
+      : null;
+  });
+}
+
+/**
+ * Fetch any text resource (robots.txt, sitemaps, ...), following redirects
+ * @param url Resource URL
+ * @param options Request options
+ * @param accept Accept header value
+ * @returns The fetched resource
+ */
+export async function fetchText(url: string, options: FetchOptions = {}, accept = '*/*'): Promise<FetchedPage> {
+  return fetchResource(url, options, accept);
+}
+
+/**
+ * Checks the headers of the final response before its body is read
+ * @returns An error to abort the request with, or null to read the body
+ */
+type ResponseCheck = (url: string, status: number, headers: http.IncomingHttpHeaders) => FetchError | null;
+
+async function fetchResource(url: string, options: FetchOptions, accept: string, check?: ResponseCheck): Promise<FetchedPage> {
+  const deadline = Date.now() + (options.timeout ?? DEFAULT_TIMEOUT);
+  const maxRedirects = options.maxRedirects ?? DEFAULT_MAX_REDIRECTS;
+  const maxBytes = options.maxBytes ?? DEFAULT_MAX_BYTES;
+  const origin = new URL(url).origin;
+  const cookies = parseCookies(options.cookies);
+  const redirects: string[] = [];
+  let current = url;
+
+  while (true) {
+    const sameOrigin = new URL(current).origin === origin;
+    const headers: Record<string, string> = {
+      'user-agent': 'wcag-scanner',
+      accept,
+      'accept-encoding': 'gzip, deflate, br',
+      ...lowerCaseKeys(options.headers || {}),
+    };
+
+    // Never send credentials to another origin after a redirect
+    if (sameOrigin && cookies.size > 0) {
+      headers.cookie = Array.from(cookies, ([name, value]) => `${name}=${value}`).join('; ');
+    } else if (!sameOrigin) {
+      delete headers.cookie;
+      delete headers.authorization;
+    }
+
+    const requested = current;
+    const response = await request(current, headers, deadline - Date.now(), maxBytes, (status, responseHeaders) => {
+      const redirected = REDIRECT_STATUSES.includes(status) && Boolean(responseHeaders.location);
+      return check && !redirected && status < 400 ? check(requested, status, responseHeaders) : null;
+    });
+
+    if (REDIRECT_STATUSES.includes(response.status) && response.headers.location) {
+      if (sameOrigin) {
+        // Keep session cookies set by login-style redirects
+        toArray(response.headers['set-cookie']).forEach(cookie => addCookie(cookies, cookie.split(';')[0]));
+      }
+
+      if (redirects.length >= maxRedirects) {
+        throw new FetchError(`Too many redirects (more than ${maxRedirects})`, url, response.status);
+      }
+      redirects.push(current);
+      current = new URL(response.headers.location, current).href;
+      continue;
+    }
+
+    if (response.status >= 400) {
+      throw new FetchError(`Request failed with status ${response.status}`, current, response.status);
+    }
+
+    return {
+      url: current,
+      status: response.status,
+      headers: response.headers,
+      body: decodeBody(current, response, maxBytes),
+      redirects,
+    };
+  }
+}
+
+/**
+ * Subresource loader that fetches stylesheets and scripts from a page's own origin
+ * @param pageUrl URL of the page
+ * @param options Request options, e.g. the credentials used for the page
+ * @returns Loader that returns null for other origins
+ */
+export function sameOriginLoader(pageUrl: string, options: FetchOptions = {}): SubresourceLoader {
+  const origin = new URL(pageUrl).origin;
+  return async url => {
+    if (!isHttpUrl(url) || new URL(url).origin !== origin) return null;
+    return (await fetchText(url, options)).body;
+  };
+}
+
+/**
+ * Scanner options for a fetched page. When stylesheets are loaded or scripts run and the
+ * caller supplied no resource loader, subresources are fetched from the page's own origin.
+ * @param page Fetched page
+ * @param options Scan options
+ * @returns Options to scan the page with
+ */
+export function pageScanOptions<T extends ScanUrlOptions>(page: FetchedPage, options: T): T {
+  if (options.resourceLoader || !(options.loadStylesheets || options.runScripts)) {
+    return options;
+  }
+  return { ...options, resourceLoader: sameOriginLoader(page.url, options) };
+}
+
+interface RawResponse {
+  status: number;
+  headers: http.IncomingHttpHeaders;
+  body: Buffer;
+}
+
+function request(
+  url: string,
+  headers: Record<string, string>,
+  timeout: number,
+  maxBytes: number,
+  inspect: (status: number, headers: http.IncomingHttpHeaders) => FetchError | null
+): Promise<RawResponse> {
+  return new Promise((resolve, reject) => {
+    const parsed = new URL(url);
+    if (parsed.protocol !== 'http:' && parsed.protocol !== 'https:') {
+      reject(new FetchError(`Unsupported protocol ${parsed.protocol}`, url));
+      return;
+    }
+    if (timeout <= 0) {
+      reject(new FetchError('Request timed out', url));
+      return;
+    }
+
+    const client = parsed.protocol === 'https:' ? https : http;
+    const req = client.get(parsed, { headers }, res => {
+      // Drop the connection rather than download a body that is not wanted
+      const rejected = inspect(res.statusCode || 0, res.headers);
+      if (rejected) {
+        clearTimeout(timer);
+        req.destroy();
+        reject(rejected);
+        return;
+      }
+
+      const chunks: Buffer[] = [];
+      let received = 0;
+      res.on('data', (chunk: Buffer) => {
+        received += chunk.length;
+        if (received > maxBytes) {
+          clearTimeout(timer);
+          req.destroy();
+          reject(new FetchError(`Response body exceeds ${maxBytes} bytes`, url, res.statusCode));
+          return;
+        }
+        chunks.push(chunk);
+      });
+      res.on('end', () => {
+        clearTimeout(timer);
+        resolve({ status: res.statusCode || 0, headers: res.headers, body: Buffer.concat(chunks) });
+      });
+      res.on('error', error => {
+        clearTimeout(timer);
+        reject(new FetchError(error.message, url));
+      });
+    });
+
+    const timer = setTimeout(() => {
+      req.destroy(new FetchError(`Request timed out after ${timeout}ms`, url));
+    }, timeout);
+
+    req.on('error', error => {
+      clearTimeout(timer);
+      reject(error instanceof FetchError ? error : new FetchError(error.message, url));
+    });
+  });
+}
+
+/**
+ * Decompress and decode a response body using its headers
+ * @throws FetchError when the body is corrupt or decompresses to more than maxBytes
+ */
+function decodeBody(url: string, response: RawResponse, maxBytes: number): string {
+  const { body, headers } = response;
+  // Caps the output of a decompression bomb
+  const limits = { maxOutputLength: maxBytes };
+
+  let data = body;
+  try {
+    switch ((headers['content-encoding'] || '').toLowerCase()) {
+      case 'gzip':
+        data = zlib.gunzipSync(body, limits);
+        break;
+      case 'deflate':
+        data = zlib.inflateSync(body, limits);
+        break;
+      case 'br':
+        data = zlib.brotliDecompressSync(body, limits);
+        break;
+    }
+  } catch (error) {
+    const message = (error as NodeJS.ErrnoException).code === 'ERR_BUFFER_TOO_LARGE'
+      ? `Response body exceeds ${maxBytes} bytes when decompressed`
+      : `Could not decompress the response body: ${(error as Error).message}`;
+    throw new FetchError(message, url, response.status);
+  }
+
+  const charset = /charset=["']?([\w-]+)/i.exec(headers['content-type'] || '');
+  try {
+    return new TextDecoder(charset ? charset[1] : 'utf-8').decode(data);
+  } catch {
+    return data.toString('utf8');
+  }
+}
+
+function parseCookies(cookies: FetchOptions['cookies']): Map<string, string> {
+  const jar = new Map<string, string>();
+  if (!cookies) return jar;
+
+  if (typeof cookies === 'string') {
+    cookies.split(';').forEach(pair => addCookie(jar, pair));
+  } else {
+    Object.keys(cookies).forEach(name => jar.set(name, cookies[name]));
+  }
+
+  return jar;
+}
+
+function addCookie(jar: Map<string, string>, pair: string): void {
+  const eq = pair.indexOf('=');
+  if (eq > 0) jar.set(pair.slice(0, eq).trim(), pair.slice(eq + 1).trim());
+}
+
+function lowerCaseKeys(headers: Record<string, string>): Record<string, string> {
+  const result: Record<string, string> = {};
+  Object.keys(headers).forEach(name => {
+    result[name.toLowerCase()] = headers[name];
+  });
+  return result;
+}
+
+function toArray(value: string | string[] | undefined): string[] {
+  if (!value) return [];
+  return Array.isArray(value) ? value : [value];
+}
@@ -1,18 +1,34 @@
 import { WCAGScanner } from './scanner';
-import { ScannerOptions, ScanResults } from './types';
-import { generateReport, ReporterFormat } from './reporters';
+import { ScannerOptions, ScanResults, SiteResults } from './types';
+import { generateReport, htmlReporter, ReporterFormat } from './reporters';
 import middleware from './middleware';
+import { fetchPage, FetchedPage, pageScanOptions, ScanUrlOptions } from './fetcher';
+import { crawlSite } from './crawler';
+import { defineRule } from './defineRule';
 import fs from 'fs';
 import path from 'path';
-export { FAST_RULES, FULL_RULES, RULE_PRESETS, resolveRuleNames } from './rules/presets';
+import { pathToFileURL } from 'url';
+export { FAST_RULES, FULL_RULES, RULE_PRESETS, resolveRuleNames } from './presets';
+export { applyRuleOverrides } from './overrides';
+export { BEST_PRACTICE_TAG, defineRule } from './defineRule';
+export { loadRulePack, loadRulePacks } from './rulePacks';
+export { applySuppressions, collectSuppressions, DISABLE_NEXT_LINE, IGNORE_ATTRIBUTE } from './suppressions';
+export {
+  baselinePage,
+  compareWithBaseline,
+  createBaseline,
+  filterNewViolations,
+  fingerprint,
+  loadBaseline,
+  writeBaseline,
+} from './baseline';
+export type { BaselineFile, BaselinePage } from './baseline';
 
 /**
  * Scan an HTML string for WCAG violations.
  */
 export async function scanHtml(html: string, options: ScannerOptions = {}): Promise<ScanResults> {
-  const scanner = new WCAGScanner(options);
-  await scanner.loadHTML(html);
-  return scanner.scan();
+  return runScan(new WCAGScanner(options), html, options.baseUrl);
 }
 
 /**
@@ -20,10 +36,37 @@ export async function scanHtml(html: string, options: ScannerOptions = {}): Prom
  */
 export async function scanFile(filePath: string, options: ScannerOptions = {}): Promise<ScanResults> {
   const html = fs.readFileSync(path.resolve(filePath), 'utf8');
-  const baseUrl = options.baseUrl || `file://${path.resolve(filePath)}`;
-  const scanner = new WCAGScanner(options);
-  await scanner.loadHTML(html, baseUrl);
-  return scanner.scan();
+  const baseUrl = options.baseUrl || pathToFileURL(path.resolve(filePath)).href;
+  return runScan(new WCAGScanner(options), html, baseUrl);
+}
+
+/**
+ * Fetch a page over HTTP(S) and scan it for WCAG violations.
+ * Relative URLs in the page resolve against the final URL after redirects.
+ */
+export async function scanUrl(url: string, options: ScanUrlOptions = {}): Promise<ScanResults> {
+  return scanPage(await fetchPage(url, options), options);
+}
+
+/**
+ * Scan a page that was already fetched with fetchPage.
+ * Stylesheets and scripts, when enabled, are fetched from the page's origin with the same request options.
+ */
+export async function scanPage(page: FetchedPage, options: ScanUrlOptions = {}): Promise<ScanResults> {
+  const scanOptions = pageScanOptions(page, options);
+  return runScan(new WCAGScanner(scanOptions), page.body, options.baseUrl || page.url);
+}
+
+/**
+ * Load, scan and close a document
+ */
+async function runScan(scanner: WCAGScanner, html: string, baseUrl?: string): Promise<ScanResults> {
+  try {
+    await scanner.loadHTML(html, baseUrl);
+    return await scanner.scan();
+  } finally {
+    scanner.close();
+  }
 }
 
 /**
@@ -37,6 +80,13 @@ export function formatReport(
   return generateReport(results, format, options);
 }
 
+/**
+ * Generate an HTML report for several pages, e.g. from crawlSite.
+ */
+export function formatSiteReport(site: SiteResults, options: ScannerOptions = {}): string {
+  return htmlReporter.formatSite(site, options);
+}
+
 /**
  * Save a report string to a file.
  */
@@ -44,9 +94,15 @@ export function saveReport(report: string, filePath: string): void {
   fs.writeFileSync(filePath, report);
 }
 
+export { fetchPage, FetchError, isHttpUrl, NotHtmlError, sameOriginLoader } from './fetcher';
+export { ScriptError } from './resources';
+export { RuleFailureError } from './ruleErrors';
+export type { FetchOptions, FetchedPage, ScanUrlOptions } from './fetcher';
+export { crawlSite, extractLinks, parseRobotsTxt, parseSitemap, summarizeSite } from './crawler';
+export type { CrawlOptions, RobotsTxt, Sitemap, UrlPattern } from './crawler';
 export { WCAGScanner };
 export * from './types';
 export { ReporterFormat };
 export { middleware };
 
-export default { scanHtml, scanFile, formatReport, saveReport, middleware };
+export default { scanHtml, scanFile, scanUrl, crawlSite, defineRule, formatReport, formatSiteReport, saveReport, middleware };
@@ -0,0 +1,331 @@
+import { Incomplete, Pass, RuleMetadata, ScannerOptions, ScanResults, Standard, Violation, Warning, WcagLevel, WcagVersion } from './types';
+
+const LEVEL_ORDER: WcagLevel[] = ['A', 'AA', 'AAA'];
+const VERSION_ORDER: WcagVersion[] = ['2.0', '2.1', '2.2'];
+
+/**
+ * A WCAG success criterion: its conformance level, the version that added it and,
+ * for 4.1.1, the version that removed it
+ */
+interface Criterion {
+  level: WcagLevel;
+  version: WcagVersion;
+  removedIn?: WcagVersion;
+}
+
+const c = (level: WcagLevel, version: WcagVersion = '2.0', removedIn?: WcagVersion): Criterion =>
+  removedIn ? { level, version, removedIn } : { level, version };
+
+/**
+ * Every WCAG 2.x success criterion
+ */
+export const WCAG_CRITERIA: Record<string, Criterion> = {
+  '1.1.1': c('A'),
+  '1.2.1': c('A'), '1.2.2': c('A'), '1.2.3': c('A'), '1.2.4': c('AA'), '1.2.5': c('AA'),
+  '1.2.6': c('AAA'), '1.2.7': c('AAA'), '1.2.8': c('AAA'), '1.2.9': c('AAA'),
+  '1.3.1': c('A'), '1.3.2': c('A'), '1.3.3': c('A'), '1.3.4': c('AA', '2.1'), '1.3.5': c('AA', '2.1'),
+  '1.3.6': c('AAA', '2.1'),
+  '1.4.1': c('A'), '1.4.2': c('A'), '1.4.3': c('AA'), '1.4.4': c('AA'), '1.4.5': c('AA'), '1.4.6': c('AAA'),
+  '1.4.7': c('AAA'), '1.4.8': c('AAA'), '1.4.9': c('AAA'), '1.4.10': c('AA', '2.1'), '1.4.11': c('AA', '2.1'),
+  '1.4.12': c('AA', '2.1'), '1.4.13': c('AA', '2.1'),
+  '2.1.1': c('A'), '2.1.2': c('A'), '2.1.3': c('AAA'), '2.1.4': c('A', '2.1'),
+  '2.2.1': c('A'), '2.2.2': c('A'), '2.2.3': c('AAA'), '2.2.4': c('AAA'), '2.2.5': c('AAA'),
+  '2.2.6': c('AAA', '2.1'),
+  '2.3.1': c('A'), '2.3.2': c('AAA'), '2.3.3': c('AAA', '2.1'),
+  '2.4.1': c('A'), '2.4.2': c('A'), '2.4.3': c('A'), '2.4.4': c('A'), '2.4.5': c('AA'), '2.4.6': c('AA'),
+  '2.4.7': c('AA'), '2.4.8': c('AAA'), '2.4.9': c('AAA'), '2.4.10': c('AAA'), '2.4.11': c('AA', '2.2'),
+  '2.4.12': c('AAA', '2.2'), '2.4.13': c('AAA', '2.2'),
+  '2.5.1': c('A', '2.1'), '2.5.2': c('A', '2.1'), '2.5.3': c('A', '2.1'), '2.5.4': c('A', '2.1'),
+  '2.5.5': c('AAA', '2.1'), '2.5.6': c('AAA', '2.1'), '2.5.7': c('AA', '2.2'), '2.5.8': c('AA', '2.2'),
+  '3.1.1': c('A'), '3.1.2': c('AA'), '3.1.3': c('AAA'), '3.1.4': c('AAA'), '3.1.5': c('AAA'), '3.1.6': c('AAA'),
+  '3.2.1': c('A'), '3.2.2': c('A'), '3.2.3': c('AA'), '3.2.4': c('AA'), '3.2.5': c('AAA'), '3.2.6': c('A', '2.2'),
+  '3.3.1': c('A'), '3.3.2': c('A'), '3.3.3': c('AA'), '3.3.4': c('AA'), '3.3.5': c('AAA'), '3.3.6': c('AAA'),
+  '3.3.7': c('A', '2.2'), '3.3.8': c('AA', '2.2'), '3.3.9': c('AAA', '2.2'),
+  '4.1.1': c('A', '2.0', '2.2'), '4.1.2': c('A'), '4.1.3': c('AA', '2.1'),
+};
+
+interface StandardProfile {
+  /** Name used in reports */
+  name: string;
+  /** WCAG version whose success criteria the standard includes */
+  wcag: WcagVersion;
+  /** Highest level the standard requires */
+  maxLevel: WcagLevel;
+  /** Clause number for a success criterion */
+  clause: (criterion: string) => string;
+}
+
+/**
+ * Standards that can be checked against, each defined by the WCAG version it incorporates
+ */
+export const STANDARDS: Record<Standard, StandardProfile> = {
+  wcag20: { name: 'WCAG 2.0', wcag: '2.0', maxLevel: 'AAA', clause: criterion => criterion },
+  wcag21: { name: 'WCAG 2.1', wcag: '2.1', maxLevel: 'AAA', clause: criterion => criterion },
+  wcag22: { name: 'WCAG 2.2', wcag: '2.2', maxLevel: 'AAA', clause: criterion => criterion },
+  // EN 301 549 V3.2.1 clause 9 mirrors WCAG 2.1 A and AA, numbered 9.<criterion>
+  en301549: { name: 'EN 301 549', wcag: '2.1', maxLevel: 'AA', clause: criterion => `9.${criterion}` },
+  // Revised Section 508 incorporates WCAG 2.0 A and AA by reference in E205.4
+  section508: { name: 'Section 508', wcag: '2.0', maxLevel: 'AA', clause: criterion => `E205.4 (${criterion})` },
+};
+
+const DEFAULT_STANDARD: Standard = 'wcag21';
+
+/**
+ * Success criteria of a rule id, the level of rules without criteria, and whether the rule
+ * is a best practice: a check that helps users but that the criteria do not strictly require
+ */
+interface RuleEntry {
+  wcag: string[];
+  level?: WcagLevel;
+  bestPractice?: boolean;
+}
+
+/**
+ * Metadata of each built-in rule id
+ */
+const RULES: Record<string, RuleEntry> = {
+  // aria
+  'aria-role-valid': { wcag: ['4.1.2'] },
+  'aria-deprecated-role': { wcag: ['4.1.2'], bestPractice: true },
+  'aria-role-compatible': { wcag: ['4.1.2'] },
+  'aria-required-attr': { wcag: ['4.1.2'] },
+  'aria-valid-attr': { wcag: ['4.1.2'] },
+  'aria-prohibited-attr': { wcag: ['4.1.2'] },
+  'aria-boolean-value': { wcag: ['4.1.2'] },
+  'aria-valid-attr-value': { wcag: ['4.1.2'] },
+  'aria-deprecated-attr': { wcag: ['4.1.2'], bestPractice: true },
+  'aria-required-children': { wcag: ['1.3.1'] },
+  'aria-required-parent': { wcag: ['1.3.1'] },
+  'aria-redundant-role': { wcag: [], bestPractice: true },
+  // backgroundImages
+  'background-image': { wcag: ['1.1.1'] },
+  // contrast
+  'color-contrast': { wcag: ['1.4.3', '1.4.6'] },
+  // forms
+  'form-label': { wcag: ['1.3.1', '2.4.6', '3.3.2', '4.1.2'] },
+  'form-label-alternative': { wcag: ['1.3.1', '4.1.2'] },
+  'placeholder-label': { wcag: ['1.3.1', '3.3.2'] },
+  'select-options': { wcag: ['4.1.2'] },
+  'fieldset-legend': { wcag: ['1.3.1', '3.3.2'] },
+  'fieldset-legend-empty': { wcag: ['1.3.1', '3.3.2'] },
+  'form-submit': { wcag: ['3.2.2'] },
+  'form-name': { wcag: ['4.1.2'] },
+  'required-aria-required': { wcag: [], bestPractice: true },
+  'pattern-title': { wcag: ['3.3.1', '3.3.2'] },
+  'accessible-authentication': { wcag: ['3.3.8'] },
+  // idReferences
+  'id-reference-missing': { wcag: ['1.3.1', '4.1.2'] },
+  'id-reference-hidden': { wcag: ['1.3.1', '4.1.2'] },
+  'duplicate-id-referenced': { wcag: ['1.3.1', '4.1.2'] },
+  'duplicate-id': { wcag: ['1.3.1', '4.1.2'], bestPractice: true },
+  // images
+  'img-alt': { wcag: ['1.1.1'] },
+  'img-alt-decorative': { wcag: ['1.1.1'] },
+  'img-alt-generic': { wcag: ['1.1.1'] },
+  'img-alt-long': { wcag: ['1.1.1'], bestPractice: true },
+  'img-dimensions': { wcag: [], bestPractice: true },
+  'svg-role': { wcag: ['1.1.1'] },
+  'svg-accessible-name': { wcag: ['1.1.1'] },
+  'svg-title-empty': { wcag: ['1.1.1'] },
+  'map-unused': { wcag: [], bestPractice: true },
+  'area-alt': { wcag: ['1.1.1', '2.4.4'] },
+  // keyboard
+  'tabindex-positive': { wcag: ['2.4.3'] },
+  'tabindex-non-interactive': { wcag: ['2.1.1'] },
+  'keyboard-event-equivalents': { wcag: ['2.1.1'] },
+  'focus-visible': { wcag: ['2.4.7'] },
+  'interactive-semantics': { wcag: ['4.1.2'] },
+  'interactive-focusable': { wcag: ['2.1.1'] },
+  'link-new-window': { wcag: ['3.2.2'] },
+  'target-size': { wcag: ['2.5.8'] },
+  'focus-not-obscured': { wcag: ['2.4.11'] },
+  // structure
+  'heading-h1': { wcag: ['2.4.6'], bestPractice: true },
+  'heading-h1-multiple': { wcag: ['2.4.6'], bestPractice: true },
+  'heading-empty': { wcag: ['1.3.1', '2.4.6'] },
+  'heading-skip': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-main': { wcag: ['1.3.1', '2.4.1'] },
+  'landmark-main-multiple': { wcag: ['1.3.1'] },
+  'landmark-navigation': { wcag: ['1.3.1', '2.4.1'] },
+  'landmark-banner': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-banner-name': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-contentinfo': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-contentinfo-name': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-complementary': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-complementary-name': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-form': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-form-name': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-search': { wcag: ['1.3.1'], bestPractice: true },
+  'landmark-search-name': { wcag: ['1.3.1'], bestPractice: true },
+  'html-lang': { wcag: ['3.1.1'] },
+  'document-title': { wcag: ['2.4.2'] },
+  'document-title-empty': { wcag: ['2.4.2'] },
+  'skip-link': { wcag: ['2.4.1'] },
+  'link-purpose': { wcag: ['2.4.9'] },
+  'abbr-expansion': { wcag: ['3.1.4'] },
+  'list-structure': { wcag: ['1.3.1'] },
+  'list-structure-child': { wcag: ['1.3.1'] },
+  'dl-dt': { wcag: ['1.3.1'] },
+  'dl-dd': { wcag: ['1.3.1'] },
+  'dl-structure': { wcag: ['1.3.1'] },
+  'table-layout': { wcag: [], bestPractice: true },
+  'table-headers': { wcag: ['1.3.1'] },
+  'table-header-empty': { wcag: ['1.3.1'] },
+  'table-header-scope': { wcag: ['1.3.1'] },
+  'table-caption': { wcag: ['1.3.1'], bestPractice: true },
+  'table-caption-empty': { wcag: ['1.3.1'], bestPractice: true },
+};
+
+/**
+ * Success criteria of rule ids registered by defined rules, with the level of rules that
+ * have no criteria
+ */
+const CUSTOM_RULES = new Map<string, RuleEntry>();
+
+/**
+ * Register the metadata of a custom rule id, so it is filtered and annotated like a built-in one
+ * @param rule Rule id
+ * @param metadata Success criteria, the level for rules without criteria, and whether the
+ * rule is a best practice
+ * @throws Error when the id belongs to a built-in rule or a criterion is not a WCAG 2.x success criterion
+ */
+export function registerRuleMetadata(rule: string, metadata: RuleEntry): void {
+  if (Object.prototype.hasOwnProperty.call(RULES, rule)) {
+    throw new Error(`Rule id "${rule}" is already used by a built-in rule`);
+  }
+  const unknown = metadata.wcag.filter(id => !Object.prototype.hasOwnProperty.call(WCAG_CRITERIA, id));
+  if (unknown.length > 0) {
+    throw new Error(`Rule "${rule}" cites unknown success criteria: ${unknown.join(', ')}`);
+  }
+  CUSTOM_RULES.set(rule, metadata);
+}
+
+/**
+ * Get the metadata of a built-in or registered rule id
+ * @param rule Rule id, e.g. "heading-h1"
+ * @returns Criteria with their levels and versions, the rule's level and whether it is a
+ * best practice, or undefined for rule ids without metadata
+ */
+export function getRuleMetadata(rule: string): RuleMetadata | undefined {
+  const entry = Object.prototype.hasOwnProperty.call(RULES, rule) ? RULES[rule] : CUSTOM_RULES.get(rule);
+  if (!entry) return undefined;
+
+  const { wcag, bestPractice = false } = entry;
+  const criteria = wcag.map(id => ({ id, level: WCAG_CRITERIA[id].level, version: WCAG_CRITERIA[id].version }));
+
+  // A rule applies from the least demanding level it can fail
+  return { rule, criteria, level: lowestLevel(wcag) || entry.level, bestPractice };
+}
+
+/**
+ * Whether a success criterion is part of a standard
+ * @param criterion Success criterion, e.g. "2.5.8"
+ * @param standard Standard; defaults to WCAG 2.1
+ */
+export function inStandard(criterion: string, standard: Standard = DEFAULT_STANDARD): boolean {
+  if (!Object.prototype.hasOwnProperty.call(WCAG_CRITERIA, criterion)) return false;
+
+  const { version, removedIn } = WCAG_CRITERIA[criterion];
+  const target = VERSION_ORDER.indexOf(profile(standard).wcag);
+  return VERSION_ORDER.indexOf(version) <= target && (!removedIn || VERSION_ORDER.indexOf(removedIn) > target);
+}
+
+/**
+ * Whether a rule id is checked at the given conformance level and standard
+ * @param rule Rule id
+ * @param options Scanner options; level defaults to AA, standard to WCAG 2.1, and best
+ * practices are included unless disabled
+ */
+export function ruleAppliesAt(
+  rule: string,
+  options: Pick<ScannerOptions, 'level' | 'standard' | 'bestPractices'>,
+): boolean {
+  const metadata = getRuleMetadata(rule);
+  // Rules without metadata, such as custom rules, always run
+  if (!metadata) return true;
+  if (metadata.bestPractice && options.bestPractices === false) return false;
+  if (metadata.criteria.length === 0) {
+    return !metadata.level || LEVEL_ORDER.indexOf(metadata.level) <= LEVEL_ORDER.indexOf(selectedLevel(options));
+  }
+
+  const criteria = metadata.criteria.filter(criterion => inStandard(criterion.id, options.standard));
+  const level = lowestLevel(criteria.map(criterion => criterion.id));
+  return level !== undefined && LEVEL_ORDER.indexOf(level) <= LEVEL_ORDER.indexOf(selectedLevel(options));
+}
+
+/**
+ * Keep only the results of rule ids that apply at the selected level and standard, and
+ * record each result's level, whether it is a best practice and, when a standard is
+ * chosen, its clause numbers in that standard
+ * @param results Scan results as reported by the rule modules
+ * @param options Scanner options
+ * @returns New scan results
+ */
+export function applyLevel<T extends ScanResults>(results: T, options: ScannerOptions): T {
+  const standard = options.standard;
+  const annotate = <R extends Pass | Violation | Warning | Incomplete>(items: R[]): R[] => items
+    .filter(item => ruleAppliesAt(item.rule, options))
+    .map(item => {
+      const metadata = getRuleMetadata(item.rule);
+      if (!metadata) return item;
+
+      // Prefer the criteria the result itself cites, e.g. 1.4.6 for contrast checked at AAA
+      const cited = ((item as Violation).wcag || []).filter(id => inStandard(id, standard));
+      const criteria = cited.length > 0
+        ? cited
+        : metadata.criteria.map(criterion => criterion.id).filter(id => inStandard(id, standard));
+      const level = lowestLevel(criteria) || (metadata.criteria.length === 0 ? metadata.level : undefined);
+      return {
+        ...item,
+        ...(level ? { level } : {}),
+        ...(metadata.bestPractice ? { bestPractice: true } : {}),
+        ...(standard && criteria.length > 0 ? { clauses: criteria.map(profile(standard).clause) } : {}),
+      };
+    });
+
+  return {
+    ...results,
+    passes: annotate(results.passes),
+    violations: annotate(results.violations),
+    warnings: annotate(results.warnings),
+    incomplete: annotate(results.incomplete),
+  };
+}
+
+/**
+ * Level to check against: the selected level, capped by what the standard requires
+ */
+function selectedLevel(options: Pick<ScannerOptions, 'level' | 'standard'>): WcagLevel {
+  const level = options.level && LEVEL_ORDER.includes(options.level) ? options.level : 'AA';
+  const maxLevel = profile(options.standard).maxLevel;
+  return LEVEL_ORDER.indexOf(level) <= LEVEL_ORDER.indexOf(maxLevel) ? level : maxLevel;
+}
+
+function profile(standard: Standard = DEFAULT_STANDARD): StandardProfile {
+  return Object.prototype.hasOwnProperty.call(STANDARDS, standard) ? STANDARDS[standard] : STANDARDS[DEFAULT_STANDARD];
+}
+
+function lowestLevel(criteria: string[]): WcagLevel | undefined {
+  const levels = criteria
+    .filter(id => Object.prototype.hasOwnProperty.call(WCAG_CRITERIA, id))
+    .map(id => LEVEL_ORDER.indexOf(WCAG_CRITERIA[id].level));
+  return levels.length > 0 ? LEVEL_ORDER[Math.min(...levels)] : undefined;
+}
+
+/**
+ * Describe a result's level for reports, e.g. "AA" or "A (best practice)"
+ * @returns The description, or an empty string when the result has no level information
+ */
+export function describeLevel(item: { level?: WcagLevel; bestPractice?: boolean }): string {
+  if (item.level) return item.bestPractice ? `${item.level} (best practice)` : item.level;
+  return item.bestPractice ? 'Best practice' : '';
+}
+
+/**
+ * Describe a result's clauses in the chosen standard for reports, e.g. "EN 301 549 9.1.1.1"
+ * @returns The description, or an empty string when no standard was chosen or the result has no clauses
+ */
+export function describeClauses(item: { clauses?: string[] }, standard?: Standard): string {
+  if (!standard || !item.clauses || item.clauses.length === 0) return '';
+  return `${profile(standard).name} ${item.clauses.join(', ')}`;
+}
@@ -1,5 +1,6 @@
 import { Request, Response, NextFunction } from 'express';
 import { WCAGScanner, ScannerOptions, ScanResults } from '../index';
+import { loadConfig, mergeOptions } from '../config';
 
 /**
  * Options for the Express middleware
@@ -17,17 +18,19 @@ export interface ExpressMiddlewareOptions extends ScannerOptions {
 
 /**
  * Create Express middleware for scanning HTML responses for accessibility issues
- * @param options Middleware options
+ * @param options Middleware options, merged over any discovered config file
  * @returns Express middleware function
  */
 export function createMiddleware(options: ExpressMiddlewareOptions = {}) {
-  const defaultOptions: ExpressMiddlewareOptions = {
-    enabled: process.env.NODE_ENV !== 'production', // Disable in production by default
-    level: 'AA',
-    headerName: undefined,
-    inlineReport: false,
-    ...options
-  };
+  const defaultOptions = mergeOptions<ExpressMiddlewareOptions>(
+    {
+      enabled: process.env.NODE_ENV !== 'production', // Disable in production by default
+      level: 'AA',
+      inlineReport: false,
+    },
+    loadConfig({ configPath: options.config }),
+    options
+  );
   
   return async function wcagScannerMiddleware(req: Request, res: Response, next: NextFunction) {
     // Skip if disabled or non-HTML request
@@ -44,7 +47,8 @@ export function createMiddleware(options: ExpressMiddlewareOptions = {}) {
       if (typeof body === 'string' && isHtmlResponse(res) && looksLikeHtmlDocument(body)) {
         try {
           // Create scanner
-          const scanner = new WCAGScanner(defaultOptions);
+          // Config was already merged above, so skip discovery per request
+          const scanner = new WCAGScanner({ ...defaultOptions, config: false });
           const response = this;
 
           // If we do not need to mutate the response or set headers, scan after sending.
@@ -0,0 +1,64 @@
+import { ImpactLevel, Incomplete, RuleOverride, RuleOverrides, ScanResults, Violation, Warning } from './types';
+
+interface NormalizedOverride {
+  enabled: boolean;
+  type?: 'violation' | 'warning';
+  impact?: ImpactLevel;
+}
+
+function normalizeOverride(override: RuleOverride): NormalizedOverride {
+  if (override === 'off') return { enabled: false };
+  return {
+    enabled: override.enabled !== false,
+    type: override.type,
+    impact: override.impact,
+  };
+}
+
+/**
+ * Apply per-rule-id overrides to scan results. Disabled rule ids are removed
+ * from every list, violations and warnings can be moved between lists, and
+ * violations, warnings and incomplete results can be given a different impact.
+ * @param results Scan results as reported by the rule modules
+ * @param overrides Overrides keyed by rule id
+ * @returns New scan results with the overrides applied
+ */
+export function applyRuleOverrides<T extends ScanResults>(results: T, overrides?: RuleOverrides): T {
+  if (!overrides || Object.keys(overrides).length === 0) return results;
+
+  const lookup = (rule: string): NormalizedOverride | undefined =>
+    Object.prototype.hasOwnProperty.call(overrides, rule) ? normalizeOverride(overrides[rule]) : undefined;
+
+  const violations: Violation[] = [];
+  const warnings: Warning[] = [];
+
+  const place = (item: Violation | Warning, defaultType: 'violation' | 'warning') => {
+    const override = lookup(item.rule);
+    if (override && !override.enabled) return;
+
+    const updated = override?.impact ? { ...item, impact: override.impact } : item;
+    if ((override?.type || defaultType) === 'violation') {
+      violations.push(updated);
+    } else {
+      warnings.push(updated);
+    }
+  };
+
+  results.violations.forEach(item => place(item, 'violation'));
+  results.warnings.forEach(item => place(item, 'warning'));
+
+  const incomplete: Incomplete[] = [];
+  results.incomplete.forEach(item => {
+    const override = lookup(item.rule);
+    if (override && !override.enabled) return;
+    incomplete.push(override?.impact ? { ...item, impact: override.impact } : item);
+  });
+
+  return {
+    ...results,
+    passes: results.passes.filter(item => lookup(item.rule)?.enabled !== false),
+    violations,
+    warnings,
+    incomplete,
+  };
+}
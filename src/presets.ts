@@ -1,6 +1,6 @@
-import { ScannerOptions } from '../types';
+import { ScannerOptions } from './types';
 
-export const FAST_RULES = ['images', 'contrast', 'forms', 'aria', 'structure', 'keyboard'] as const;
+export const FAST_RULES = ['images', 'contrast', 'forms', 'aria', 'structure', 'keyboard', 'idReferences'] as const;
 export const FULL_RULES = [...FAST_RULES, 'backgroundImages'] as const;
 export const RULE_PRESETS = {
   fast: [...FAST_RULES],
@@ -5,11 +5,12 @@ import React, {
   useRef,
   CSSProperties,
 } from 'react';
-import { scanBrowserPage, BrowserScanResults, AnnotatedViolation, AnnotatedWarning } from './browserScanner';
+import { scanBrowserPage, BrowserScanResults, AnnotatedViolation, AnnotatedWarning, AnnotatedIncomplete } from './browserScanner';
 import { getAiSuggestion, getStoredApiKey, setStoredApiKey, AiSuggestion } from './gemini';
+import { describeRuleErrors } from '../ruleErrors';
 import { RulePreset, ScannerOptions } from '../types';
 
-type Tab    = 'violations' | 'warnings';
+type Tab    = 'violations' | 'warnings' | 'incomplete';
 type View   = 'list' | 'settings';
 type Impact = 'all' | 'critical' | 'serious' | 'moderate' | 'minor';
 
@@ -19,6 +20,8 @@ export interface WcagDevOverlayProps {
   rules?:    string[];
   position?: 'bottom-right' | 'bottom-left';
   debounce?: number;
+  /** Extra scanner options forwarded to every scan */
+  options?:  ScannerOptions;
 }
 
 // ─── Impact colours ───────────────────────────────────────────────────────────
@@ -200,7 +203,7 @@ function clearAllHighlights() {
 
 // ─── ViolationCard ─────────────────────────────────────────────────────────────
 interface CardProps {
-  item: AnnotatedViolation | AnnotatedWarning;
+  item: AnnotatedViolation | AnnotatedWarning | AnnotatedIncomplete;
   pinned: boolean;
   onPin: (el: Element) => void;
   apiKey: string;
@@ -212,6 +215,7 @@ const ViolationCard: React.FC<CardProps> = ({ item, pinned, onPin, apiKey }) =>
   const [suggestion, setSuggestion] = useState<AiSuggestion | null>(null);
   const [aiError, setAiError]     = useState('');
   const { color, bg, border }     = theme(item.impact);
+  const help = 'review' in item ? item.review : item.help;
 
   const fetchAi = async () => {
     if (!apiKey || aiState === 'loading') return;
@@ -286,9 +290,15 @@ const ViolationCard: React.FC<CardProps> = ({ item, pinned, onPin, apiKey }) =>
             </code>
           )}
 
-          {item.help && (
+          {'reason' in item && (
+            <p style={{ margin: '7px 0 0', fontSize: 11, color: '#475569', lineHeight: 1.5 }}>
+              {item.reason}
+            </p>
+          )}
+
+          {help && (
             <p style={{ margin: '7px 0 0', fontSize: 11, color: '#475569', fontStyle: 'italic', lineHeight: 1.5 }}>
-              {item.help}
+              {help}
             </p>
           )}
 
@@ -456,7 +466,7 @@ const SettingsPanel: React.FC<SettingsProps> = ({ apiKey, preset, onSave, onPres
 
 // ─── Main Overlay ──────────────────────────────────────────────────────────────
 export const WcagDevOverlay: React.FC<WcagDevOverlayProps> = ({
-  level = 'AA', preset = 'fast', rules, position = 'bottom-right', debounce = 750,
+  level = 'AA', preset = 'fast', rules, position = 'bottom-right', debounce = 750, options,
 }) => {
   const [open, setOpen]         = useState(() => { try { return sessionStorage.getItem('wcag-open') === '1'; } catch { return false; } });
   const [view, setView]         = useState<View>('list');
@@ -482,6 +492,15 @@ export const WcagDevOverlay: React.FC<WcagDevOverlayProps> = ({
   const pendingScanRef = useRef(false);
   const scanTokenRef = useRef(0);
 
+  // Inline `options` and `rules` props are new objects on every render; rescan only when
+  // their contents change, and read the latest values from refs
+  const optionsRef = useRef(options);
+  const rulesRef   = useRef(rules);
+  optionsRef.current = options;
+  rulesRef.current   = rules;
+  const optionsKey = JSON.stringify(options ?? null);
+  const rulesKey   = JSON.stringify(rules ?? null);
+
   // ── Persist open state ────────────────────────────────────────────────────
   useEffect(() => {
     try { sessionStorage.setItem('wcag-open', open ? '1' : '0'); } catch { /* */ }
@@ -505,7 +524,14 @@ export const WcagDevOverlay: React.FC<WcagDevOverlayProps> = ({
     scanningRef.current = true;
     setScanning(true);
     try {
-      const res = await scanBrowserPage({ level, preset: activePreset, rules } as ScannerOptions);
+      // Rule failures are shown as a banner rather than aborting the scan
+      const res = await scanBrowserPage({
+        ...optionsRef.current,
+        level,
+        preset: activePreset,
+        rules: rulesRef.current,
+        failOnRuleError: false,
+      } as ScannerOptions);
       if (token === scanTokenRef.current) {
         setResults(res);
         setLastScan(new Date());
@@ -521,7 +547,7 @@ export const WcagDevOverlay: React.FC<WcagDevOverlayProps> = ({
         }
       }, 300);
     }
-  }, [activePreset, level, rules]);
+  }, [activePreset, level, rulesKey, optionsKey]);
 
   useEffect(() => { scan(); }, [scan]);
 
@@ -583,10 +609,12 @@ export const WcagDevOverlay: React.FC<WcagDevOverlayProps> = ({
   // ── Derived ───────────────────────────────────────────────────────────────
   const vCount = results?.violations.length ?? 0;
   const wCount = results?.warnings.length   ?? 0;
+  const iCount = results?.incomplete.length ?? 0;
   const pCount = results?.passes.length     ?? 0;
+  const scanIncomplete = results ? describeRuleErrors(results) : '';
   const isLeft = position === 'bottom-left';
 
-  const rawItems = tab === 'violations' ? results?.violations : results?.warnings;
+  const rawItems = tab === 'violations' ? results?.violations : tab === 'warnings' ? results?.warnings : results?.incomplete;
   const items    = filter === 'all' ? rawItems : rawItems?.filter(i => i.impact === filter);
   const isEmpty  = !items || items.length === 0;
 
@@ -683,14 +711,26 @@ export const WcagDevOverlay: React.FC<WcagDevOverlayProps> = ({
               <div style={{ display: 'flex', gap: 6, padding: '8px 12px', borderBottom: '1px solid #f1f5f9', background: '#f8fafc', flexShrink: 0 }}>
                 <span style={chip('#dc2626', vCount)}>✗ {vCount} violation{vCount !== 1 ? 's' : ''}</span>
                 <span style={chip('#ea580c', wCount)}>⚠ {wCount} warning{wCount !== 1 ? 's' : ''}</span>
+                <span style={chip('#0891b2', iCount)}>? {iCount} to review</span>
                 <span style={chip('#16a34a', pCount)}>✓ {pCount} pass{pCount !== 1 ? 'es' : ''}</span>
                 {apiKey && <span style={{ ...chip('#7c3aed', 1), marginLeft: 'auto' }} title="AI suggestions enabled">✨ AI</span>}
               </div>
 
+              {/* Rule failures */}
+              {scanIncomplete && (
+                <div role="alert" style={{ padding: '8px 12px', background: '#fef2f2', borderBottom: '1px solid #fecaca', color: '#b91c1c', fontSize: 11, flexShrink: 0 }}>
+                  <div style={{ fontWeight: 700 }}>⚠ {scanIncomplete}</div>
+                  {results?.errors.map((error, i) => (
+                    <div key={i} style={{ marginTop: 3, fontFamily: 'monospace', wordBreak: 'break-word' }}>{error.rule}: {error.message}</div>
+                  ))}
+                </div>
+              )}
+
               {/* Tabs + filter */}
               <div style={{ display: 'flex', alignItems: 'center', borderBottom: '1px solid #f1f5f9', padding: '0 12px', gap: 2, flexShrink: 0 }}>
                 <button style={tabBtn(tab === 'violations')} onClick={() => setTab('violations')}>Violations ({vCount})</button>
                 <button style={tabBtn(tab === 'warnings')}  onClick={() => setTab('warnings')}>Warnings ({wCount})</button>
+                <button style={tabBtn(tab === 'incomplete')} onClick={() => setTab('incomplete')}>Needs review ({iCount})</button>
                 <select
                   style={{ marginLeft: 'auto', fontSize: 10, border: '1px solid #e2e8f0', borderRadius: 5, padding: '3px 6px', color: '#475569', background: '#fff', cursor: 'pointer', outline: 'none' }}
                   value={filter}
@@ -714,7 +754,7 @@ export const WcagDevOverlay: React.FC<WcagDevOverlayProps> = ({
                 {!scanning && isEmpty && (
                   <div style={{ textAlign: 'center', padding: '28px 0' }}>
                     <div style={{ fontSize: 28, marginBottom: 8 }}>{tab === 'violations' ? '✅' : '🔕'}</div>
-                    <div style={{ color: '#64748b', fontSize: 12, fontWeight: 500 }}>No {tab} found</div>
+                    <div style={{ color: '#64748b', fontSize: 12, fontWeight: 500 }}>{tab === 'incomplete' ? 'Nothing to review' : `No ${tab} found`}</div>
                     {filter !== 'all' && (
                       <button style={{ marginTop: 6, fontSize: 11, color: '#7c3aed', background: 'none', border: 'none', cursor: 'pointer' }} onClick={() => setFilter('all')}>
                         Clear filter
@@ -1,4 +1,4 @@
-import { ScannerOptions, ScanResults, Violation, Warning, Pass } from '../types';
+import { ScannerOptions, ScanResults, Violation, Warning, Incomplete, Pass, RuleError, SuppressedResult } from '../types';
 import imagesRule from '../rules/images';
 import backgroundImagesRule from '../rules/backgroundImages';
 import contrastRule from '../rules/contrast';
@@ -6,7 +6,13 @@ import formsRule from '../rules/forms';
 import ariaRule from '../rules/aria';
 import structureRule from '../rules/structure';
 import keyboardRule from '../rules/keyboard';
-import { FAST_RULES, resolveRuleNames } from '../rules/presets';
+import idReferencesRule from '../rules/idReferences';
+import { FAST_RULES, resolveRuleNames } from '../presets';
+import { applyLevel } from '../metadata';
+import { applyRuleOverrides } from '../overrides';
+import { applySuppressions } from '../suppressions';
+import { RuleFailureError, toRuleError } from '../ruleErrors';
+import { clearIdCache } from '../utils/elements';
 
 export interface AnnotatedViolation extends Violation {
   domElement?: Element;
@@ -21,10 +27,22 @@ export interface AnnotatedWarning extends Warning {
   elementSelector?: string;
 }
 
+export interface AnnotatedIncomplete extends Incomplete {
+  domElement?: Element;
+  elementPath?: string;
+  elementSelector?: string;
+}
+
 export interface BrowserScanResults {
   violations: AnnotatedViolation[];
   warnings: AnnotatedWarning[];
+  /** Checks that need human review */
+  incomplete: AnnotatedIncomplete[];
   passes: Pass[];
+  /** Issues hidden by disable comments or data-wcag-ignore attributes */
+  suppressed?: SuppressedResult[];
+  /** Rules that threw; when not empty the scan is incomplete */
+  errors: RuleError[];
   duration: number;
 }
 
@@ -36,6 +54,7 @@ const RULES: Record<string, { check: (d: Document, w: Window, o: ScannerOptions)
   aria: ariaRule,
   structure: structureRule,
   keyboard: keyboardRule,
+  idReferences: idReferencesRule,
 };
 
 export async function scanBrowserPage(options: ScannerOptions = {}): Promise<BrowserScanResults> {
@@ -43,7 +62,11 @@ export async function scanBrowserPage(options: ScannerOptions = {}): Promise<Bro
   const ruleNames = resolveRuleNames(options, FAST_RULES);
   const violations: Violation[] = [];
   const warnings: Warning[] = [];
+  const incomplete: Incomplete[] = [];
   const passes: Pass[] = [];
+  const errors: RuleError[] = [];
+  // The live page may have changed since the last scan
+  clearIdCache(document);
   const overlayRoot = document.querySelector('[data-wcag-overlay-root="true"]');
   const overlayParent = overlayRoot?.parentNode ?? null;
   const overlayNextSibling = overlayRoot?.nextSibling ?? null;
@@ -61,9 +84,10 @@ export async function scanBrowserPage(options: ScannerOptions = {}): Promise<Bro
         const res = await rule.check(document, window as unknown as Window, options);
         violations.push(...(res.violations || []));
         warnings.push(...(res.warnings || []));
+        incomplete.push(...(res.incomplete || []));
         passes.push(...(res.passes || []));
-      } catch {
-        // rule failed in browser context — skip silently
+      } catch (error) {
+        errors.push(toRuleError(name, error));
       }
     }
   } finally {
@@ -72,6 +96,15 @@ export async function scanBrowserPage(options: ScannerOptions = {}): Promise<Bro
     }
   }
 
+  if (errors.length > 0 && options.failOnRuleError) {
+    throw new RuleFailureError(errors);
+  }
+
+  const results = applySuppressions(
+    applyRuleOverrides(applyLevel({ violations, warnings, incomplete, passes }, options), options.ruleOverrides),
+    document,
+  );
+
   // Exclude elements that live inside the WCAG overlay itself
   const overlaySurface = document.querySelector('[data-wcag-overlay="true"]');
   const isInOverlay = (el: Element | null): boolean =>
@@ -80,33 +113,38 @@ export async function scanBrowserPage(options: ScannerOptions = {}): Promise<Bro
       (overlayRoot != null && overlayRoot.contains(el))
     );
 
-  const annotate = <T extends Violation | Warning>(item: T): T & {
+  const annotate = <T extends Violation | Warning | Incomplete>(item: T): T & {
     domElement?: Element;
     elementPath?: string;
     elementSelector?: string;
   } => {
-    const el = findElement(item, document);
+    // Rules record a selector for the element they inspected; older results fall back to a lookup
+    const el = (item.selector ? querySelectorSafe(item.selector, document) : null) ?? findElement(item, document);
     if (!el) return { ...item };
     return {
       ...item,
       domElement: el,
       elementPath: getElementPath(el),
-      elementSelector: getNthChildSelector(el),
+      elementSelector: item.selector ?? getNthChildSelector(el),
     };
   };
 
   return {
-    violations: dedupeIssues(violations.map(annotate)).filter(v => !isInOverlay(v.domElement ?? null)),
-    warnings: dedupeIssues(warnings.map(annotate)).filter(w => !isInOverlay(w.domElement ?? null)),
-    passes: dedupePasses(passes),
+    violations: dedupeIssues(results.violations.map(annotate)).filter(v => !isInOverlay(v.domElement ?? null)),
+    warnings: dedupeIssues(results.warnings.map(annotate)).filter(w => !isInOverlay(w.domElement ?? null)),
+    incomplete: dedupeIssues(results.incomplete.map(annotate)).filter(i => !isInOverlay(i.domElement ?? null)),
+    passes: dedupePasses(results.passes),
+    suppressed: results.suppressed ?? [],
+    errors,
     duration: Math.round(performance.now() - start),
   };
 }
 
-function dedupeIssues<T extends Violation | Warning>(items: T[]): T[] {
+function dedupeIssues<T extends Violation | Warning | Incomplete>(items: T[]): T[] {
   const seen = new Set<string>();
   return items.filter(item => {
-    const key = [
+    // Key on element identity when the rule recorded one
+    const key = item.selector ? [item.rule, item.description, item.selector].join('::') : [
       item.rule,
       item.description,
       item.impact,
@@ -186,7 +224,15 @@ export function getElementPath(el: Element): string {
   return parts.join(' › ');
 }
 
-export function findElement(item: Violation | Warning, doc: Document): Element | null {
+function querySelectorSafe(selector: string, doc: Document): Element | null {
+  try {
+    return doc.querySelector(selector);
+  } catch {
+    return null;
+  }
+}
+
+export function findElement(item: Violation | Warning | Incomplete, doc: Document): Element | null {
   const { element: info, snippet } = item;
 
   // 1. ID — most reliable
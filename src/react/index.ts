@@ -1,8 +1,9 @@
 export { WcagDevOverlay } from './WcagDevOverlay';
 export type { WcagDevOverlayProps } from './WcagDevOverlay';
 export { scanBrowserPage } from './browserScanner';
-export type { BrowserScanResults, AnnotatedViolation, AnnotatedWarning } from './browserScanner';
+export type { BrowserScanResults, AnnotatedViolation, AnnotatedWarning, AnnotatedIncomplete } from './browserScanner';
 export { initWcagOverlay } from './init';
+export type { InitWcagOverlayOptions } from './init';
 export { getAiSuggestion, getStoredApiKey, setStoredApiKey } from './gemini';
 export type { AiSuggestion } from './gemini';
-export { FAST_RULES, FULL_RULES, RULE_PRESETS, resolveRuleNames } from '../rules/presets';
+export { FAST_RULES, FULL_RULES, RULE_PRESETS, resolveRuleNames } from '../presets';
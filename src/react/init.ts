@@ -15,11 +15,23 @@
 import React from 'react';
 import { WcagDevOverlay } from './WcagDevOverlay';
 import type { WcagDevOverlayProps } from './WcagDevOverlay';
+import { mergeOptions, validateConfig } from '../config/validate';
+import type { ScannerOptions } from '../types';
 
-export function initWcagOverlay(options: WcagDevOverlayProps = {}): void {
+export interface InitWcagOverlayOptions extends WcagDevOverlayProps {
+  /**
+   * Shared scanner config, e.g. the contents of wcag-scanner.config.json.
+   * Explicit overlay options take precedence over it.
+   */
+  config?: ScannerOptions;
+}
+
+export function initWcagOverlay(options: InitWcagOverlayOptions = {}): void {
   if (typeof window === 'undefined' || typeof document === 'undefined') return;
   if (typeof process !== 'undefined' && process.env.NODE_ENV === 'production') return;
 
+  const props = resolveOverlayProps(options);
+
   const mount = () => {
     if (document.querySelector('[data-wcag-overlay-root]')) return;
 
@@ -27,7 +39,7 @@ export function initWcagOverlay(options: WcagDevOverlayProps = {}): void {
     container.setAttribute('data-wcag-overlay-root', 'true');
     document.body.appendChild(container);
 
-    const el = React.createElement(WcagDevOverlay, options);
+    const el = React.createElement(WcagDevOverlay, props);
 
     // React 18+: use createRoot from react-dom/client
     try {
@@ -56,3 +68,22 @@ export function initWcagOverlay(options: WcagDevOverlayProps = {}): void {
     mount();
   }
 }
+
+/**
+ * Merge the shared config under the explicit overlay options
+ * @param options Options passed to initWcagOverlay
+ * @returns Props for the overlay component
+ */
+function resolveOverlayProps({ config, ...props }: InitWcagOverlayOptions): WcagDevOverlayProps {
+  if (!config) return props;
+
+  const shared = validateConfig(config, 'initWcagOverlay config');
+  const scannerOptions = mergeOptions<ScannerOptions>(shared, props.options);
+  return {
+    ...props,
+    level: props.level ?? scannerOptions.level,
+    preset: props.preset ?? scannerOptions.preset,
+    rules: props.rules ?? scannerOptions.rules,
+    options: scannerOptions,
+  };
+}
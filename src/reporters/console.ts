@@ -1,4 +1,7 @@
-import { ScanResults, ScannerOptions, Violation, Warning, Pass } from "../types";
+import { describeRuleErrors } from "../ruleErrors";
+import { describeClauses, describeLevel } from "../metadata";
+import { Incomplete, ScanResults, ScannerOptions, Standard, Violation, Warning, Pass } from "../types";
+import { formatLocation } from "../utils/locations";
 
 // Node only imports
 let chalk: any;
@@ -26,7 +29,7 @@ try {
  * @returns Formatted console output string
  */
 export function format(results: ScanResults, options: ScannerOptions = {}): string {
-    const { violations, warnings, passes } = results;
+    const { violations, warnings, incomplete, passes } = results;
     let output = '\n';
 
     // Summary section
@@ -34,9 +37,38 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
     output += chalk.grey('-'.repeat(50) + '\n');
     output += `${chalk.green(`✓ Passes: ${passes.length}`)}`;
     output += `${chalk.yellow(`⚠ Warnings: ${warnings.length}`)}`;
+    output += `${chalk.cyan(`? Needs review: ${incomplete.length}`)}`;
     output += `${chalk.red(`✗ Violations: ${violations.length}`)}`;
+    const notes: string[] = [];
+    if (results.suppressed && results.suppressed.length > 0) {
+        notes.push(chalk.grey(`Suppressed: ${results.suppressed.length}`));
+    }
+    if (results.baseline) {
+        const { baseline } = results;
+        let note = chalk.cyan(`Baseline: ${baseline.new} new, ${baseline.existing} existing, ${baseline.fixed.length} fixed`);
+        if (options.onlyNewViolations && baseline.existing > 0) {
+            note += chalk.grey(` (${baseline.existing} existing not shown)`);
+        }
+        notes.push(note);
+    }
+    if (notes.length > 0) {
+        output += `\n${notes.join('\n')}\n`;
+    }
     output += chalk.grey('-'.repeat(50) + '\n\n');
 
+    // Rules that threw left gaps in the results, so say so before anything else
+    const scanIncomplete = describeRuleErrors(results);
+    if (scanIncomplete) {
+        output += chalk.bold.red(`⚠ ${scanIncomplete}\n`);
+        (results.errors || []).forEach(error => {
+            output += chalk.red(`  ${error.rule}: ${error.message}\n`);
+            if (options.verbose && error.stack) {
+                output += chalk.grey(`${error.stack.split('\n').slice(1).join('\n')}\n`);
+            }
+        });
+        output += '\n';
+    }
+
     // Group violations by impact
     if (violations.length > 0) {
         output += chalk.bold.red('VIOLATIONS\n');
@@ -52,7 +84,7 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
                 output += chalk.bold(`\n${getImpactIcon(impact)} ${impact.toUpperCase()} (${impactGroups[impact].length})`);
 
                 impactGroups[impact].forEach((violation, index) => {
-                    output += formatViolation(violation, index + 1, options.verbose);
+                    output += formatViolation(violation, index + 1, options.verbose, results.url, options.standard);
                 });
             }
         });
@@ -63,7 +95,16 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
         output += chalk.bold.yellow('\nWARNINGS\n');
         
         warnings.forEach((warning, index) => {
-            output += formatWarning(warning, index + 1, options.verbose);
+            output += formatWarning(warning, index + 1, options.verbose, results.url, options.standard);
+        });
+    }
+
+    // Needs review section
+    if (incomplete.length > 0) {
+        output += chalk.bold.cyan('\nNEEDS REVIEW\n');
+
+        incomplete.forEach((item, index) => {
+            output += formatIncomplete(item, index + 1, options.verbose, results.url, options.standard);
         });
     }
 
@@ -100,18 +141,31 @@ function groupByImpact(violations: Violation[]): Record<string, Violation[]> {
    * @param violation Violation object
    * @param index Violation number
    * @param verbose Show verbose details
+   * @param url URL of the scanned document
+   * @param standard Standard whose clause numbers are shown
    * @returns Formatted violation string
    */
-  function formatViolation(violation: Violation, index: number, verbose = false): string {
+  function formatViolation(violation: Violation, index: number, verbose = false, url?: string, standard?: Standard): string {
     let output = '';
     
     // Basic info
     output += chalk.red(`\n${index}. ${violation.description}\n`);
+
+    // Source location
+    if (violation.location) {
+      output += chalk.cyan(`   ${formatLocation(url, violation.location)}\n`);
+    }
     
     // WCAG criteria
     if (violation.wcag && violation.wcag.length > 0) {
       output += chalk.gray(`   WCAG: ${violation.wcag.join(', ')}\n`);
     }
+    if (describeLevel(violation)) {
+      output += chalk.gray(`   Level: ${describeLevel(violation)}\n`);
+    }
+    if (describeClauses(violation, standard)) {
+      output += chalk.gray(`   Clauses: ${describeClauses(violation, standard)}\n`);
+    }
     
     // Element info
     if (violation.element) {
@@ -144,18 +198,31 @@ function groupByImpact(violations: Violation[]): Record<string, Violation[]> {
    * @param warning Warning object
    * @param index Warning number
    * @param verbose Show verbose details
+   * @param url URL of the scanned document
+   * @param standard Standard whose clause numbers are shown
    * @returns Formatted warning string
    */
-  function formatWarning(warning: Warning, index: number, verbose = false): string {
+  function formatWarning(warning: Warning, index: number, verbose = false, url?: string, standard?: Standard): string {
     let output = '';
     
     // Basic info
     output += chalk.yellow(`\n${index}. ${warning.description}\n`);
+
+    // Source location
+    if (warning.location) {
+      output += chalk.cyan(`   ${formatLocation(url, warning.location)}\n`);
+    }
     
     // WCAG criteria
     if (warning.wcag && warning.wcag.length > 0) {
       output += chalk.gray(`   WCAG: ${warning.wcag.join(', ')}\n`);
     }
+    if (describeLevel(warning)) {
+      output += chalk.gray(`   Level: ${describeLevel(warning)}\n`);
+    }
+    if (describeClauses(warning, standard)) {
+      output += chalk.gray(`   Clauses: ${describeClauses(warning, standard)}\n`);
+    }
     
     // Element info
     if (warning.element) {
@@ -178,6 +245,56 @@ function groupByImpact(violations: Violation[]): Record<string, Violation[]> {
     return output;
   }
   
+  /**
+   * Format a single incomplete result for console output
+   * @param item Incomplete result
+   * @param index Result number
+   * @param verbose Show verbose details
+   * @param url URL of the scanned document
+   * @param standard Standard whose clause numbers are shown
+   * @returns Formatted result string
+   */
+  function formatIncomplete(item: Incomplete, index: number, verbose = false, url?: string, standard?: Standard): string {
+    let output = '';
+
+    // Basic info
+    output += chalk.cyan(`\n${index}. ${item.description}\n`);
+
+    // Source location
+    if (item.location) {
+      output += chalk.cyan(`   ${formatLocation(url, item.location)}\n`);
+    }
+
+    // WCAG criteria
+    if (item.wcag && item.wcag.length > 0) {
+      output += chalk.gray(`   WCAG: ${item.wcag.join(', ')}\n`);
+    }
+    if (describeLevel(item)) {
+      output += chalk.gray(`   Level: ${describeLevel(item)}\n`);
+    }
+    if (describeClauses(item, standard)) {
+      output += chalk.gray(`   Clauses: ${describeClauses(item, standard)}\n`);
+    }
+
+    // Element info
+    if (item.element) {
+      const element = item.element;
+      output += chalk.gray(`   Element: ${element.tagName || 'unknown'}` +
+                        (element.id ? ` #${element.id}` : '') +
+                        (element.className ? ` .${element.className}` : '') + '\n');
+    }
+
+    // Code snippet (if verbose)
+    if (item.snippet && verbose) {
+      output += chalk.gray(`   Code: ${formatCodeSnippet(item.snippet)}\n`);
+    }
+
+    output += chalk.gray(`   Reason: ${item.reason}\n`);
+    output += chalk.gray(`   Review: ${item.review}\n`);
+
+    return output;
+  }
+
   /**
    * Format a single pass for console output
    * @param pass Pass object
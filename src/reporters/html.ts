@@ -1,29 +1,19 @@
-import { ScanResults, ScannerOptions, Violation, Warning, Pass } from '../types';
+import { describeRuleErrors } from '../ruleErrors';
+import { describeClauses, describeLevel, STANDARDS } from '../metadata';
+import { Incomplete, RuleError, ScanResults, ScannerOptions, SiteResults, Standard, Violation, Warning, Pass } from '../types';
+import { formatLocation } from '../utils/locations';
 
 /**
- * Format scan results as HTML
- * @param results Scanner results object
- * @param options Scanner options used for the scan
- * @returns HTML report as a string
+ * Shared stylesheet for page and site reports
  */
-export function format(results: ScanResults, options: ScannerOptions = {}): string {
-  const { violations, warnings, passes } = results;
-
-  // Generate HTML
-  let html = `
-    <!DOCTYPE html>
-    <html lang="en">
-    <head>
-      <meta charset="UTF-8">
-      <meta name="viewport" content="width=device-width, initial-scale=1.0">
-      <title>WCAG Accessibility Report</title>
-      <style>
+const REPORT_STYLES = `
         :root {
           --color-critical: #e53935;
           --color-serious: #f57c00;
           --color-moderate: #fbc02d;
           --color-minor: #039be5;
           --color-pass: #43a047;
+          --color-review: #00838f;
           --color-text: #333;
           --color-background: #fff;
           --color-card: #f5f5f5;
@@ -81,6 +71,11 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
           border-left: 4px solid var(--color-moderate);
         }
         
+        .summary-card.incomplete {
+          background-color: rgba(0, 131, 143, 0.1);
+          border-left: 4px solid var(--color-review);
+        }
+        
         .summary-card.passes {
           background-color: rgba(67, 160, 71, 0.1);
           border-left: 4px solid var(--color-pass);
@@ -100,6 +95,10 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
           color: var(--color-moderate);
         }
         
+        .summary-card.incomplete .summary-number {
+          color: var(--color-review);
+        }
+        
         .summary-card.passes .summary-number {
           color: var(--color-pass);
         }
@@ -175,6 +174,10 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
           border-left-color: var(--color-moderate);
         }
         
+        .code-block.incomplete {
+          border-left-color: var(--color-review);
+        }
+        
         .code-block.pass {
           border-left-color: var(--color-pass);
         }
@@ -264,6 +267,48 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
           background-color: white;
         }
         
+        .site-table {
+          width: 100%;
+          border-collapse: collapse;
+          margin-bottom: 2rem;
+        }
+
+        .site-table th, .site-table td {
+          text-align: left;
+          padding: 0.5rem;
+          border-bottom: 1px solid #ddd;
+        }
+
+        .site-page {
+          margin-bottom: 1rem;
+          padding: 1rem;
+          border-radius: 8px;
+          background-color: var(--color-card);
+        }
+
+        .site-page > summary {
+          cursor: pointer;
+          font-weight: 600;
+          word-break: break-all;
+        }
+
+        .scan-incomplete {
+          margin-bottom: 2rem;
+          padding: 1rem 1.5rem;
+          border-radius: 8px;
+          border-left: 4px solid var(--color-critical);
+          background-color: rgba(229, 57, 53, 0.1);
+        }
+
+        .scan-incomplete strong {
+          color: var(--color-critical);
+        }
+
+        .scan-incomplete pre {
+          overflow-x: auto;
+          font-size: 0.8rem;
+        }
+
         @media (prefers-color-scheme: dark) {
           :root {
             --color-text: #eee;
@@ -282,15 +327,34 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
             color: var(--color-text);
           }
         }
-      </style>
+      `;
+
+/**
+ * Format scan results as HTML
+ * @param results Scanner results object
+ * @param options Scanner options used for the scan
+ * @returns HTML report as a string
+ */
+export function format(results: ScanResults, options: ScannerOptions = {}): string {
+  const { violations, warnings, incomplete, passes } = results;
+
+  // Generate HTML
+  let html = `
+    <!DOCTYPE html>
+    <html lang="en">
+    <head>
+      <meta charset="UTF-8">
+      <meta name="viewport" content="width=device-width, initial-scale=1.0">
+      <title>WCAG Accessibility Report</title>
+      <style>${REPORT_STYLES}</style>
     </head>
     <body>
       <div class="container">
         <header>
           <h1>WCAG Accessibility Report</h1>
-          <p>Level: ${options.level || 'AA'} | Date: ${new Date().toLocaleString()}</p>
+          <p>Level: ${options.level || 'AA'}${options.standard && STANDARDS[options.standard] ? ` | Standard: ${STANDARDS[options.standard].name}` : ''} | Date: ${new Date().toLocaleString()}</p>
         </header>
-        
+        ${formatRuleErrors(results.errors || [])}
         <div class="summary">
           <div class="summary-card violations">
             <div class="summary-number">${violations.length}</div>
@@ -300,6 +364,10 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
             <div class="summary-number">${warnings.length}</div>
             <div class="summary-label">Warnings</div>
           </div>
+          <div class="summary-card incomplete">
+            <div class="summary-number">${incomplete.length}</div>
+            <div class="summary-label">Needs review</div>
+          </div>
           <div class="summary-card passes">
             <div class="summary-number">${passes.length}</div>
             <div class="summary-label">Passes</div>
@@ -309,6 +377,7 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
         <div class="tabs">
           <div class="tab active" data-tab="violations">Violations</div>
           <div class="tab" data-tab="warnings">Warnings</div>
+          <div class="tab" data-tab="incomplete">Needs review</div>
           <div class="tab" data-tab="passes">Passes</div>
         </div>
         
@@ -325,7 +394,7 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
             </select>
           </div>
           
-          ${formatViolationsList(violations)}
+          ${formatViolationsList(violations, results.url, options.standard)}
         </div>
         
         <div class="tab-content" id="warnings-content">
@@ -333,7 +402,15 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
             <input type="text" class="search-box" placeholder="Search warnings..." id="warnings-search">
           </div>
           
-          ${formatWarningsList(warnings)}
+          ${formatWarningsList(warnings, results.url, options.standard)}
+        </div>
+        
+        <div class="tab-content" id="incomplete-content">
+          <div class="filter-bar">
+            <input type="text" class="search-box" placeholder="Search results to review..." id="incomplete-search">
+          </div>
+          
+          ${formatIncompleteList(incomplete, results.url, options.standard)}
         </div>
         
         <div class="tab-content" id="passes-content">
@@ -395,6 +472,7 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
         
         setupSearch('violations-search', 'violations-content');
         setupSearch('warnings-search', 'warnings-content');
+        setupSearch('incomplete-search', 'incomplete-content');
       </script>
     </body>
     </html>
@@ -403,12 +481,145 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
   return html;
 }
 
+/**
+ * Format results for several pages, e.g. from crawling a site, as one HTML document
+ * @param site Per-page results and site-wide rollup
+ * @param options Scanner options used for the scan
+ * @returns HTML report as a string
+ */
+export function formatSite(site: SiteResults, options: ScannerOptions = {}): string {
+  const { summary } = site;
+
+  const ruleRows = summary.rules.map(rule => `
+          <tr>
+            <td>${escapeHtml(rule.rule)}</td>
+            <td>${rule.violations}</td>
+            <td>${rule.warnings}</td>
+            <td>${rule.pages}</td>
+          </tr>`).join('');
+
+  const pageUrls = Object.keys(site.pages);
+  const pageRows = pageUrls.map(page => {
+    const results = site.pages[page];
+    return `
+          <tr>
+            <td>${escapeHtml(page)}</td>
+            <td>${results.violations.length}</td>
+            <td>${results.warnings.length}</td>
+            <td>${results.passes.length}</td>
+          </tr>`;
+  }).join('');
+
+  const errorRows = Object.keys(site.errors).map(page => `
+          <tr>
+            <td>${escapeHtml(page)}</td>
+            <td colspan="3">${escapeHtml(site.errors[page])}</td>
+          </tr>`).join('');
+
+  const pageSections = pageUrls.map(page => {
+    const results = site.pages[page];
+    return `
+        <details class="site-page"${results.violations.length > 0 ? ' open' : ''}>
+          <summary>${escapeHtml(page)} (${results.violations.length} violations, ${results.warnings.length} warnings, ${results.incomplete.length} to review)</summary>
+          ${formatRuleErrors(results.errors || [])}
+          <h3>Violations</h3>
+          ${formatViolationsList(results.violations, results.url, options.standard)}
+          <h3>Warnings</h3>
+          ${formatWarningsList(results.warnings, results.url, options.standard)}
+          <h3>Needs review</h3>
+          ${formatIncompleteList(results.incomplete, results.url, options.standard)}
+        </details>`;
+  }).join('');
+
+  // Page sections list each page's failures; the banner only names the rules
+  const ruleErrors = pageUrls.reduce<RuleError[]>((errors, page) => errors.concat(site.pages[page].errors || []), []);
+  const scanIncomplete = describeRuleErrors({ errors: ruleErrors });
+
+  return `
+    <!DOCTYPE html>
+    <html lang="en">
+    <head>
+      <meta charset="UTF-8">
+      <meta name="viewport" content="width=device-width, initial-scale=1.0">
+      <title>WCAG Accessibility Site Report</title>
+      <style>${REPORT_STYLES}</style>
+    </head>
+    <body>
+      <div class="container">
+        <header>
+          <h1>WCAG Accessibility Site Report</h1>
+          <p>Level: ${options.level || 'AA'}${options.standard && STANDARDS[options.standard] ? ` | Standard: ${STANDARDS[options.standard].name}` : ''} | Date: ${new Date().toLocaleString()}</p>
+        </header>
+        ${scanIncomplete ? `
+        <div class="scan-incomplete" role="alert">
+          <strong>⚠ ${escapeHtml(scanIncomplete)}</strong>
+        </div>` : ''}
+
+        <div class="summary">
+          <div class="summary-card passes">
+            <div class="summary-number">${summary.pages}</div>
+            <div class="summary-label">Pages scanned</div>
+          </div>
+          <div class="summary-card violations">
+            <div class="summary-number">${summary.violations}</div>
+            <div class="summary-label">Violations on ${summary.pagesWithViolations} pages</div>
+          </div>
+          <div class="summary-card warnings">
+            <div class="summary-number">${summary.warnings}</div>
+            <div class="summary-label">Warnings</div>
+          </div>
+          <div class="summary-card incomplete">
+            <div class="summary-number">${summary.incomplete}</div>
+            <div class="summary-label">Needs review</div>
+          </div>
+        </div>
+
+        <h2>Rules</h2>
+        ${summary.rules.length > 0 ? `
+        <table class="site-table">
+          <thead>
+            <tr><th>Rule</th><th>Violations</th><th>Warnings</th><th>Pages</th></tr>
+          </thead>
+          <tbody>${ruleRows}
+          </tbody>
+        </table>` : '<p>No violations or warnings found. Great job!</p>'}
+
+        <h2>Pages</h2>
+        <table class="site-table">
+          <thead>
+            <tr><th>Page</th><th>Violations</th><th>Warnings</th><th>Passes</th></tr>
+          </thead>
+          <tbody>${pageRows}${errorRows}
+          </tbody>
+        </table>
+        ${pageSections}
+      </div>
+
+      <script>
+        // Toggle result details
+        document.querySelectorAll('.collapse-toggle').forEach(toggle => {
+          toggle.addEventListener('click', () => {
+            const details = toggle.closest('.result-card').querySelector('.result-details');
+            const isVisible = details.style.display !== 'none';
+
+            details.style.display = isVisible ? 'none' : 'block';
+            toggle.textContent = isVisible ? '▼' : '▲';
+          });
+        });
+      </script>
+    </body>
+    </html>
+  `;
+}
+
 /**
  * Format violations as HTML
  * @param violations Array of violations
+ * @param url URL of the scanned document
+ * @param standard Standard whose clause numbers are shown
  * @returns HTML string
  */
-function formatViolationsList(violations: Violation[]): string {
+function formatViolationsList(violations: Violation[], url?: string, standard?: Standard): string {
   if (violations.length === 0) {
     return '<p>No violations found. Great job!</p>';
   }
@@ -430,7 +641,7 @@ function formatViolationsList(violations: Violation[]): string {
   impactOrder.forEach(impact => {
     if (byImpact[impact] && byImpact[impact].length > 0) {
       byImpact[impact].forEach(violation => {
-        html += formatViolationCard(violation, impact);
+        html += formatViolationCard(violation, impact, url, standard);
       });
     }
   });
@@ -442,9 +653,11 @@ function formatViolationsList(violations: Violation[]): string {
  * Format a single violation as an HTML card
  * @param violation Violation object
  * @param impact Impact level
+ * @param url URL of the scanned document
+ * @param standard Standard whose clause numbers are shown
  * @returns HTML string
  */
-function formatViolationCard(violation: Violation, impact: string): string {
+function formatViolationCard(violation: Violation, impact: string, url?: string, standard?: Standard): string {
   return `
     <div class="result-card" data-impact="${impact}">
       <h3>
@@ -454,6 +667,8 @@ function formatViolationCard(violation: Violation, impact: string): string {
       </h3>
       
       <div class="result-details" style="display: none;">
+        ${formatLocationMeta(url, violation)}
+
         ${violation.element ? `
           <div class="result-meta">
             <div class="result-meta-item">
@@ -472,6 +687,24 @@ function formatViolationCard(violation: Violation, impact: string): string {
           </div>
         ` : ''}
         
+        ${describeLevel(violation) ? `
+          <div class="result-meta">
+            <div class="result-meta-item">
+              <span class="result-meta-label">Level:</span>
+              ${escapeHtml(describeLevel(violation))}
+            </div>
+          </div>
+        ` : ''}
+        
+        ${describeClauses(violation, standard) ? `
+          <div class="result-meta">
+            <div class="result-meta-item">
+              <span class="result-meta-label">Clauses:</span>
+              ${escapeHtml(describeClauses(violation, standard))}
+            </div>
+          </div>
+        ` : ''}
+        
         ${violation.help ? `
           <div class="result-meta">
             <div class="result-meta-item">
@@ -505,9 +738,11 @@ function formatViolationCard(violation: Violation, impact: string): string {
 /**
  * Format warnings as HTML
  * @param warnings Array of warnings
+ * @param url URL of the scanned document
+ * @param standard Standard whose clause numbers are shown
  * @returns HTML string
  */
-function formatWarningsList(warnings: Warning[]): string {
+function formatWarningsList(warnings: Warning[], url?: string, standard?: Standard): string {
   if (warnings.length === 0) {
     return '<p>No warnings found.</p>';
   }
@@ -522,6 +757,8 @@ function formatWarningsList(warnings: Warning[]): string {
         </h3>
         
         <div class="result-details" style="display: none;">
+          ${formatLocationMeta(url, warning)}
+
           ${warning.element ? `
             <div class="result-meta">
               <div class="result-meta-item">
@@ -540,6 +777,24 @@ function formatWarningsList(warnings: Warning[]): string {
             </div>
           ` : ''}
           
+          ${describeLevel(warning) ? `
+            <div class="result-meta">
+              <div class="result-meta-item">
+                <span class="result-meta-label">Level:</span>
+                ${escapeHtml(describeLevel(warning))}
+              </div>
+            </div>
+          ` : ''}
+          
+          ${describeClauses(warning, standard) ? `
+            <div class="result-meta">
+              <div class="result-meta-item">
+                <span class="result-meta-label">Clauses:</span>
+                ${escapeHtml(describeClauses(warning, standard))}
+              </div>
+            </div>
+          ` : ''}
+          
           ${warning.help ? `
             <div class="result-meta">
               <div class="result-meta-item">
@@ -562,6 +817,93 @@ function formatWarningsList(warnings: Warning[]): string {
   return html;
 }
 
+/**
+ * Format results that need review as HTML
+ * @param items Array of incomplete results
+ * @param url URL of the scanned document
+ * @param standard Standard whose clause numbers are shown
+ * @returns HTML string
+ */
+function formatIncompleteList(items: Incomplete[], url?: string, standard?: Standard): string {
+  if (items.length === 0) {
+    return '<p>Nothing needs review.</p>';
+  }
+
+  let html = '';
+  items.forEach(item => {
+    html += `
+      <div class="result-card">
+        <h3>
+          ${escapeHtml(item.description)}
+          <button class="collapse-toggle">▼</button>
+        </h3>
+        
+        <div class="result-details" style="display: none;">
+          ${formatLocationMeta(url, item)}
+
+          ${item.element ? `
+            <div class="result-meta">
+              <div class="result-meta-item">
+                <span class="result-meta-label">Element:</span>
+                ${escapeHtml(formatElement(item.element))}
+              </div>
+            </div>
+          ` : ''}
+          
+          ${item.wcag && item.wcag.length > 0 ? `
+            <div class="result-meta">
+              <div class="result-meta-item">
+                <span class="result-meta-label">WCAG:</span>
+                ${item.wcag.map(wcag => `<a href="https://www.w3.org/WAI/WCAG21/Understanding/${wcag.toLowerCase()}" target="_blank">${wcag}</a>`).join(', ')}
+              </div>
+            </div>
+          ` : ''}
+          
+          ${describeLevel(item) ? `
+            <div class="result-meta">
+              <div class="result-meta-item">
+                <span class="result-meta-label">Level:</span>
+                ${escapeHtml(describeLevel(item))}
+              </div>
+            </div>
+          ` : ''}
+          
+          ${describeClauses(item, standard) ? `
+            <div class="result-meta">
+              <div class="result-meta-item">
+                <span class="result-meta-label">Clauses:</span>
+                ${escapeHtml(describeClauses(item, standard))}
+              </div>
+            </div>
+          ` : ''}
+          
+          <div class="result-meta">
+            <div class="result-meta-item">
+              <span class="result-meta-label">Reason:</span>
+              ${escapeHtml(item.reason)}
+            </div>
+          </div>
+          
+          <div class="result-meta">
+            <div class="result-meta-item">
+              <span class="result-meta-label">Review:</span>
+              ${escapeHtml(item.review)}
+            </div>
+          </div>
+          
+          ${item.snippet ? `
+            <div class="code-block incomplete">
+              ${escapeHtml(item.snippet)}
+            </div>
+          ` : ''}
+        </div>
+      </div>
+    `;
+  });
+
+  return html;
+}
+
 /**
  * Format passes as HTML
  * @param passes Array of passes
@@ -604,6 +946,48 @@ function formatPassesList(passes: Pass[]): string {
   return html;
 }
 
+/**
+ * Format the "scan incomplete" banner for rules that threw
+ * @param errors Rules that threw
+ * @returns HTML string, empty when every rule ran
+ */
+function formatRuleErrors(errors: RuleError[]): string {
+  const message = describeRuleErrors({ errors });
+  if (!message) return '';
+
+  const items = errors.map(error => `
+            <li>
+              <code>${escapeHtml(error.rule)}</code>: ${escapeHtml(error.message)}
+              ${error.stack ? `<details><summary>Stack trace</summary><pre>${escapeHtml(error.stack)}</pre></details>` : ''}
+            </li>`).join('');
+
+  return `
+        <div class="scan-incomplete" role="alert">
+          <strong>⚠ ${escapeHtml(message)}</strong>
+          <ul>${items}
+          </ul>
+        </div>`;
+}
+
+/**
+ * Format the source location of a result as a meta row
+ * @param url URL of the scanned document
+ * @param item Violation, warning or incomplete result
+ * @returns HTML string, empty without a location
+ */
+function formatLocationMeta(url: string | undefined, item: Violation | Warning | Incomplete): string {
+  if (!item.location) return '';
+
+  return `
+    <div class="result-meta">
+      <div class="result-meta-item">
+        <span class="result-meta-label">Location:</span>
+        <code>${escapeHtml(formatLocation(url, item.location))}</code>
+      </div>
+    </div>
+  `;
+}
+
 /**
  * Format element information as a string
  * @param element Element info object
@@ -650,5 +1034,6 @@ function escapeHtml(str: string): string {
 }
 
 export default {
-  format
+  format,
+  formatSite
 };
\ No newline at end of file
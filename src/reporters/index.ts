@@ -2,11 +2,15 @@ import { ScanResults, ScannerOptions } from '../types';
 import jsonReporter from './json';
 import consoleReporter from './console';
 import htmlReporter from './html';
+import sarifReporter from './sarif';
+import junitReporter from './junit';
+import markdownReporter from './markdown';
+import { filterNewViolations } from '../baseline';
 
 /**
  * Available reporter formats
  */
-export type ReporterFormat = 'json' | 'console' | 'html';
+export type ReporterFormat = 'json' | 'console' | 'html' | 'sarif' | 'junit' | 'markdown';
 
 /**
  * Generate a report in the specified format
@@ -20,23 +24,34 @@ export function generateReport(
   format: ReporterFormat = 'json', 
   options: ScannerOptions = {}
 ): string {
+  const reported = options.onlyNewViolations ? filterNewViolations(results) : results;
+
   switch (format) {
     case 'json':
-      return jsonReporter.format(results, options);
+      return jsonReporter.format(reported, options);
     case 'console':
-      return consoleReporter.format(results, options);
+      return consoleReporter.format(reported, options);
     case 'html':
-      return htmlReporter.format(results, options);
+      return htmlReporter.format(reported, options);
+    case 'sarif':
+      return sarifReporter.format(reported, options);
+    case 'junit':
+      return junitReporter.format(reported, options);
+    case 'markdown':
+      return markdownReporter.format(reported, options);
     default:
       // Default to JSON if unknown format
-      return jsonReporter.format(results, options);
+      return jsonReporter.format(reported, options);
   }
 }
 
 export {
   jsonReporter,
   consoleReporter,
-  htmlReporter
+  htmlReporter,
+  sarifReporter,
+  junitReporter,
+  markdownReporter
 };
 
 export default {
@@ -1,4 +1,5 @@
 import { ScanResults, ScannerOptions } from "../types";
+import { formatLocation } from "../utils/locations";
 
 /**
  * Format scan results as JSON
@@ -11,16 +12,33 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
         summary: {
             violations: results.violations.length,
             warnings: results.warnings.length,
+            incomplete: results.incomplete.length,
             passes: results.passes.length,
+            errors: (results.errors || []).length,
+            complete: (results.errors || []).length === 0,
             timestamp: new Date().toISOString(),
             options: {
                 level: options.level || 'AA',
                 rules: options.rules || [],
-            }
+            },
+            ...(results.suppressed ? { suppressed: results.suppressed.length } : {}),
+            ...(results.baseline ? {
+                baseline: {
+                    file: results.baseline.file,
+                    new: results.baseline.new,
+                    existing: results.baseline.existing,
+                    fixed: results.baseline.fixed.length,
+                    onlyNewViolations: !!options.onlyNewViolations,
+                }
+            } : {}),
         },
-        violations: cleanResultItems(results.violations),
-        warnings: cleanResultItems(results.warnings),
-        passes: cleanResultItems(results.passes),
+        violations: cleanResultItems(results.violations, results.url),
+        warnings: cleanResultItems(results.warnings, results.url),
+        incomplete: cleanResultItems(results.incomplete, results.url),
+        passes: cleanResultItems(results.passes, results.url),
+        errors: results.errors || [],
+        ...(results.suppressed ? { suppressed: cleanResultItems(results.suppressed, results.url) } : {}),
+        ...(results.baseline ? { fixed: results.baseline.fixed } : {}),
     };
     return JSON.stringify(report, null, 2);
 }
@@ -28,9 +46,10 @@ export function format(results: ScanResults, options: ScannerOptions = {}): stri
 /**
  * Clean result items for better JSON formatting
  * @param items Array of result items
+ * @param url URL of the scanned document, for `file:line:col` sources
  * @returns Cleaned items
  */
-function cleanResultItems(items: any[]): any[] {
+function cleanResultItems(items: any[], url?: string): any[] {
     return items.map(item => {
         // Create a clean copy without cicular references
         const cleanItem = { ...item };
@@ -48,6 +67,10 @@ function cleanResultItems(items: any[]): any[] {
               ? cleanItem.snippet.substring(0, 300) + '...'
               : cleanItem.snippet;
         }
+
+        if (cleanItem.location) {
+            cleanItem.source = formatLocation(url, cleanItem.location);
+        }
         return cleanItem;
     });
 }
@@ -0,0 +1,177 @@
+import { describeClauses, describeLevel } from '../metadata';
+import { Incomplete, RuleError, ScanResults, ScannerOptions, Standard, Violation, Warning } from '../types';
+
+/**
+ * A page to report as a JUnit test suite
+ */
+export interface JUnitPage {
+  /** Suite name, e.g. the file path or URL */
+  name: string;
+  results: ScanResults;
+}
+
+interface RuleCase {
+  rule: string;
+  violations: Violation[];
+  warnings: Warning[];
+  incomplete: Incomplete[];
+}
+
+/**
+ * Format scan results as JUnit XML, one test case per rule id
+ * @param results Scan results object
+ * @param options Options used for the scan
+ * @returns JUnit XML string
+ */
+export function format(results: ScanResults, options: ScannerOptions = {}): string {
+  return formatAll([{ name: results.url || 'wcag-scanner', results }], options);
+}
+
+/**
+ * Format several pages as JUnit XML, one test suite per page
+ * @param pages Named scan results
+ * @param options Options used for the scan; junitWarnings picks how warnings and results that
+ * need review are reported
+ * @returns JUnit XML string
+ */
+export function formatAll(pages: JUnitPage[], options: ScannerOptions = {}): string {
+  const warningMode = options.junitWarnings || 'system-out';
+  let tests = 0;
+  let failures = 0;
+  let skipped = 0;
+  let errors = 0;
+
+  const suites = pages.map(page => {
+    const cases = ruleCases(page.results);
+    const ruleErrors = page.results.errors || [];
+    const suiteFailures = cases.filter(testCase => testCase.violations.length > 0).length;
+    const suiteSkipped = warningMode === 'skipped'
+      ? cases.filter(testCase => testCase.violations.length === 0 && needsAttention(testCase) > 0).length
+      : 0;
+
+    tests += cases.length + ruleErrors.length;
+    failures += suiteFailures;
+    skipped += suiteSkipped;
+    errors += ruleErrors.length;
+
+    const body = ruleErrors.map(error => formatErrorCase(page.name, error)).join('')
+      + cases.map(testCase => formatCase(page.name, testCase, warningMode, options.standard)).join('');
+    return `  <testsuite name="${escapeXml(page.name)}" tests="${cases.length + ruleErrors.length}" failures="${suiteFailures}" errors="${ruleErrors.length}" skipped="${suiteSkipped}">\n${body}  </testsuite>\n`;
+  });
+
+  return '<?xml version="1.0" encoding="UTF-8"?>\n'
+    + `<testsuites name="wcag-scanner" tests="${tests}" failures="${failures}" errors="${errors}" skipped="${skipped}">\n`
+    + suites.join('')
+    + '</testsuites>\n';
+}
+
+/**
+ * Group results by rule id, in the order rules first appear; rules that only passed are included
+ */
+function ruleCases(results: ScanResults): RuleCase[] {
+  const cases = new Map<string, RuleCase>();
+  const caseFor = (rule: string): RuleCase => {
+    let testCase = cases.get(rule);
+    if (!testCase) {
+      testCase = { rule, violations: [], warnings: [], incomplete: [] };
+      cases.set(rule, testCase);
+    }
+    return testCase;
+  };
+
+  results.violations.forEach(violation => caseFor(violation.rule).violations.push(violation));
+  results.warnings.forEach(warning => caseFor(warning.rule).warnings.push(warning));
+  results.incomplete.forEach(item => caseFor(item.rule).incomplete.push(item));
+  results.passes.forEach(pass => caseFor(pass.rule));
+
+  return Array.from(cases.values());
+}
+
+function formatCase(
+  suite: string,
+  testCase: RuleCase,
+  warningMode: 'skipped' | 'system-out',
+  standard?: Standard,
+): string {
+  const details = (item: Violation | Warning | Incomplete) => describe(item, standard);
+  const open = `    <testcase classname="${escapeXml(suite)}" name="${escapeXml(testCase.rule)}"`;
+  const children: string[] = [];
+
+  if (testCase.violations.length > 0) {
+    const [first] = testCase.violations;
+    const message = `${testCase.violations.length} violation(s): ${first.description}`;
+    children.push(
+      `      <failure message="${escapeXml(message)}" type="${escapeXml(first.impact)}">`
+      + `${escapeXml(testCase.violations.map(details).join('\n\n'))}</failure>\n`
+    );
+  }
+
+  if (needsAttention(testCase) > 0) {
+    if (warningMode === 'skipped' && testCase.violations.length === 0) {
+      const counts = [
+        testCase.warnings.length > 0 ? `${testCase.warnings.length} warning(s)` : '',
+        testCase.incomplete.length > 0 ? `${testCase.incomplete.length} incomplete result(s)` : '',
+      ].filter(Boolean).join(' and ');
+      children.push(`      <skipped message="${escapeXml(`${counts} need manual review`)}"/>\n`);
+    }
+    const output = [
+      ...testCase.warnings.map(details),
+      ...testCase.incomplete.map(item => `Needs review: ${details(item)}`),
+    ];
+    children.push(`      <system-out>${escapeXml(output.join('\n\n'))}</system-out>\n`);
+  }
+
+  return children.length === 0 ? `${open}/>\n` : `${open}>\n${children.join('')}    </testcase>\n`;
+}
+
+/**
+ * A rule that threw, reported as an errored test case so CI shows the scan as incomplete
+ */
+function formatErrorCase(suite: string, error: RuleError): string {
+  const message = `Scan incomplete: rule ${error.rule} failed: ${error.message}`;
+  return `    <testcase classname="${escapeXml(suite)}" name="${escapeXml(error.rule)}">\n`
+    + `      <error message="${escapeXml(message)}" type="RuleError">${escapeXml(error.stack || error.message)}</error>\n`
+    + '    </testcase>\n';
+}
+
+/**
+ * Warnings and incomplete results of a test case, which need a person to look at them
+ */
+function needsAttention(testCase: RuleCase): number {
+  return testCase.warnings.length + testCase.incomplete.length;
+}
+
+/**
+ * Plain-text details of one violation, warning or incomplete result
+ */
+function describe(item: Violation | Warning | Incomplete, standard?: Standard): string {
+  const lines = [`[${item.impact}] ${item.description}`];
+  if (item.location) lines.push(`Line: ${item.location.line}:${item.location.column}`);
+  if (item.snippet) lines.push(`Snippet: ${item.snippet}`);
+  if ('help' in item && item.help) lines.push(`Help: ${item.help}`);
+  if ('reason' in item) lines.push(`Reason: ${item.reason}`, `Review: ${item.review}`);
+  if (item.wcag && item.wcag.length > 0) lines.push(`WCAG: ${item.wcag.join(', ')}`);
+  if (describeLevel(item)) lines.push(`Level: ${describeLevel(item)}`);
+  if (describeClauses(item, standard)) lines.push(`Clauses: ${describeClauses(item, standard)}`);
+  if ('helpUrl' in item && item.helpUrl) lines.push(`More info: ${item.helpUrl}`);
+  return lines.join('\n');
+}
+
+/**
+ * Escape text for XML attributes and content, dropping characters XML 1.0 does not allow
+ */
+function escapeXml(value: string): string {
+  return value
+    // eslint-disable-next-line no-control-regex
+    .replace(/[\u0000-\u0008\u000B\u000C\u000E-\u001F]/g, '')
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+    .replace(/'/g, '&apos;');
+}
+
+export default {
+  format,
+  formatAll,
+};
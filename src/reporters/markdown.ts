@@ -0,0 +1,308 @@
+import { describeRuleErrors } from '../ruleErrors';
+import { describeClauses, describeLevel } from '../metadata';
+import {
+  BaselineSummary,
+  ImpactLevel,
+  Incomplete,
+  RuleError,
+  ScanResults,
+  ScannerOptions,
+  Standard,
+  Violation,
+  Warning,
+} from '../types';
+
+/**
+ * Default size budget, just under GitHub's 65536-character limit for comment bodies
+ */
+export const DEFAULT_MARKDOWN_MAX_LENGTH = 65000;
+
+const IMPACTS: ImpactLevel[] = ['critical', 'serious', 'moderate', 'minor'];
+
+const IMPACT_ICONS: Record<ImpactLevel, string> = {
+  critical: '🔴',
+  serious: '🟠',
+  moderate: '🟡',
+  minor: '🔵',
+};
+
+// Room kept for the truncation notice
+const NOTICE_RESERVE = 200;
+
+/**
+ * A page to report in a multi-page markdown report
+ */
+export interface MarkdownPage {
+  /** Page heading, e.g. the file path or URL */
+  name: string;
+  results: ScanResults;
+}
+
+interface RuleGroup {
+  rule: string;
+  kind: 'violation' | 'warning' | 'incomplete';
+  items: Array<Violation | Warning | Incomplete>;
+}
+
+const SECTION_TITLES: Record<RuleGroup['kind'], string> = {
+  violation: 'Violations',
+  warning: 'Warnings',
+  incomplete: 'Needs review',
+};
+
+/**
+ * Format scan results as GitHub-flavoured markdown, e.g. for a pull-request comment
+ * @param results Scan results object
+ * @param options Options used for the scan; markdownMaxLength sets the size budget
+ * @returns Markdown string
+ */
+export function format(results: ScanResults, options: ScannerOptions = {}): string {
+  return formatAll([{ name: results.url || '', results }], options);
+}
+
+/**
+ * Format several pages as one markdown report with a shared size budget.
+ * The summary table is always included; rule sections that do not fit the budget are
+ * shortened or left out, and a notice says how many results were not shown.
+ * @param pages Named scan results
+ * @param options Options used for the scan
+ * @returns Markdown string
+ */
+export function formatAll(pages: MarkdownPage[], options: ScannerOptions = {}): string {
+  const maxLength = options.markdownMaxLength || DEFAULT_MARKDOWN_MAX_LENGTH;
+  const multiPage = pages.length > 1;
+  const heading = multiPage ? '####' : '###';
+
+  let output = `## WCAG accessibility scan\n\n${ruleErrorsAlert(pages)}${summaryTable(pages, options)}\n`;
+  let omittedResults = 0;
+  let omittedRules = 0;
+  const omit = (groups: RuleGroup[]) => {
+    groups.forEach(group => {
+      omittedRules++;
+      omittedResults += group.items.length;
+    });
+  };
+
+  pages.forEach(page => {
+    const groups = ruleGroups(page.results);
+    const title = multiPage ? `### ${escapeMarkdown(page.name)}\n\n` : '';
+    const empty = groups.length === 0 ? '✅ No violations or warnings.\n\n' : '';
+    if (omittedRules > 0 || output.length + title.length + empty.length + NOTICE_RESERVE > maxLength) {
+      omit(groups);
+      return;
+    }
+    output += title + empty;
+
+    let section: RuleGroup['kind'] | null = null;
+    groups.forEach(group => {
+      // Once something has been left out, keep the rest out so the report stays in order
+      if (omittedRules > 0) {
+        omit([group]);
+        return;
+      }
+
+      const title = group.kind !== section
+        ? `${heading} ${SECTION_TITLES[group.kind]}\n\n`
+        : '';
+      const block = ruleBlock(group, maxLength - output.length - title.length - NOTICE_RESERVE, options.standard);
+      if (!block) {
+        omit([group]);
+        return;
+      }
+
+      output += title + block.text;
+      section = group.kind;
+      omittedResults += group.items.length - block.shown;
+    });
+  });
+
+  if (omittedResults > 0 || omittedRules > 0) {
+    const rules = omittedRules > 0 ? ` and ${omittedRules} more rule(s)` : '';
+    output += `> ✂️ Report truncated to fit ${maxLength} characters: ${omittedResults} result(s)${rules} not shown. `
+      + 'Run with `--format html` or `--format json` for the full report.\n';
+  }
+
+  return output;
+}
+
+/**
+ * Alert listing the rules that threw, so a clean-looking report is not mistaken for a clean page
+ * @returns Markdown string, empty when every rule ran
+ */
+function ruleErrorsAlert(pages: MarkdownPage[]): string {
+  const errors = pages.reduce<RuleError[]>((all, page) => all.concat(page.results.errors || []), []);
+  const message = describeRuleErrors({ errors });
+  if (!message) return '';
+
+  // A rule that fails on every page is listed once
+  const lines = errors
+    .map(error => `> - \`${error.rule}\`: ${escapeMarkdown(error.message.split('\n')[0])}`)
+    .filter((line, index, all) => all.indexOf(line) === index);
+  return `> [!WARNING]\n> **${message}**\n>\n${lines.join('\n')}\n\n`;
+}
+
+/**
+ * Counts by impact for every page, plus passes, needs-review and suppressed/baseline notes
+ */
+function summaryTable(pages: MarkdownPage[], options: ScannerOptions): string {
+  const count = (items: Array<Violation | Warning>, impact: ImpactLevel) =>
+    items.filter(item => item.impact === impact).length;
+
+  let violations = 0;
+  let warnings = 0;
+  let passes = 0;
+  let incomplete = 0;
+  let suppressed = 0;
+  const rows = IMPACTS.map(impact => {
+    let impactViolations = 0;
+    let impactWarnings = 0;
+    pages.forEach(({ results }) => {
+      impactViolations += count(results.violations, impact);
+      impactWarnings += count(results.warnings, impact);
+    });
+    violations += impactViolations;
+    warnings += impactWarnings;
+    return `| ${IMPACT_ICONS[impact]} ${capitalize(impact)} | ${impactViolations} | ${impactWarnings} |`;
+  });
+  pages.forEach(({ results }) => {
+    passes += results.passes.length;
+    incomplete += results.incomplete.length;
+    suppressed += results.suppressed ? results.suppressed.length : 0;
+  });
+
+  const lines = [
+    '| Impact | Violations | Warnings |',
+    '| --- | ---: | ---: |',
+    ...rows,
+    `| **Total** | **${violations}** | **${warnings}** |`,
+    '',
+  ];
+
+  const notes = [`✅ ${passes} passed check(s)`];
+  if (pages.length > 1) notes.unshift(`${pages.length} pages scanned`);
+  if (incomplete > 0) notes.push(`🔍 ${incomplete} need(s) review`);
+  if (suppressed > 0) notes.push(`${suppressed} suppressed`);
+  const baselines = pages
+    .map(({ results }) => results.baseline)
+    .filter((baseline): baseline is BaselineSummary => Boolean(baseline));
+  if (baselines.length > 0) {
+    const total = (key: 'new' | 'existing') => baselines.reduce((sum, baseline) => sum + baseline[key], 0);
+    const fixed = baselines.reduce((sum, baseline) => sum + baseline.fixed.length, 0);
+    let note = `baseline: ${total('new')} new, ${total('existing')} existing, ${fixed} fixed`;
+    if (options.onlyNewViolations && total('existing') > 0) note += ' (existing not shown)';
+    notes.push(note);
+  }
+  lines.push(notes.join(' · '), '');
+
+  return lines.join('\n');
+}
+
+/**
+ * Group violations, then warnings, then results that need review by rule id, most severe rules first
+ */
+function ruleGroups(results: ScanResults): RuleGroup[] {
+  const group = (kind: RuleGroup['kind'], items: Array<Violation | Warning | Incomplete>): RuleGroup[] => {
+    const groups = new Map<string, RuleGroup>();
+    items.forEach(item => {
+      let entry = groups.get(item.rule);
+      if (!entry) {
+        entry = { rule: item.rule, kind, items: [] };
+        groups.set(item.rule, entry);
+      }
+      entry.items.push(item);
+    });
+    return Array.from(groups.values()).sort((a, b) => severity(a) - severity(b));
+  };
+
+  return [
+    ...group('violation', results.violations),
+    ...group('warning', results.warnings),
+    ...group('incomplete', results.incomplete),
+  ];
+}
+
+function severity(group: RuleGroup): number {
+  return Math.min(...group.items.map(item => {
+    const rank = IMPACTS.indexOf(item.impact);
+    return rank === -1 ? IMPACTS.length : rank;
+  }));
+}
+
+/**
+ * Render a collapsible section for one rule, with as many occurrences as fit the budget
+ * @returns The markdown and number of occurrences shown, or null if not even one fits
+ */
+function ruleBlock(group: RuleGroup, budget: number, standard?: Standard): { text: string; shown: number } | null {
+  const [first] = group.items;
+  const noun = group.kind === 'violation' ? 'violation(s)' : group.kind === 'warning' ? 'warning(s)' : 'to review';
+  const summary = `<summary>${IMPACT_ICONS[first.impact] || '⚪'} <code>${escapeHtml(group.rule)}</code> `
+    + `— ${group.items.length} ${noun}: ${escapeHtml(first.description)}</summary>`;
+
+  const about: string[] = [];
+  if ('reason' in first) about.push(escapeMarkdown(first.reason), `**Review:** ${escapeMarkdown(first.review)}`);
+  if ('help' in first && first.help) about.push(escapeMarkdown(first.help));
+  if (first.wcag && first.wcag.length > 0) about.push(`WCAG ${first.wcag.join(', ')}`);
+  if (describeLevel(first)) about.push(`Level ${describeLevel(first)}`);
+  if (describeClauses(first, standard)) about.push(describeClauses(first, standard));
+  const helpUrl = 'helpUrl' in first ? first.helpUrl : undefined;
+  if (helpUrl) about.push(`[Learn more](${helpUrl})`);
+
+  const open = `<details>\n${summary}\n\n${about.length > 0 ? `${about.join(' · ')}\n\n` : ''}`;
+  const close = '</details>\n\n';
+
+  let body = '';
+  let shown = 0;
+  for (const item of group.items) {
+    const occurrence = formatOccurrence(item);
+    const more = group.items.length - shown - 1 > 0
+      ? `_…and ${group.items.length - shown - 1} more not shown._\n\n`
+      : '';
+    if (open.length + body.length + occurrence.length + more.length + close.length > budget) break;
+    body += occurrence;
+    shown++;
+  }
+
+  if (shown === 0) return null;
+  if (shown < group.items.length) {
+    body += `_…and ${group.items.length - shown} more not shown._\n\n`;
+  }
+  return { text: open + body + close, shown };
+}
+
+function formatOccurrence(item: Violation | Warning | Incomplete): string {
+  const where = item.location ? ` (line ${item.location.line}, column ${item.location.column})` : '';
+  let text = `- ${escapeMarkdown(item.description)}${where}\n`;
+  if (item.snippet) {
+    text += `\n${fence(item.snippet, 'html')}\n`;
+  }
+  return `${text}\n`;
+}
+
+/**
+ * Wrap code in a fence longer than any backtick run inside it
+ */
+function fence(code: string, language: string): string {
+  const longest = (code.match(/`+/g) || []).reduce((max, run) => Math.max(max, run.length), 0);
+  const ticks = '`'.repeat(Math.max(3, longest + 1));
+  return `${ticks}${language}\n${code}\n${ticks}\n`;
+}
+
+function capitalize(value: string): string {
+  return value.charAt(0).toUpperCase() + value.slice(1);
+}
+
+function escapeHtml(value: string): string {
+  return value.replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;');
+}
+
+/**
+ * Escape text so markup in descriptions is shown rather than rendered
+ */
+function escapeMarkdown(value: string): string {
+  return escapeHtml(value).replace(/([\\`*_[\]|])/g, '\\$1');
+}
+
+export default {
+  format,
+  formatAll,
+};
@@ -0,0 +1,178 @@
+import { ImpactLevel, Incomplete, ScanResults, ScannerOptions, Violation, Warning, WcagLevel } from '../types';
+import { fingerprint } from '../baseline';
+import { describeClauses } from '../metadata';
+import { displayPath } from '../utils/locations';
+
+export const SARIF_SCHEMA = 'https://json.schemastore.org/sarif-2.1.0.json';
+export const SARIF_VERSION = '2.1.0';
+
+/**
+ * Fingerprint key; bump the suffix if the fingerprint algorithm changes
+ */
+export const FINGERPRINT_KEY = 'wcagScanner/v1';
+
+type SarifLevel = 'error' | 'warning' | 'note' | 'none';
+
+const VIOLATION_LEVELS: Record<ImpactLevel, SarifLevel> = {
+  critical: 'error',
+  serious: 'error',
+  moderate: 'warning',
+  minor: 'note',
+};
+
+// Warnings need manual review, so they never fail a code-scanning check
+const WARNING_LEVELS: Record<ImpactLevel, SarifLevel> = {
+  critical: 'warning',
+  serious: 'warning',
+  moderate: 'warning',
+  minor: 'note',
+};
+
+// SARIF requires level "none" on results whose kind is not "fail"
+const REVIEW_LEVEL: SarifLevel = 'none';
+
+// Least to most severe
+const SEVERITY: SarifLevel[] = ['none', 'note', 'warning', 'error'];
+
+interface SarifRule {
+  id: string;
+  shortDescription: { text: string };
+  help?: { text: string };
+  helpUri?: string;
+  defaultConfiguration: { level: SarifLevel };
+  properties: { tags: string[]; wcag: string[]; wcagLevel?: WcagLevel; bestPractice?: boolean; clauses?: string };
+}
+
+/**
+ * Format scan results as a SARIF 2.1.0 log
+ * @param results Scan results object
+ * @param options Options used for the scan
+ * @returns SARIF JSON string
+ */
+export function format(results: ScanResults, options: ScannerOptions = {}): string {
+  return formatAll([results], options);
+}
+
+/**
+ * Format the results of several pages as one SARIF log with a single run
+ * @param pages Scan results for each page
+ * @param options Options used for the scan
+ * @param cwd Directory file locations are made relative to
+ * @returns SARIF JSON string
+ */
+export function formatAll(pages: ScanResults[], options: ScannerOptions = {}, cwd: string = process.cwd()): string {
+  const rules: SarifRule[] = [];
+  const ruleIndex = new Map<string, number>();
+  const sarifResults: object[] = [];
+  const notifications: object[] = [];
+
+  const addRule = (item: Violation | Warning | Incomplete, level: SarifLevel): number => {
+    // A rule's default level is the most severe level of its results, whatever the page order
+    const existing = ruleIndex.get(item.rule);
+    if (existing !== undefined) {
+      const configuration = rules[existing].defaultConfiguration;
+      if (SEVERITY.indexOf(level) > SEVERITY.indexOf(configuration.level)) configuration.level = level;
+      return existing;
+    }
+
+    const wcag = item.wcag || [];
+    const help = 'review' in item ? item.review : item.help;
+    rules.push({
+      id: item.rule,
+      shortDescription: { text: help || item.description },
+      ...(help ? { help: { text: help } } : {}),
+      ...('helpUrl' in item && item.helpUrl ? { helpUri: item.helpUrl } : {}),
+      defaultConfiguration: { level },
+      properties: {
+        tags: [
+          'accessibility',
+          ...wcag.map(criterion => `wcag${criterion}`),
+          ...(item.level ? [`wcag-${item.level.toLowerCase()}`] : []),
+          ...(item.bestPractice ? ['best-practice'] : []),
+        ],
+        wcag,
+        ...(item.level ? { wcagLevel: item.level } : {}),
+        ...(item.bestPractice ? { bestPractice: true } : {}),
+        ...(describeClauses(item, options.standard) ? { clauses: describeClauses(item, options.standard) } : {}),
+      },
+    });
+    ruleIndex.set(item.rule, rules.length - 1);
+    return rules.length - 1;
+  };
+
+  pages.forEach(results => {
+    const uri = displayPath(results.url, cwd);
+    const add = (item: Violation | Warning | Incomplete, level: SarifLevel) => {
+      sarifResults.push({
+        ruleId: item.rule,
+        ruleIndex: addRule(item, level),
+        ...(level === REVIEW_LEVEL ? { kind: 'review' } : {}),
+        level,
+        message: { text: item.description },
+        ...(uri ? { locations: [location(uri, item)] } : {}),
+        partialFingerprints: { [FINGERPRINT_KEY]: fingerprint(item) },
+      });
+    };
+
+    results.violations.forEach(violation => add(violation, VIOLATION_LEVELS[violation.impact] || 'warning'));
+    results.warnings.forEach(warning => add(warning, WARNING_LEVELS[warning.impact] || 'note'));
+    results.incomplete.forEach(item => add(item, REVIEW_LEVEL));
+
+    // Rules that threw are tool failures rather than findings
+    (results.errors || []).forEach(error => {
+      notifications.push({
+        level: 'error',
+        message: { text: `Scan incomplete: rule ${error.rule} failed: ${error.message}` },
+        descriptor: { id: error.rule },
+        ...(uri ? { locations: [{ physicalLocation: { artifactLocation: { uri } } }] } : {}),
+        exception: { message: error.message },
+        ...(error.stack ? { properties: { stack: error.stack } } : {}),
+      });
+    });
+  });
+
+  const log = {
+    $schema: SARIF_SCHEMA,
+    version: SARIF_VERSION,
+    runs: [{
+      tool: {
+        driver: {
+          name: 'wcag-scanner',
+          informationUri: 'https://github.com/sinhaparth5/wcag-scanner',
+          rules,
+        },
+      },
+      properties: { level: options.level || 'AA', ...(options.standard ? { standard: options.standard } : {}) },
+      ...(notifications.length > 0
+        ? { invocations: [{ executionSuccessful: false, toolExecutionNotifications: notifications }] }
+        : {}),
+      results: sarifResults,
+    }],
+  };
+
+  return JSON.stringify(log, null, 2);
+}
+
+function location(uri: string, item: Violation | Warning | Incomplete): object {
+  const region = item.location
+    ? {
+      startLine: item.location.line,
+      startColumn: item.location.column,
+      ...(item.location.endLine ? { endLine: item.location.endLine } : {}),
+      ...(item.location.endColumn ? { endColumn: item.location.endColumn } : {}),
+      ...(item.snippet ? { snippet: { text: item.snippet } } : {}),
+    }
+    : undefined;
+
+  return {
+    physicalLocation: {
+      artifactLocation: { uri },
+      ...(region ? { region } : {}),
+    },
+  };
+}
+
+export default {
+  format,
+  formatAll,
+};
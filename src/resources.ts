@@ -0,0 +1,328 @@
+import fs from 'fs';
+import { fileURLToPath } from 'url';
+import { AbortablePromise, FetchOptions as ResourceFetchOptions, ResourceLoader } from 'jsdom';
+import { SettleOptions, SubresourceLoader } from './types';
+
+export const DEFAULT_SETTLE_TIMEOUT = 10000;
+export const DEFAULT_NETWORK_IDLE = 500;
+
+const SETTLE_POLL_INTERVAL = 25;
+
+/**
+ * Thrown when a page's scripts fail and ignoreScriptErrors is not set
+ */
+export class ScriptError extends Error {
+  /** Error messages reported while running the page's scripts */
+  readonly errors: string[];
+
+  constructor(errors: string[]) {
+    super(`Page scripts threw ${errors.length} error(s): ${errors.join('; ')}`);
+    this.name = 'ScriptError';
+    this.errors = errors;
+  }
+}
+
+/**
+ * Counts in-flight subresource loads so callers can wait for the network to go idle
+ */
+export class RequestTracker {
+  private pending = 0;
+  private lastActivity = Date.now();
+
+  /**
+   * Track a load until it settles
+   * @param promise Pending load
+   * @returns The same promise
+   */
+  track<T>(promise: Promise<T>): Promise<T> {
+    this.pending++;
+    this.lastActivity = Date.now();
+    return promise.finally(() => {
+      this.pending--;
+      this.lastActivity = Date.now();
+    });
+  }
+
+  /**
+   * Milliseconds since the last load finished, or 0 while loads are in flight
+   */
+  idleFor(): number {
+    return this.pending > 0 ? 0 : Date.now() - this.lastActivity;
+  }
+}
+
+/**
+ * Options for the sandboxed resource loader
+ */
+export interface SandboxedResourceLoaderOptions {
+  /** URL of the page being scanned */
+  pageUrl: string;
+  /** Load `<link rel="stylesheet">` resources */
+  stylesheets: boolean;
+  /** Load `<script src>` resources */
+  scripts: boolean;
+  /** Caller-supplied loader, tried before the disk */
+  loader?: SubresourceLoader;
+  /** Tracks in-flight loads for network-idle detection */
+  tracker?: RequestTracker;
+}
+
+/**
+ * jsdom resource loader that only loads the stylesheets and scripts it was asked to.
+ * Resources come from the caller's loader or, for pages loaded from a `file:` URL, from disk.
+ * Frames and images are never loaded. Requests made by page scripts do not go through a
+ * resource loader; see blockNetworkRequests.
+ */
+export class SandboxedResourceLoader extends ResourceLoader {
+  private readonly options: SandboxedResourceLoaderOptions;
+  private readonly fromFile: boolean;
+
+  constructor(options: SandboxedResourceLoaderOptions) {
+    super();
+    this.options = options;
+    this.fromFile = options.pageUrl.startsWith('file:');
+  }
+
+  fetch(url: string, options: ResourceFetchOptions): AbortablePromise<Buffer> | null {
+    const tag = options.element ? options.element.localName : '';
+    const wanted = (tag === 'link' && this.options.stylesheets) || (tag === 'script' && this.options.scripts);
+    if (!wanted) {
+      return null;
+    }
+
+    // Pages served over http(s) may not read local files
+    const load = loadResource(url, this.options.loader, this.fromFile);
+    const tracked = this.options.tracker ? this.options.tracker.track(load) : load;
+    const promise = tracked.then(text => Buffer.from(text || '', 'utf8'));
+    return Object.assign(promise, { abort: () => undefined });
+  }
+}
+
+type EventHandler = ((event: Event) => void) | null;
+
+/**
+ * Replace XMLHttpRequest, WebSocket and EventSource with stubs that fail without touching the
+ * network. jsdom implements them with real HTTP and socket connections that bypass the
+ * resource loader, so page scripts could otherwise reach any host.
+ * @param window jsdom window, before the page is parsed
+ */
+export function blockNetworkRequests(window: Window): void {
+  const { EventTarget: BaseTarget, Event: WindowEvent } = window as unknown as {
+    EventTarget: new () => EventTarget;
+    Event: typeof Event;
+  };
+
+  // Fire events the way a failed connection would, after the current task
+  const fail = (target: EventTarget, types: string[], before: () => void) => {
+    window.setTimeout(() => {
+      before();
+      for (const type of types) {
+        const event = new WindowEvent(type);
+        target.dispatchEvent(event);
+        const handler = (target as unknown as Record<string, EventHandler>)[`on${type}`];
+        if (typeof handler === 'function') handler.call(target, event);
+      }
+    }, 0);
+  };
+
+  class BlockedXMLHttpRequest extends BaseTarget {
+    static readonly UNSENT = 0;
+    static readonly OPENED = 1;
+    static readonly HEADERS_RECEIVED = 2;
+    static readonly LOADING = 3;
+    static readonly DONE = 4;
+
+    readyState = 0;
+    status = 0;
+    statusText = '';
+    response = '';
+    responseText = '';
+    responseType = '';
+    responseURL = '';
+    responseXML = null;
+    timeout = 0;
+    withCredentials = false;
+    upload = new BaseTarget();
+    onreadystatechange: EventHandler = null;
+    onloadstart: EventHandler = null;
+    onprogress: EventHandler = null;
+    onabort: EventHandler = null;
+    onerror: EventHandler = null;
+    onload: EventHandler = null;
+    ontimeout: EventHandler = null;
+    onloadend: EventHandler = null;
+
+    open(): void {
+      this.readyState = 1;
+    }
+
+    send(): void {
+      fail(this, ['readystatechange', 'error', 'loadend'], () => {
+        this.readyState = 4;
+      });
+    }
+
+    abort(): void {
+      this.readyState = 0;
+    }
+
+    setRequestHeader(): void {}
+    overrideMimeType(): void {}
+    getResponseHeader(): null {
+      return null;
+    }
+    getAllResponseHeaders(): string {
+      return '';
+    }
+  }
+
+  class BlockedWebSocket extends BaseTarget {
+    static readonly CONNECTING = 0;
+    static readonly OPEN = 1;
+    static readonly CLOSING = 2;
+    static readonly CLOSED = 3;
+
+    readonly url: string;
+    readyState = 0;
+    bufferedAmount = 0;
+    extensions = '';
+    protocol = '';
+    binaryType = 'blob';
+    onopen: EventHandler = null;
+    onmessage: EventHandler = null;
+    onerror: EventHandler = null;
+    onclose: EventHandler = null;
+
+    constructor(url: string) {
+      super();
+      this.url = String(url);
+      fail(this, ['error', 'close'], () => {
+        this.readyState = 3;
+      });
+    }
+
+    send(): void {}
+    close(): void {
+      this.readyState = 3;
+    }
+  }
+
+  class BlockedEventSource extends BaseTarget {
+    static readonly CONNECTING = 0;
+    static readonly OPEN = 1;
+    static readonly CLOSED = 2;
+
+    readonly url: string;
+    readonly withCredentials = false;
+    readyState = 0;
+    onopen: EventHandler = null;
+    onmessage: EventHandler = null;
+    onerror: EventHandler = null;
+
+    constructor(url: string) {
+      super();
+      this.url = String(url);
+      fail(this, ['error'], () => {
+        this.readyState = 2;
+      });
+    }
+
+    close(): void {
+      this.readyState = 2;
+    }
+  }
+
+  Object.assign(window, {
+    XMLHttpRequest: BlockedXMLHttpRequest,
+    WebSocket: BlockedWebSocket,
+    EventSource: BlockedEventSource,
+  });
+}
+
+/**
+ * Resolve the text of a stylesheet or script
+ * @param url Absolute resource URL
+ * @param loader Caller-supplied loader, tried first
+ * @param allowFiles Whether `file:` URLs may be read from disk
+ * @returns The resource text, or null when it is unavailable
+ */
+export async function loadResource(url: string, loader?: SubresourceLoader, allowFiles = true): Promise<string | null> {
+  try {
+    const custom = loader ? await loader(url) : null;
+    if (custom !== null && custom !== undefined) return custom;
+
+    if (allowFiles && url.startsWith('file:')) {
+      return await fs.promises.readFile(fileURLToPath(url), 'utf8');
+    }
+  } catch {
+    // A missing resource should not stop the scan
+  }
+  return null;
+}
+
+/**
+ * Wait until a window has fired its load event, i.e. all stylesheets and scripts have loaded.
+ * Gives up after `timeout` ms, e.g. when a caller's resource loader never settles.
+ * @param window jsdom window
+ * @param timeout Milliseconds to wait at most
+ * @returns True if the page loaded, false if the timeout was reached first
+ */
+export function waitForLoad(window: Window, timeout = DEFAULT_SETTLE_TIMEOUT): Promise<boolean> {
+  if (window.document.readyState === 'complete') {
+    return Promise.resolve(true);
+  }
+  return new Promise(resolve => {
+    const onLoad = () => {
+      clearTimeout(timer);
+      resolve(true);
+    };
+    const timer = setTimeout(() => {
+      window.removeEventListener('load', onLoad);
+      resolve(false);
+    }, timeout);
+    window.addEventListener('load', onLoad, { once: true });
+  });
+}
+
+/**
+ * Wait for a client-rendered page to settle: until `settle.selector` matches, or else until
+ * the page has loaded and no subresource has loaded for `settle.networkIdle` ms.
+ * Gives up after `settle.timeout` ms.
+ * @param window jsdom window
+ * @param tracker Tracker fed by the page's resource loader
+ * @param settle Settle conditions
+ * @returns True if the page settled, false if the timeout was reached first
+ * @throws Error when `settle.selector` is not a valid selector
+ */
+export function waitForSettle(window: Window, tracker: RequestTracker, settle: SettleOptions = {}): Promise<boolean> {
+  const timeout = settle.timeout ?? DEFAULT_SETTLE_TIMEOUT;
+  const networkIdle = settle.networkIdle ?? DEFAULT_NETWORK_IDLE;
+
+  // Reject an invalid selector now rather than failing on every poll until the timeout
+  if (settle.selector) {
+    try {
+      window.document.querySelector(settle.selector);
+    } catch {
+      return Promise.reject(new Error(`Invalid settle selector: ${settle.selector}`));
+    }
+  }
+
+  const settled = (): boolean => {
+    if (settle.selector) {
+      return window.document.querySelector(settle.selector) !== null;
+    }
+    return window.document.readyState === 'complete' && tracker.idleFor() >= networkIdle;
+  };
+
+  return new Promise(resolve => {
+    const finish = (result: boolean) => {
+      clearInterval(poll);
+      clearTimeout(timer);
+      resolve(result);
+    };
+    const poll = setInterval(() => {
+      if (settled()) finish(true);
+    }, SETTLE_POLL_INTERVAL);
+    const timer = setTimeout(() => finish(false), timeout);
+  });
+}
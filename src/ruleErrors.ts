@@ -0,0 +1,39 @@
+import { RuleError, ScanResults } from './types';
+
+/**
+ * Thrown by a scan with failOnRuleError set when one or more rules threw
+ */
+export class RuleFailureError extends Error {
+  /** The rules that threw */
+  readonly errors: RuleError[];
+
+  constructor(errors: RuleError[]) {
+    super(`${errors.length} rule(s) failed: ${errors.map(error => `${error.rule}: ${error.message}`).join('; ')}`);
+    this.name = 'RuleFailureError';
+    this.errors = errors;
+  }
+}
+
+/**
+ * Record what a rule threw
+ * @param rule Name of the rule that threw
+ * @param error Thrown value
+ */
+export function toRuleError(rule: string, error: unknown): RuleError {
+  if (error instanceof Error) {
+    return { rule, message: error.message, ...(error.stack ? { stack: error.stack } : {}) };
+  }
+  return { rule, message: String(error) };
+}
+
+/**
+ * Describe the rules that failed, for the "scan incomplete" banner of reports
+ * @returns The description, or an empty string when every rule ran
+ */
+export function describeRuleErrors(results: Pick<ScanResults, 'errors'>): string {
+  const errors = results.errors || [];
+  if (errors.length === 0) return '';
+
+  const rules = errors.map(error => error.rule).filter((rule, index, all) => all.indexOf(rule) === index);
+  return `Scan incomplete: ${rules.length} rule(s) failed (${rules.join(', ')}), so their checks are missing from these results`;
+}
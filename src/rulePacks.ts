@@ -0,0 +1,46 @@
+import path from 'path';
+import { DefinedRule } from './types';
+import { isDefinedRule } from './defineRule';
+
+/**
+ * Load the defined rules exported by a rule pack
+ * @param name npm package name, or a path relative to cwd
+ * @param cwd Directory packages and relative paths are resolved from
+ * @returns The pack's rules
+ * @throws Error when the pack cannot be resolved or does not export defined rules
+ */
+export function loadRulePack(name: string, cwd: string = process.cwd()): DefinedRule[] {
+  const request = name.startsWith('.') || path.isAbsolute(name) ? path.resolve(cwd, name) : name;
+
+  let loaded: unknown;
+  try {
+    loaded = require(require.resolve(request, { paths: [cwd] }));
+  } catch (error) {
+    throw new Error(`Could not load rule pack "${name}": ${(error as Error).message}`);
+  }
+
+  const rules = packRules(loaded);
+  if (!rules || rules.length === 0 || !rules.every(isDefinedRule)) {
+    throw new Error(`Rule pack "${name}" must export an array of rules created with defineRule, or { rules }`);
+  }
+  return rules;
+}
+
+/**
+ * Load several rule packs
+ * @param names npm package names or paths
+ * @param cwd Directory packages and relative paths are resolved from
+ */
+export function loadRulePacks(names: string[], cwd: string = process.cwd()): DefinedRule[] {
+  return names.reduce<DefinedRule[]>((rules, name) => rules.concat(loadRulePack(name, cwd)), []);
+}
+
+// Packs may be CommonJS or compiled ES modules, with the rules as the export or under `rules`
+function packRules(loaded: unknown): unknown[] | undefined {
+  for (const candidate of [loaded, (loaded as { default?: unknown } | null)?.default]) {
+    if (Array.isArray(candidate)) return candidate;
+    const rules = (candidate as { rules?: unknown } | null | undefined)?.rules;
+    if (Array.isArray(rules)) return rules;
+  }
+  return undefined;
+}
@@ -1,4 +1,15 @@
 import { ScannerOptions, ScanResults, ElementInfo } from '../types';
+import { isHidden, resolveIdReferences } from '../utils/accname';
+import {
+  ARIA_ATTRIBUTES,
+  ARIA_ROLES,
+  AriaAttribute,
+  isConcreteRole,
+  isValidAttributeValue,
+  supportedAttributes
+} from '../utils/ariaSpec';
+import { elementContext } from '../utils/elements';
+import { explicitRole, getRole, roleTokens } from '../utils/roles';
 
 /**
  * Accessibility checker for ARIA roles
@@ -15,7 +26,8 @@ export default {
         const results: ScanResults = {
         passes: [],
         violations: [],
-        warnings: []
+        warnings: [],
+        incomplete: []
         };
 
         // Check ARIA roles
@@ -26,6 +38,10 @@ export default {
         
         // Check ARIA states and properties
         checkAriaStatesProperties(document, results);
+
+        // Check required owned elements and required parent roles
+        checkRequiredOwnedElements(document, results);
+        checkRequiredContext(document, results);
         
         // Check for overriding native semantics
         checkNativeSemantics(document, results);
@@ -40,43 +56,41 @@ export default {
  * @param results Scan results
  */
 function checkAriaRoles(document: Document, results: ScanResults): void {
-  const validRoles = [
-    'alert', 'alertdialog', 'application', 'article', 'banner', 'button', 'cell', 'checkbox',
-    'columnheader', 'combobox', 'complementary', 'contentinfo', 'definition', 'dialog',
-    'directory', 'document', 'feed', 'figure', 'form', 'grid', 'gridcell', 'group', 'heading',
-    'img', 'link', 'list', 'listbox', 'listitem', 'log', 'main', 'marquee', 'math', 'menu',
-    'menubar', 'menuitem', 'menuitemcheckbox', 'menuitemradio', 'navigation', 'none',
-    'note', 'option', 'presentation', 'progressbar', 'radio', 'radiogroup', 'region',
-    'row', 'rowgroup', 'rowheader', 'scrollbar', 'search', 'searchbox', 'separator',
-    'slider', 'spinbutton', 'status', 'switch', 'tab', 'table', 'tablist', 'tabpanel',
-    'term', 'textbox', 'timer', 'toolbar', 'tooltip', 'tree', 'treegrid', 'treeitem'
-  ];
-  
   // Check all elements with role attributes
   const elementsWithRole = document.querySelectorAll('[role]');
   
   elementsWithRole.forEach(element => {
-    const role = element.getAttribute('role');
+    const tokens = roleTokens(element);
     
-    if (!role) return;
+    if (tokens.length === 0) return;
+
+    // Later tokens are fallbacks, so the attribute is only invalid when no token is a valid role
+    const role = explicitRole(element);
+    const value = tokens.join(' ');
     
     const info: ElementInfo = {
       tagName: element.tagName.toLowerCase(),
-      role: role,
+      role: role || value,
       id: element.id || null,
       className: element.className || null
     };
     
     // Check if role is valid
-    if (!validRoles.includes(role)) {
+    if (!role) {
+      const isAbstract = tokens.every(token => Object.prototype.hasOwnProperty.call(ARIA_ROLES, token));
       results.violations.push({
         rule: 'aria-role-valid',
         element: info,
         impact: 'serious',
-        description: `Invalid ARIA role: "${role}"`,
+        description: isAbstract
+          ? `Abstract ARIA role used in content: "${value}"`
+          : `Invalid ARIA role: "${value}"`,
         snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(element),
         wcag: ['4.1.2'],
-        help: `Use only valid ARIA roles. "${role}" is not a valid ARIA role.`
+        help: isAbstract
+          ? `"${value}" is an abstract ARIA role that only exists to organise the taxonomy. Use one of its concrete subclass roles instead.`
+          : `Use only valid ARIA roles. "${value}" is not a valid ARIA role.`
       });
     } else {
       results.passes.push({
@@ -84,6 +98,18 @@ function checkAriaRoles(document: Document, results: ScanResults): void {
         element: info,
         description: `Element has valid ARIA role: "${role}"`
       });
+
+      if (ARIA_ROLES[role].deprecated) {
+        results.warnings.push({
+          rule: 'aria-deprecated-role',
+          element: info,
+          impact: 'minor',
+          description: `ARIA role "${role}" is deprecated in WAI-ARIA 1.2`,
+          snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
+          help: `Replace the deprecated "${role}" role, for example with role="list"`
+        });
+      }
       
       // Check if role is applied to appropriate element
       if (!isRoleAllowedOnElement(element.tagName.toLowerCase(), role)) {
@@ -93,6 +119,7 @@ function checkAriaRoles(document: Document, results: ScanResults): void {
           impact: 'serious',
           description: `ARIA role "${role}" is not allowed on <${element.tagName.toLowerCase()}> element`,
           snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
           wcag: ['4.1.2'],
           help: `Ensure ARIA roles are used on elements that support them`
         });
@@ -107,40 +134,31 @@ function checkAriaRoles(document: Document, results: ScanResults): void {
  * @param results Scan results
  */
 function checkRequiredAriaAttributes(document: Document, results: ScanResults): void {
-  // Map of roles to their required attributes
-  const requiredAttributes: { [key: string]: string[] } = {
-    'combobox': ['aria-expanded', 'aria-controls'],
-    'slider': ['aria-valuemin', 'aria-valuemax', 'aria-valuenow'],
-    'scrollbar': ['aria-valuemin', 'aria-valuemax', 'aria-valuenow', 'aria-controls'],
-    'progressbar': ['aria-valuemin', 'aria-valuemax', 'aria-valuenow'],
-    'spinbutton': ['aria-valuemin', 'aria-valuemax', 'aria-valuenow']
-  };
-  
   // Check elements with roles that require specific attributes
-  Object.keys(requiredAttributes).forEach(role => {
-    const elements = document.querySelectorAll(`[role="${role}"]`);
+  document.querySelectorAll('[role]').forEach(element => {
+    const role = explicitRole(element);
+    if (!role || !ARIA_ROLES[role].required) return;
+
+    const info: ElementInfo = {
+      tagName: element.tagName.toLowerCase(),
+      role: role,
+      id: element.id || null
+    };
     
-    elements.forEach(element => {
-      const info: ElementInfo = {
-        tagName: element.tagName.toLowerCase(),
-        role: role,
-        id: element.id || null
-      };
-      
-      // Check each required attribute
-      requiredAttributes[role].forEach(attrName => {
-        if (!element.hasAttribute(attrName)) {
-          results.violations.push({
-            rule: 'aria-required-attr',
-            element: info,
-            impact: 'serious',
-            description: `Element with role="${role}" is missing required attribute: ${attrName}`,
-            snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
-            wcag: ['4.1.2'],
-            help: `Elements with role="${role}" must have ${attrName} attribute`
-          });
-        }
-      });
+    // Check each required attribute
+    (ARIA_ROLES[role].required || []).forEach(attrName => {
+      if (!element.hasAttribute(attrName) && !isProvidedNatively(element, attrName)) {
+        results.violations.push({
+          rule: 'aria-required-attr',
+          element: info,
+          impact: 'serious',
+          description: `Element with role="${role}" is missing required attribute: ${attrName}`,
+          snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
+          wcag: ['4.1.2'],
+          help: `Elements with role="${role}" must have ${attrName} attribute`
+        });
+      }
     });
   });
 }
@@ -151,33 +169,14 @@ function checkRequiredAriaAttributes(document: Document, results: ScanResults):
  * @param results Scan results
  */
 function checkAriaStatesProperties(document: Document, results: ScanResults): void {
-  // Valid ARIA states and properties
-  const validAriaAttributes = [
-    'aria-activedescendant', 'aria-atomic', 'aria-autocomplete', 'aria-busy', 'aria-checked',
-    'aria-colcount', 'aria-colindex', 'aria-colspan', 'aria-controls', 'aria-current',
-    'aria-describedby', 'aria-details', 'aria-disabled', 'aria-dropeffect', 'aria-errormessage',
-    'aria-expanded', 'aria-flowto', 'aria-grabbed', 'aria-haspopup', 'aria-hidden',
-    'aria-invalid', 'aria-keyshortcuts', 'aria-label', 'aria-labelledby', 'aria-level',
-    'aria-live', 'aria-modal', 'aria-multiline', 'aria-multiselectable', 'aria-orientation',
-    'aria-owns', 'aria-placeholder', 'aria-posinset', 'aria-pressed', 'aria-readonly',
-    'aria-relevant', 'aria-required', 'aria-roledescription', 'aria-rowcount', 'aria-rowindex',
-    'aria-rowspan', 'aria-selected', 'aria-setsize', 'aria-sort', 'aria-valuemax',
-    'aria-valuemin', 'aria-valuenow', 'aria-valuetext'
-  ];
-  
-  // Boolean attributes that must have values "true" or "false"
-  const booleanAttributes = [
-    'aria-atomic', 'aria-busy', 'aria-disabled', 'aria-expanded', 'aria-grabbed',
-    'aria-hidden', 'aria-modal', 'aria-multiline', 'aria-multiselectable', 'aria-pressed',
-    'aria-readonly', 'aria-required', 'aria-selected'
-  ];
-  
   // Find all elements with aria-* attributes
   const allElements = document.querySelectorAll('*');
   
   allElements.forEach(element => {
     // Get all attributes for this element
     const attributes = element.attributes;
+    const role = getRole(element);
+    let supported: Set<string> | null = null;
     
     for (let i = 0; i < attributes.length; i++) {
       const attr = attributes[i];
@@ -190,45 +189,199 @@ function checkAriaStatesProperties(document: Document, results: ScanResults): vo
       const info: ElementInfo = {
         tagName: element.tagName.toLowerCase(),
         id: element.id || null,
+        role: role || undefined,
         attrName: attr.name,
         attrValue: attr.value
       };
+      const definition = ARIA_ATTRIBUTES[attr.name];
       
       // Check if the aria attribute is valid
-      if (!validAriaAttributes.includes(attr.name)) {
+      if (!definition) {
         results.violations.push({
           rule: 'aria-valid-attr',
           element: info,
           impact: 'serious',
           description: `Invalid ARIA attribute: "${attr.name}"`,
           snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
           wcag: ['4.1.2'],
           help: `Use only valid ARIA attributes. "${attr.name}" is not a valid ARIA attribute.`
         });
-      } else {
-        results.passes.push({
-          rule: 'aria-valid-attr',
+        continue;
+      }
+
+      results.passes.push({
+        rule: 'aria-valid-attr',
+        element: info,
+        description: `Element has valid ARIA attribute: "${attr.name}"`
+      });
+
+      // Check the attribute is not prohibited on the element's role
+      if (role && isConcreteRole(role) && (ARIA_ROLES[role].prohibited || []).includes(attr.name)) {
+        results.violations.push({
+          rule: 'aria-prohibited-attr',
           element: info,
-          description: `Element has valid ARIA attribute: "${attr.name}"`
+          impact: 'serious',
+          description: `ARIA attribute "${attr.name}" is not allowed on elements with role "${role}"`,
+          snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
+          wcag: ['4.1.2'],
+          help: `Elements with role "${role}" cannot be named. Give the element a role that supports ${attr.name}, or move the text into its content.`
+        });
+      }
+
+      // Check the value matches the attribute's type; an empty value is the same as leaving it out
+      if (attr.value.trim() && !isValidAttributeValue(attr.name, attr.value)) {
+        const isBoolean = ['true/false', 'true/false/undefined', 'tristate'].includes(definition.type);
+        results.violations.push({
+          rule: isBoolean ? 'aria-boolean-value' : 'aria-valid-attr-value',
+          element: info,
+          impact: 'serious',
+          description: isBoolean
+            ? `ARIA boolean attribute "${attr.name}" must have value ${describeValues(definition)}, got "${attr.value}"`
+            : `ARIA attribute "${attr.name}" has an invalid value: "${attr.value}"`,
+          snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
+          wcag: ['4.1.2'],
+          help: `The value of ${attr.name} must be ${describeValues(definition)}`
+        });
+      }
+
+      // Check for deprecated attributes
+      if (definition.deprecatedAsGlobal && !supported) {
+        supported = elementSupportedAttributes(element, role);
+      }
+      const deprecatedOnRole = definition.deprecatedAsGlobal && supported !== null && !supported.has(attr.name);
+      if (definition.deprecated || deprecatedOnRole) {
+        results.warnings.push({
+          rule: 'aria-deprecated-attr',
+          element: info,
+          impact: 'minor',
+          description: definition.deprecated
+            ? `ARIA attribute "${attr.name}" is deprecated in WAI-ARIA 1.2`
+            : `ARIA attribute "${attr.name}" is deprecated on ${role ? `role "${role}"` : 'elements without a role'}`,
+          snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
+          help: definition.deprecated
+            ? `Remove ${attr.name}; assistive technologies no longer support it`
+            : `${attr.name} is only supported on roles that list it, such as form controls and widgets`
         });
-        
-        // Check boolean attributes have valid values
-        if (booleanAttributes.includes(attr.name) && attr.value !== 'true' && attr.value !== 'false') {
-          results.violations.push({
-            rule: 'aria-boolean-value',
-            element: info,
-            impact: 'serious',
-            description: `ARIA boolean attribute "${attr.name}" must have value "true" or "false", got "${attr.value}"`,
-            snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
-            wcag: ['4.1.2'],
-            help: `Boolean ARIA attributes must have values of either "true" or "false"`
-          });
-        }
       }
     }
   });
 }
 
+/**
+ * Attributes native form controls support whatever their role, including inputs such as
+ * password or date that have no ARIA role
+ */
+const FORM_CONTROL_ATTRIBUTES = ['aria-disabled', 'aria-errormessage', 'aria-invalid', 'aria-readonly', 'aria-required'];
+
+/**
+ * Attributes an element supports: those of its role, plus form control states on native controls
+ * @param element Element to check
+ * @param role Its role, or null when it has none
+ */
+function elementSupportedAttributes(element: Element, role: string | null): Set<string> {
+  const supported = supportedAttributes(role);
+  if (['input', 'select', 'textarea'].includes(element.localName)) {
+    FORM_CONTROL_ATTRIBUTES.forEach(name => supported.add(name));
+  }
+  return supported;
+}
+
+/**
+ * Check that elements with roles such as list or tablist own the children their role requires
+ * @param document DOM document
+ * @param results Scan results
+ */
+function checkRequiredOwnedElements(document: Document, results: ScanResults): void {
+  document.querySelectorAll('[role]').forEach(element => {
+    const role = getRole(element) || '';
+    const required = isConcreteRole(role) ? ARIA_ROLES[role].requiredOwned : undefined;
+    if (!required || element.getAttribute('aria-busy') === 'true') return;
+
+    const owned = ownedElements(element);
+    // Nothing to check yet: containers are often filled in by script
+    if (owned.length === 0 && !(element.textContent || '').trim()) return;
+
+    const info: ElementInfo = {
+      tagName: element.tagName.toLowerCase(),
+      role: role,
+      id: element.id || null
+    };
+    const ownsRequired = required.some(entry => {
+      const [group, child] = entry.split(' > ');
+      return owned.some(item => {
+        const itemRole = getRole(item);
+        if (!child) return itemRole === group;
+        return itemRole === child || (itemRole === group && ownedElements(item).some(nested => getRole(nested) === child));
+      });
+    });
+    const names = required.map(entry => entry.split(' > ').pop()).join(' or ');
+
+    if (ownsRequired) {
+      results.passes.push({
+        rule: 'aria-required-children',
+        element: info,
+        description: `Element with role="${role}" contains ${names} elements`
+      });
+      return;
+    }
+
+    results.violations.push({
+      rule: 'aria-required-children',
+      element: info,
+      impact: 'critical',
+      description: `Element with role="${role}" does not contain any elements with role ${names}`,
+      snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+      ...elementContext(element),
+      wcag: ['1.3.1'],
+      help: `Elements with role="${role}" must contain or own (with aria-owns) elements with role ${names}`
+    });
+  });
+}
+
+/**
+ * Check that elements with roles such as listitem or tab are inside the container their role requires
+ * @param document DOM document
+ * @param results Scan results
+ */
+function checkRequiredContext(document: Document, results: ScanResults): void {
+  document.querySelectorAll('[role]').forEach(element => {
+    const role = getRole(element) || '';
+    const required = isConcreteRole(role) ? ARIA_ROLES[role].requiredContext : undefined;
+    if (!required) return;
+
+    const info: ElementInfo = {
+      tagName: element.tagName.toLowerCase(),
+      role: role,
+      id: element.id || null
+    };
+    const parentRole = contextRole(element);
+
+    if (parentRole && required.includes(parentRole)) {
+      results.passes.push({
+        rule: 'aria-required-parent',
+        element: info,
+        description: `Element with role="${role}" is inside an element with role="${parentRole}"`
+      });
+      return;
+    }
+
+    results.violations.push({
+      rule: 'aria-required-parent',
+      element: info,
+      impact: 'critical',
+      description: `Element with role="${role}" is not inside an element with role ${required.join(' or ')}`,
+      snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+      ...elementContext(element),
+      wcag: ['1.3.1'],
+      help: `Place elements with role="${role}" inside (or own them from) an element with role ${required.join(' or ')}`
+    });
+  });
+}
+
 /**
  * Check for overriding native semantics with ARIA roles
  * @param document DOM document
@@ -269,7 +422,7 @@ function checkNativeSemantics(document: Document, results: ScanResults): void {
         return;
       }
 
-      const role = element.getAttribute('role');
+      const role = explicitRole(element);
 
       // Special handling for input elements (role depends on type)
       if (tagName === 'input') {
@@ -293,6 +446,7 @@ function checkNativeSemantics(document: Document, results: ScanResults): void {
             impact: 'minor',
             description: `Redundant role: <input type="${inputType}"> already has implicit role="${role}"`,
             snippet: element.outerHTML,
+            ...elementContext(element),
             help: `Avoid redundant ARIA roles that match the element's implicit role`
           });
         }
@@ -314,6 +468,7 @@ function checkNativeSemantics(document: Document, results: ScanResults): void {
           impact: 'minor',
           description: `Redundant role: <${tagName}> already has implicit role="${role}"`,
           snippet: element.outerHTML,
+          ...elementContext(element),
           help: `Avoid redundant ARIA roles that match the element's implicit role`
         });
       }
@@ -344,4 +499,91 @@ function isRoleAllowedOnElement(tagName: string, role: string): boolean {
   
   // By default, assume role is allowed
   return true;
-}
\ No newline at end of file
+}
+
+/**
+ * Whether a native element supplies a state that its explicit role requires,
+ * e.g. the checkedness of <input type="checkbox" role="switch">
+ * @param element Element with the role
+ * @param attrName Required attribute
+ */
+function isProvidedNatively(element: Element, attrName: string): boolean {
+  const type = (element.getAttribute('type') || '').toLowerCase();
+  return attrName === 'aria-checked' && element.tagName.toLowerCase() === 'input' && (type === 'checkbox' || type === 'radio');
+}
+
+/**
+ * Describe the values an attribute accepts, for messages
+ * @param definition Attribute definition
+ */
+function describeValues(definition: AriaAttribute): string {
+  switch (definition.type) {
+    case 'true/false':
+      return '"true" or "false"';
+    case 'true/false/undefined':
+      return '"true", "false" or "undefined"';
+    case 'tristate':
+      return '"true", "false", "mixed" or "undefined"';
+    case 'integer':
+      return 'an integer';
+    case 'number':
+      return 'a number';
+    case 'token':
+      return `one of ${(definition.values || []).map(value => `"${value}"`).join(', ')}`;
+    case 'tokens':
+      return `a space-separated list of ${(definition.values || []).map(value => `"${value}"`).join(', ')}`;
+    default:
+      return 'a valid value';
+  }
+}
+
+/**
+ * Whether an element is only a wrapper for accessibility purposes, so its children
+ * count as children of its parent
+ * @param element Element to check
+ */
+function isTransparent(element: Element): boolean {
+  const role = getRole(element);
+  return role === null || role === 'generic' || role === 'none' || role === 'presentation';
+}
+
+/**
+ * Elements an element owns in the accessibility tree: its nearest descendants that are
+ * not plain wrappers, plus elements referenced by aria-owns
+ * @param element Owning element
+ */
+function ownedElements(element: Element): Element[] {
+  const owned: Element[] = [];
+  const visit = (parent: Element) => {
+    Array.from(parent.children).forEach(child => {
+      if (isHidden(child)) return;
+      if (isTransparent(child)) {
+        visit(child);
+      } else {
+        owned.push(child);
+      }
+    });
+  };
+
+  visit(element);
+  return owned.concat(resolveIdReferences(element, 'aria-owns'));
+}
+
+/**
+ * Role of the element an element belongs to: the element that owns it with aria-owns,
+ * or its nearest ancestor that is not a plain wrapper
+ * @param element Element to check
+ * @returns Role of the context element, or null when there is none
+ */
+function contextRole(element: Element): string | null {
+  const owner = element.id
+    ? Array.from(element.ownerDocument.querySelectorAll('[aria-owns]'))
+      .find(candidate => (candidate.getAttribute('aria-owns') || '').split(/\s+/).includes(element.id))
+    : undefined;
+  if (owner) return getRole(owner);
+
+  for (let parent = element.parentElement; parent; parent = parent.parentElement) {
+    if (!isTransparent(parent)) return getRole(parent);
+  }
+  return null;
+}
@@ -1,4 +1,5 @@
 import { ScannerOptions, ScanResults, ElementInfo } from '../types';
+import { elementContext } from '../utils/elements';
 
 /**
  * Check CSS background images for potentially meaningful content that lacks text alternatives.
@@ -8,7 +9,8 @@ export default {
     const results: ScanResults = {
       passes: [],
       violations: [],
-      warnings: []
+      warnings: [],
+      incomplete: []
     };
 
     checkBackgroundImages(document, window, results);
@@ -58,14 +60,16 @@ function checkBackgroundImages(document: Document, window: Window, results: Scan
 
     if (!hasTextContent && !hasAriaLabel && !hasAriaLabelledby && !hasTitle) {
       if (backgroundImage.includes('url(') && !isBackgroundLikelyDecorative(element)) {
-        results.warnings.push({
+        results.incomplete.push({
           rule: 'background-image',
           element: info,
           impact: 'moderate',
           description: 'Element with background image may need text alternative',
           snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
           wcag: ['1.1.1'],
-          help: 'If the background image conveys meaning, add text alternative via aria-label or text content'
+          reason: 'Only a person can tell whether a background image conveys meaning',
+          review: 'If the background image conveys meaning, add a text alternative via role="img" and aria-label, or visible text'
         });
       }
     }
@@ -1,5 +1,10 @@
 import { ScannerOptions, ScanResults, ElementInfo } from '../types';
+import { compositeColors, contrastRatio as calculateContrastRatio, formatColor, parseColor, RGBA } from '../utils/color';
+import { elementContext } from '../utils/elements';
+import { fontSizeInPixels, fontWeightValue, isLargeText as isLargeTextSize } from '../utils/typography';
 
+const WHITE: RGBA = { r: 255, g: 255, b: 255, a: 1 };
+const BLACK: RGBA = { r: 0, g: 0, b: 0, a: 1 };
 
 /**
  * Check accessibility of text elements (contrast, etc.)
@@ -16,10 +21,12 @@ export default {
         const results: ScanResults = {
         passes: [],
         violations: [],
-        warnings: []
+        warnings: [],
+        incomplete: []
         };
-        const backgroundColorCache = new WeakMap<Element, string>();
-        const parsedColorCache = new Map<string, { r: number; g: number; b: number } | null>();
+        const backdropCache = new WeakMap<Element, Backdrop>();
+        const fontSizeCache = new WeakMap<Element, number>();
+        const fontWeightCache = new WeakMap<Element, number>();
 
         // Minimum contrast requirements by WCAG level
         const contrastRequirements = {
@@ -57,40 +64,94 @@ export default {
         }
 
         const textColor = style.color;
-        const bgColor = getBackgroundColor(element, window, backgroundColorCache);
         
         // Skip if we couldn't determine colors
-        if (!textColor || !bgColor) {
+        if (!textColor) {
             continue;
         }
 
+        const backdrop = getBackdrop(element, window, backdropCache);
+
         // Determine if text is large according to WCAG
-        const fontSize = parseFloat(style.fontSize);
-        const fontWeight = style.fontWeight;
-        const isLargeText = fontSize >= 24 || (fontSize >= 18.5 && isBoldWeight(fontWeight));
+        const fontSize = fontSizeInPixels(element, window, fontSizeCache);
+        const fontWeight = fontWeightValue(element, window, fontWeightCache);
+        const isLargeText = isLargeTextSize(fontSize, fontWeight);
         
         // Get required contrast ratio
         const requiredRatio = isLargeText ? requirements.largeText : requirements.normalText;
+        const threshold = `required: ${requiredRatio}:1 for ${isLargeText ? 'large' : 'normal'} text`;
 
-        // Calculate contrast ratio
-        const contrastRatio = calculateContrastRatio(textColor, bgColor, parsedColorCache);
+        // Calculate contrast ratio against each possible background (gradient stops) and keep the
+        // worst, blending translucent text over the background
+        const foreground = getTextColor(element, window);
+        let contrastRatio = Infinity;
+        let background = backdrop.colors[0];
+        if (foreground) {
+            for (const color of backdrop.colors) {
+                const ratio = calculateContrastRatio(compositeColors(foreground, color), color);
+                if (ratio < contrastRatio) {
+                    contrastRatio = ratio;
+                    background = color;
+                }
+            }
+        }
+        const bgColor = formatColor(background);
+        const overGradient = backdrop.colors.length > 1 ? ' at the worst gradient stop' : '';
         
         const info: ElementInfo = {
             tagName: element.tagName.toLowerCase(),
             id: element.id || null,
             className: element.className || null,
-            textContent: element.textContent.trim().substring(0, 30) + (element.textContent.trim().length > 30 ? '...' : '')
+            textContent: element.textContent.trim().substring(0, 30) + (element.textContent.trim().length > 30 ? '...' : ''),
+            fontSize: Math.round(fontSize * 100) / 100,
+            fontWeight,
+            largeText: isLargeText
         };
 
+        // An image behind the text could be any color, so ask for a manual check rather than guess
+        if (backdrop.image) {
+            results.incomplete.push({
+            rule: 'color-contrast',
+            element: info,
+            impact: 'serious',
+            description: `Text is over a background image (${threshold})`,
+            snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+            ...elementContext(element),
+            wcag: level === 'AAA' ? ['1.4.6'] : ['1.4.3'],
+            reason: 'The contrast of text over an image cannot be computed from styles alone',
+            review: `Check that the text has a contrast ratio of at least ${requiredRatio}:1 against every part of the image behind it; if not, put a solid or semi-opaque background behind the text`
+            });
+            continue;
+        }
+
+        // A text color that cannot be parsed (e.g. color-mix() or an unresolved var() in jsdom) has no known contrast
+        if (!foreground) {
+            results.incomplete.push({
+            rule: 'color-contrast',
+            element: info,
+            impact: 'serious',
+            description: `Text color could not be determined: "${textColor}" (${threshold})`,
+            snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+            ...elementContext(element),
+            wcag: level === 'AAA' ? ['1.4.6'] : ['1.4.3'],
+            reason: 'The computed text color is not a color value the scanner can parse',
+            review: `Check that the text has a contrast ratio of at least ${requiredRatio}:1 against background ${bgColor}`
+            });
+            continue;
+        }
+
         if (contrastRatio < requiredRatio) {
             results.violations.push({
             rule: 'color-contrast',
             element: info,
             impact: 'serious',
-            description: `Insufficient color contrast ratio: ${contrastRatio.toFixed(2)}:1 (required: ${requiredRatio}:1)`,
+            description: `Insufficient color contrast ratio: ${contrastRatio.toFixed(2)}:1${overGradient} (${threshold})`,
             snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+            ...elementContext(element),
             wcag: level === 'AAA' ? ['1.4.6'] : ['1.4.3'],
-            help: `Text elements must have a contrast ratio of at least ${requiredRatio}:1`,
+            help: isLargeText
+                ? `Large text (at least 18pt, or 14pt bold) must have a contrast ratio of at least ${requiredRatio}:1`
+                : `Text elements must have a contrast ratio of at least ${requiredRatio}:1`,
             fix: {
                 code: `color: ${textColor}; /* Adjust to achieve ${requiredRatio}:1 contrast ratio against background ${bgColor} */`,
                 description: `Increase the contrast between the text color (${textColor}) and background color (${bgColor})`,
@@ -101,7 +162,7 @@ export default {
             results.passes.push({
             rule: 'color-contrast',
             element: info,
-            description: `Sufficient color contrast ratio: ${contrastRatio.toFixed(2)}:1`
+            description: `Sufficient color contrast ratio: ${contrastRatio.toFixed(2)}:1${overGradient} (${threshold})`
             });
         }
         }
@@ -122,174 +183,141 @@ function isElementHidden(element: Element, style: CSSStyleDeclaration): boolean
   return style.display === 'none' || style.visibility === 'hidden' || style.opacity === '0';
 }
 
-function isBoldWeight(fontWeight: string): boolean {
-  const numeric = parseInt(fontWeight, 10);
-  if (!Number.isNaN(numeric)) return numeric >= 700;
-  return fontWeight === 'bold' || fontWeight === 'bolder';
+/**
+ * Get the text color of an element, resolving currentColor to the inherited color
+ * @param element Element to check
+ * @param window Browser window
+ */
+function getTextColor(element: Element, window: Window): RGBA | null {
+  const color = window.getComputedStyle(element).color;
+  if (!color) return null;
+
+  // For the color property itself, currentColor means the parent's color
+  if (color.trim().toLowerCase() === 'currentcolor') {
+    return element.parentElement ? getTextColor(element.parentElement, window) : BLACK;
+  }
+  return parseColor(color);
+}
+
+/**
+ * What is painted behind an element's text
+ */
+interface Backdrop {
+  /** Possible background colors: one for a solid background, one per stop for a gradient */
+  colors: RGBA[];
+  /** An image of unknown color shows through */
+  image: boolean;
 }
 
+const GRADIENT = /^(?:-webkit-|-moz-)?(?:repeating-)?(?:linear|radial|conic)-gradient\((.*)\)$/;
+
 /**
- * Get the effective background color of an element
+ * Get the effective background of an element: its background color and background image
+ * layers composited over those of its ancestors, down to the first opaque layer or the
+ * white canvas. Gradients contribute their color stops; other images are marked unknown.
  * @param element Element to check
  * @param window Browser window
+ * @param cache Backdrops already resolved for this scan
  */
-function getBackgroundColor(
+function getBackdrop(
   element: Element,
   window: Window,
-  cache: WeakMap<Element, string>,
-): string | null {
+  cache: WeakMap<Element, Backdrop>,
+): Backdrop {
   const cached = cache.get(element);
   if (cached) return cached;
 
-  let current = element;
-  
-  while (current !== null && current.nodeType === 1) {
-    const cachedCurrent = cache.get(current);
-    if (cachedCurrent) {
-      cache.set(element, cachedCurrent);
-      return cachedCurrent;
-    }
+  const style = window.getComputedStyle(element);
+  const value = style.backgroundColor;
+  const own = !value
+    ? null
+    : value.trim().toLowerCase() === 'currentcolor'
+      ? getTextColor(element, window)
+      : parseColor(value);
+
+  let backdrop: Backdrop;
+  if (own && own.a >= 1) {
+    backdrop = { colors: [own], image: false };
+  } else {
+    const below = element.parentElement
+      ? getBackdrop(element.parentElement, window, cache)
+      : { colors: [WHITE], image: false };
+    backdrop = own && own.a > 0
+      ? { colors: below.colors.map(color => compositeColors(own, color)), image: below.image }
+      : below;
+  }
+
+  // The first listed layer is painted on top, so apply them bottom-up
+  const layers = splitTopLevel(style.backgroundImage || '', ',').reverse();
+  for (const layer of layers) {
+    if (!layer || layer.toLowerCase() === 'none') continue;
 
-    const style = window.getComputedStyle(current);
-    const backgroundColor = style.backgroundColor;
-    
-    if (backgroundColor && backgroundColor !== 'transparent' && backgroundColor !== 'rgba(0, 0, 0, 0)') {
-      cache.set(current, backgroundColor);
-      cache.set(element, backgroundColor);
-      return backgroundColor;
+    const stops = gradientStops(layer, () => getTextColor(element, window));
+    if (!stops) {
+      backdrop = { colors: backdrop.colors, image: true };
+    } else if (stops.length === 0) {
+      continue;
+    } else if (stops.every(stop => stop.a >= 1)) {
+      backdrop = { colors: uniqueColors(stops), image: false };
+    } else {
+      const colors: RGBA[] = [];
+      stops.forEach(stop => backdrop.colors.forEach(color => colors.push(compositeColors(stop, color))));
+      backdrop = { colors: uniqueColors(colors), image: backdrop.image };
     }
-    
-    current = current.parentElement as Element;
   }
-  
-  // If we couldn't find a background color, default to white
-  cache.set(element, 'rgb(255, 255, 255)');
-  return 'rgb(255, 255, 255)';
+
+  cache.set(element, backdrop);
+  return backdrop;
 }
 
 /**
- * Calculate contrast ratio between two colors
- * @param foreground Foreground color
- * @param background Background color
+ * Get the color stops of a CSS gradient
+ * @param layer One background-image layer
+ * @param currentColor Resolves currentColor stops
+ * @returns Stop colors, or null if the layer is not a gradient
  */
-function calculateContrastRatio(
-  foreground: string,
-  background: string,
-  cache: Map<string, { r: number; g: number; b: number } | null>,
-): number {
-  const fgRgb = parseColor(foreground, cache);
-  const bgRgb = parseColor(background, cache);
-  
-  if (!fgRgb || !bgRgb) {
-    return 0;
-  }
-  
-  const fgLuminance = calculateLuminance(fgRgb.r, fgRgb.g, fgRgb.b);
-  const bgLuminance = calculateLuminance(bgRgb.r, bgRgb.g, bgRgb.b);
-  
-  const lighter = Math.max(fgLuminance, bgLuminance);
-  const darker = Math.min(fgLuminance, bgLuminance);
-  
-  return (lighter + 0.05) / (darker + 0.05);
+function gradientStops(layer: string, currentColor: () => RGBA | null): RGBA[] | null {
+  const match = layer.match(GRADIENT);
+  if (!match) return null;
+
+  const stops: RGBA[] = [];
+  splitTopLevel(match[1], ',').forEach(argument => {
+    // A stop is a color followed by optional positions; direction and shape arguments have no color
+    splitTopLevel(argument, ' ').forEach(token => {
+      const color = token.toLowerCase() === 'currentcolor' ? currentColor() : parseColor(token);
+      if (color) stops.push(color);
+    });
+  });
+  return stops;
 }
 
 /**
- * Parse color string to RGB values
- * @param color Color string
+ * Split a CSS value on a separator, ignoring separators inside parentheses
  */
-function parseColor(
-  color: string,
-  cache: Map<string, { r: number; g: number; b: number } | null>,
-): { r: number; g: number; b: number } | null {
-  const cached = cache.get(color);
-  if (cached !== undefined) return cached;
-
-  // Handle 'rgb(r, g, b)' format
-  let match = color.match(/rgb\(\s*(\d+)\s*,\s*(\d+)\s*,\s*(\d+)\s*\)/i);
-  if (match) {
-    const parsed = {
-      r: parseInt(match[1]),
-      g: parseInt(match[2]),
-      b: parseInt(match[3])
-    };
-    cache.set(color, parsed);
-    return parsed;
-  }
-  
-  // Handle 'rgba(r, g, b, a)' format
-  match = color.match(/rgba\(\s*(\d+)\s*,\s*(\d+)\s*,\s*(\d+)\s*,\s*([0-9.]+)\s*\)/i);
-  if (match) {
-    const parsed = {
-      r: parseInt(match[1]),
-      g: parseInt(match[2]),
-      b: parseInt(match[3])
-    };
-    cache.set(color, parsed);
-    return parsed;
-  }
-  
-  // Handle hex format (#RRGGBB)
-  match = color.match(/#([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})/i);
-  if (match) {
-    const parsed = {
-      r: parseInt(match[1], 16),
-      g: parseInt(match[2], 16),
-      b: parseInt(match[3], 16)
-    };
-    cache.set(color, parsed);
-    return parsed;
-  }
-  
-  // Handle shorthand hex format (#RGB)
-  match = color.match(/#([0-9a-f])([0-9a-f])([0-9a-f])/i);
-  if (match) {
-    const parsed = {
-      r: parseInt(match[1] + match[1], 16),
-      g: parseInt(match[2] + match[2], 16),
-      b: parseInt(match[3] + match[3], 16)
-    };
-    cache.set(color, parsed);
-    return parsed;
-  }
-  
-  // Handle common color names
-  const colorNames: { [key: string]: { r: number; g: number; b: number } } = {
-    black: { r: 0, g: 0, b: 0 },
-    white: { r: 255, g: 255, b: 255 },
-    red: { r: 255, g: 0, b: 0 },
-    green: { r: 0, g: 128, b: 0 },
-    blue: { r: 0, g: 0, b: 255 },
-    yellow: { r: 255, g: 255, b: 0 },
-    gray: { r: 128, g: 128, b: 128 }
-  };
-  
-  if (colorNames[color.toLowerCase()]) {
-    const parsed = colorNames[color.toLowerCase()];
-    cache.set(color, parsed);
-    return parsed;
+function splitTopLevel(value: string, separator: string): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let current = '';
+  for (const char of value) {
+    if (char === '(') depth++;
+    if (char === ')') depth--;
+    if (depth === 0 && (char === separator || (separator === ' ' && /\s/.test(char)))) {
+      if (current.trim()) parts.push(current.trim());
+      current = '';
+    } else {
+      current += char;
+    }
   }
-  
-  cache.set(color, null);
-  return null;
+  if (current.trim()) parts.push(current.trim());
+  return parts;
 }
 
-/**
- * Calculate relative luminance of a color
- * @param r Red component
- * @param g Green component
- * @param b Blue component
- */
-function calculateLuminance(r: number, g: number, b: number): number {
-  // Normalize RGB values
-  const rSrgb = r / 255;
-  const gSrgb = g / 255;
-  const bSrgb = b / 255;
-  
-  // Convert to linear RGB
-  const rLinear = rSrgb <= 0.03928 ? rSrgb / 12.92 : Math.pow((rSrgb + 0.055) / 1.055, 2.4);
-  const gLinear = gSrgb <= 0.03928 ? gSrgb / 12.92 : Math.pow((gSrgb + 0.055) / 1.055, 2.4);
-  const bLinear = bSrgb <= 0.03928 ? bSrgb / 12.92 : Math.pow((bSrgb + 0.055) / 1.055, 2.4);
-  
-  // Calculate luminance
-  return 0.2126 * rLinear + 0.7152 * gLinear + 0.0722 * bLinear;
+function uniqueColors(colors: RGBA[]): RGBA[] {
+  const seen = new Set<string>();
+  return colors.filter(color => {
+    const key = `${color.r},${color.g},${color.b},${color.a}`;
+    if (seen.has(key)) return false;
+    seen.add(key);
+    return true;
+  });
 }
@@ -1,4 +1,6 @@
 import { ScannerOptions, ScanResults, ElementInfo } from "../types";
+import { elementContext } from '../utils/elements';
+import { computeAccessibleName, accessibleName } from '../utils/accname';
 
 /**
  * Check form accessibility (labels, etc.)
@@ -15,7 +17,8 @@ export default {
         const results: ScanResults = {
             passes: [],
             violations: [],
-            warnings: []
+            warnings: [],
+            incomplete: []
         };
 
         // Check input elements for labels
@@ -30,6 +33,9 @@ export default {
         // Check validation and error messages
         checkFormValidation(document, results);
 
+        // Check that logins work with password managers and paste (WCAG 2.2)
+        checkAuthentication(document, results);
+
         return results;
 
     }
@@ -47,61 +53,63 @@ function checkInputLabels(document: Document, results: ScanResults): void {
     );
 
     formControls.forEach(control => {
-        const info: ElementInfo = {
-            tagName: control.tagName.toLowerCase(),
-            type: (control as HTMLInputElement).type || null,
-            id: control.id || null,
-            name: control.getAttribute('name') || null
-        };
-
         // Skip if in a hidden container
         if (isElementHidden(control)) {
             return;
         }
 
-        // Check for accessibility name
-        const hasExplicitLabel = control.id && document.querySelector(`label[for="${control.id}"]`);
-        const hasWrappingLabel = control.closest('label');
-        const hasAriaLabel = control.hasAttribute('aria-label');
-        const hasAriaLabelledby = control.hasAttribute('aria-labelledby');
-        const hasTitle = control.hasAttribute('title');
+        // Labels that point at empty text or missing ids do not count
+        const { name: labelText, source } = computeAccessibleName(control);
+        const info: ElementInfo = {
+            tagName: control.tagName.toLowerCase(),
+            type: (control as HTMLInputElement).type || null,
+            id: control.id || null,
+            name: control.getAttribute('name') || null,
+            accessibleName: labelText || null
+        };
 
-        if (!hasExplicitLabel && !hasWrappingLabel && !hasAriaLabel && !hasAriaLabelledby && !hasTitle) {
-            // Form control has no  accessible name
+        if (!labelText || source === 'placeholder') {
+            // Form control has no accessible name
             results.violations.push({
                 rule: 'form-label',
                 element: info,
-                description: 'Form control does not have a label',
+                description: hasLabellingMarkup(control)
+                    ? 'Form control label is empty or refers to a missing element'
+                    : 'Form control does not have a label',
                 snippet: control.outerHTML,
+                ...elementContext(control),
                 wcag: ['1.3.1', '2.4.6', '3.3.2', '4.1.2'],
                 help: 'Each form control mush have a label',
                 helpUrl: 'https://www.w3.org/WAI/WCAG21/Understanding/labels-and-instructions.html',
                 impact: "critical"
             });
-        } else if (hasExplicitLabel || hasWrappingLabel) {
+        } else if (source === 'native') {
             results.passes.push({
                 rule: 'form-label',
                 element: info,
                 description: 'Form control has proper label element',
-                snippet: control.outerHTML
+                snippet: control.outerHTML,
+                ...elementContext(control)
             });
         } else {
             results.passes.push({
                 rule: 'form-label-alternative',
                 element: info,
                 description: 'Form control has alternative labelling method',
-                snippet: control.outerHTML
+                snippet: control.outerHTML,
+                ...elementContext(control)
             });
         }
 
         // Check placeholder as label issue
-        if (control.hasAttribute('placeholder') && !hasExplicitLabel && !hasWrappingLabel && !hasAriaLabel && !hasAriaLabelledby) {
+        if (control.hasAttribute('placeholder') && (source === 'placeholder' || source === 'title' || !labelText)) {
             results.warnings.push({
                 rule: 'placeholder-label',
                 element: info,
                 impact: 'serious',
                 description: 'Placeholder is being used instead of a label',
                 snippet: control.outerHTML,
+                ...elementContext(control),
                 wcag: ['1.3.1', '3.3.2'],
                 help: 'Placeholders should not be used as replacement for labels'
             });
@@ -131,6 +139,7 @@ function checkRequiredAttributes(document: Document, results: ScanResults): void
                 impact: 'critical',
                 description: 'Select element has no options',
                 snippet: select.outerHTML,
+                ...elementContext(select),
                 wcag: ['4.1.2'],
                 help: 'Select elements must contain option elements'
             });
@@ -139,7 +148,8 @@ function checkRequiredAttributes(document: Document, results: ScanResults): void
                 rule:'select-options',
                 element: info,
                 description: 'Select element has options',
-                snippet: select.outerHTML
+                snippet: select.outerHTML,
+                ...elementContext(select)
             });
         }
     });
@@ -162,6 +172,7 @@ function checkRequiredAttributes(document: Document, results: ScanResults): void
                 impact: 'serious',
                 description: 'Fieldset does not have a legend',
                 snippet: fieldset.outerHTML.slice(0, 150) + (fieldset.outerHTML.length > 150 ? '...' : ''),
+                ...elementContext(fieldset),
                 wcag: ['1.3.1', '3.3.2'],
                 help: 'Fieldsets must have a legend that describes the group'
             });
@@ -172,6 +183,7 @@ function checkRequiredAttributes(document: Document, results: ScanResults): void
                 impact: 'serious',
                 description: 'Fieldset has an empty legend',
                 snippet: fieldset.outerHTML.slice(0, 150) + (fieldset.outerHTML.length > 150 ? '...' : ''),
+                ...elementContext(fieldset),
                 wcag: ['1.3.1', '3.3.2'],
                 help: 'Legends must contain descriptive text'
             });
@@ -180,7 +192,8 @@ function checkRequiredAttributes(document: Document, results: ScanResults): void
                 rule: 'fieldset-legend',
                 element: info,
                 description: 'Fieldset has a legend',
-                snippet: fieldset.outerHTML.slice(0, 150) + (fieldset.outerHTML.length > 150 ? '...' : '')
+                snippet: fieldset.outerHTML.slice(0, 150) + (fieldset.outerHTML.length > 150 ? '...' : ''),
+                ...elementContext(fieldset)
             });
         }
     });
@@ -211,23 +224,21 @@ function checkFormAccessibility(document: Document, results: ScanResults): void
           impact: 'moderate',
           description: 'Form does not have an explicit submit button',
           snippet: form.outerHTML.slice(0, 150) + (form.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(form),
           wcag: ['3.2.2'],
           help: 'Forms should have an explicit submit button'
         });
       }
       
       // Check for accessible name on form
-      const hasAriaLabel = form.hasAttribute('aria-label');
-      const hasAriaLabelledby = form.hasAttribute('aria-labelledby');
-      const hasTitle = form.hasAttribute('title');
-      
-      if (!hasAriaLabel && !hasAriaLabelledby && !hasTitle && !form.id) {
+      if (!accessibleName(form) && !form.id) {
         results.warnings.push({
           rule: 'form-name',
           element: info,
           impact: 'moderate',
           description: 'Form does not have an accessible name',
           snippet: form.outerHTML.slice(0, 150) + (form.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(form),
           wcag: ['4.1.2'],
           help: 'Forms should have an accessible name via aria-label, aria-labelledby, or title'
         });
@@ -258,6 +269,7 @@ function checkFormAccessibility(document: Document, results: ScanResults): void
           impact: 'minor',
           description: 'Required input missing aria-required="true"',
           snippet: input.outerHTML,
+          ...elementContext(input),
           help: 'Add aria-required="true" to reinforce that the field is required'
         });
       }
@@ -280,6 +292,7 @@ function checkFormAccessibility(document: Document, results: ScanResults): void
           impact: 'serious',
           description: 'Input with pattern constraint missing title attribute',
           snippet: input.outerHTML,
+          ...elementContext(input),
           wcag: ['3.3.1', '3.3.2'],
           help: 'Add a title attribute to explain the required format'
         });
@@ -287,6 +300,17 @@ function checkFormAccessibility(document: Document, results: ScanResults): void
     });
   }
   
+  /**
+   * Whether a control has label elements or labelling attributes, whatever they resolve to
+   * @param control Form control
+   */
+  function hasLabellingMarkup(control: Element): boolean {
+    const labels = (control as HTMLInputElement).labels;
+    return Boolean(labels && labels.length > 0) ||
+      control.hasAttribute('aria-label') ||
+      control.hasAttribute('aria-labelledby');
+  }
+
   /**
    * Check if an element is hidden
    * @param element Element to check
@@ -302,4 +326,55 @@ function checkFormAccessibility(document: Document, results: ScanResults): void
     } catch (e) {
       return false;
     }
-  }
\ No newline at end of file
+  }
+
+/**
+ * Check that password and one-time code fields allow paste and autofill, so users
+ * need not transcribe or remember them (WCAG 3.3.8)
+ * @param document DOM document
+ * @param results Scan results
+ */
+function checkAuthentication(document: Document, results: ScanResults): void {
+    const fields = document.querySelectorAll('input[type="password"], input[autocomplete="one-time-code"]');
+
+    fields.forEach(field => {
+        const info: ElementInfo = {
+            tagName: 'input',
+            type: field.getAttribute('type') || null,
+            id: field.id || null,
+            name: field.getAttribute('name') || null
+        };
+        const blocksPaste = /return\s+false|preventDefault/.test(field.getAttribute('onpaste') || '');
+        const blocksAutofill = (field.getAttribute('autocomplete') || '').trim().toLowerCase() === 'off';
+
+        if (blocksPaste) {
+            results.violations.push({
+                rule: 'accessible-authentication',
+                element: info,
+                impact: 'serious',
+                description: 'Authentication field blocks pasting',
+                snippet: field.outerHTML,
+                ...elementContext(field),
+                wcag: ['3.3.8'],
+                help: 'Allow pasting into password and code fields so users can use password managers instead of transcribing'
+            });
+        } else if (blocksAutofill) {
+            results.warnings.push({
+                rule: 'accessible-authentication',
+                element: info,
+                impact: 'minor',
+                description: 'Authentication field turns off autocomplete',
+                snippet: field.outerHTML,
+                ...elementContext(field),
+                wcag: ['3.3.8'],
+                help: 'Use autocomplete="current-password", "new-password" or "one-time-code" so password managers can fill the field'
+            });
+        } else {
+            results.passes.push({
+                rule: 'accessible-authentication',
+                element: info,
+                description: 'Authentication field allows paste and autofill'
+            });
+        }
+    });
+}
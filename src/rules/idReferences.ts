@@ -0,0 +1,238 @@
+import { ScannerOptions, ScanResults, ElementInfo } from '../types';
+import { isHidden } from '../utils/accname';
+import { ARIA_ATTRIBUTES } from '../utils/ariaSpec';
+import { elementContext } from '../utils/elements';
+
+const WCAG = ['1.3.1', '4.1.2'];
+
+/**
+ * An attribute that refers to other elements by id
+ */
+interface ReferenceAttribute {
+  /** Elements that can carry the attribute */
+  selector: string;
+  attribute: string;
+  /** Space-separated list of ids rather than a single id */
+  multiple: boolean;
+  /** Pointing at a hidden element is a normal pattern, e.g. a visually hidden label or a collapsed popup */
+  hiddenTargetAllowed: boolean;
+}
+
+const HIDDEN_TARGET_ALLOWED = ['aria-controls', 'aria-describedby', 'aria-details', 'aria-errormessage', 'aria-labelledby'];
+
+const REFERENCE_ATTRIBUTES: ReferenceAttribute[] = [
+  ...Object.keys(ARIA_ATTRIBUTES)
+    .filter(name => ARIA_ATTRIBUTES[name].type === 'idref' || ARIA_ATTRIBUTES[name].type === 'idrefs')
+    .map(name => ({
+      selector: `[${name}]`,
+      attribute: name,
+      multiple: ARIA_ATTRIBUTES[name].type === 'idrefs',
+      hiddenTargetAllowed: HIDDEN_TARGET_ALLOWED.includes(name),
+    })),
+  { selector: 'label[for]', attribute: 'for', multiple: false, hiddenTargetAllowed: false },
+  { selector: 'td[headers], th[headers]', attribute: 'headers', multiple: true, hiddenTargetAllowed: false },
+];
+
+/**
+ * Check that id references (aria-labelledby, label[for], headers, usemap and the like)
+ * resolve to exactly one element.
+ */
+export default {
+  async check(document: Document, _window: Window, _options: ScannerOptions): Promise<ScanResults> {
+    const results: ScanResults = {
+      passes: [],
+      violations: [],
+      warnings: [],
+      incomplete: []
+    };
+
+    const ids = indexIds(document);
+    const referenced = new Map<string, string>();
+
+    checkIdReferences(document, ids, referenced, results);
+    checkImageMaps(document, results);
+    checkDuplicateIds(ids, referenced, results);
+
+    return results;
+  }
+};
+
+/**
+ * Map each id to the elements that carry it, in document order
+ */
+function indexIds(document: Document): Map<string, Element[]> {
+  const ids = new Map<string, Element[]>();
+  document.querySelectorAll('[id]').forEach(element => {
+    if (!element.id) return;
+    const elements = ids.get(element.id);
+    if (elements) {
+      elements.push(element);
+    } else {
+      ids.set(element.id, [element]);
+    }
+  });
+  return ids;
+}
+
+/**
+ * Check id references resolve, and that they do not point at hidden elements where that breaks them
+ * @param document DOM document
+ * @param ids Id index
+ * @param referenced Collects referenced ids and the first attribute that referenced them
+ * @param results Scan results
+ */
+function checkIdReferences(
+  document: Document,
+  ids: Map<string, Element[]>,
+  referenced: Map<string, string>,
+  results: ScanResults,
+): void {
+  REFERENCE_ATTRIBUTES.forEach(reference => {
+    document.querySelectorAll(reference.selector).forEach(element => {
+      const value = element.getAttribute(reference.attribute) || '';
+      const tokens = reference.multiple ? value.split(/\s+/).filter(Boolean) : [value.trim()].filter(Boolean);
+      if (tokens.length === 0) return;
+
+      const info: ElementInfo = {
+        tagName: element.tagName.toLowerCase(),
+        id: element.id || null,
+        attrName: reference.attribute,
+        attrValue: value
+      };
+      const missing = tokens.filter(id => !ids.has(id));
+      tokens.forEach(id => {
+        if (ids.has(id) && !referenced.has(id)) referenced.set(id, reference.attribute);
+      });
+
+      if (missing.length > 0) {
+        results.violations.push({
+          rule: 'id-reference-missing',
+          element: info,
+          impact: missing.length === tokens.length ? 'serious' : 'moderate',
+          description: `${reference.attribute} refers to ${missing.length === 1 ? 'an id' : 'ids'} that ${missing.length === 1 ? 'does' : 'do'} not exist: ${missing.map(id => `"${id}"`).join(', ')}`,
+          snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
+          wcag: WCAG,
+          help: `Make sure every id in ${reference.attribute} matches an element on the page, or remove the reference`
+        });
+      } else {
+        results.passes.push({
+          rule: 'id-reference-missing',
+          element: info,
+          description: `${reference.attribute} refers to existing elements`
+        });
+      }
+
+      if (reference.hiddenTargetAllowed) return;
+
+      tokens
+        .filter(id => {
+          const targets = ids.get(id);
+          return targets !== undefined && isHidden(targets[0]);
+        })
+        .forEach(id => {
+          results.warnings.push({
+            rule: 'id-reference-hidden',
+            element: info,
+            impact: 'moderate',
+            description: `${reference.attribute} refers to a hidden element: "${id}"`,
+            snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+            ...elementContext(element),
+            wcag: WCAG,
+            help: `Assistive technologies ignore hidden elements, so the relationship from ${reference.attribute} is lost. Show the element or point at a visible one.`
+          });
+        });
+    });
+  });
+}
+
+/**
+ * Check usemap attributes name an existing <map>
+ * @param document DOM document
+ * @param results Scan results
+ */
+function checkImageMaps(document: Document, results: ScanResults): void {
+  const names = new Set<string>();
+  document.querySelectorAll('map').forEach(map => {
+    const name = map.getAttribute('name') || map.id;
+    if (name) names.add(name);
+  });
+
+  document.querySelectorAll('[usemap]').forEach(element => {
+    const value = element.getAttribute('usemap') || '';
+    const info: ElementInfo = {
+      tagName: element.tagName.toLowerCase(),
+      id: element.id || null,
+      src: element.getAttribute('src'),
+      attrName: 'usemap',
+      attrValue: value
+    };
+
+    // usemap is a hash-name reference such as "#nav"
+    if (value.startsWith('#') && names.has(value.slice(1))) {
+      results.passes.push({
+        rule: 'id-reference-missing',
+        element: info,
+        description: 'usemap refers to an existing map'
+      });
+      return;
+    }
+
+    results.violations.push({
+      rule: 'id-reference-missing',
+      element: info,
+      impact: 'serious',
+      description: value.startsWith('#')
+        ? `usemap refers to a map that does not exist: "${value.slice(1)}"`
+        : `usemap must start with "#": "${value}"`,
+      snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+      ...elementContext(element),
+      wcag: WCAG,
+      help: 'Set usemap to "#" followed by the name of a <map> element on the page'
+    });
+  });
+}
+
+/**
+ * Check for ids used by more than one element. A reference only reaches the first of them,
+ * so duplicates that are referenced are violations and the rest are warnings.
+ * @param ids Id index
+ * @param referenced Referenced ids and the attribute that referenced them
+ * @param results Scan results
+ */
+function checkDuplicateIds(ids: Map<string, Element[]>, referenced: Map<string, string>, results: ScanResults): void {
+  ids.forEach((elements, id) => {
+    if (elements.length < 2) return;
+
+    const duplicate = elements[1];
+    const info: ElementInfo = {
+      tagName: duplicate.tagName.toLowerCase(),
+      id
+    };
+    const attribute = referenced.get(id);
+    const issue = {
+      element: info,
+      snippet: duplicate.outerHTML.slice(0, 150) + (duplicate.outerHTML.length > 150 ? '...' : ''),
+      ...elementContext(duplicate),
+      wcag: WCAG,
+    };
+
+    if (attribute) {
+      results.violations.push({
+        ...issue,
+        rule: 'duplicate-id-referenced',
+        impact: 'serious',
+        description: `id "${id}" is used by ${elements.length} elements and referenced by ${attribute}`,
+        help: `References only reach the first element with an id. Give each element a unique id so ${attribute} points where you intend.`
+      });
+    } else {
+      results.warnings.push({
+        ...issue,
+        rule: 'duplicate-id',
+        impact: 'minor',
+        description: `id "${id}" is used by ${elements.length} elements`,
+        help: 'Give each element a unique id so it can be referenced reliably'
+      });
+    }
+  });
+}
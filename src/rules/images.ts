@@ -1,4 +1,6 @@
 import { ScannerOptions, ScanResults, ElementInfo } from '../types';
+import { elementContext } from '../utils/elements';
+import { accessibleName } from '../utils/accname';
 
 /**
  * Check image accessibility (alt text, etc.)
@@ -15,7 +17,8 @@ export default {
         const results: ScanResults = {
         passes: [],
         violations: [],
-        warnings: []
+        warnings: [],
+        incomplete: []
         };
 
         // Check <img> elements
@@ -55,6 +58,7 @@ function checkImageElements(document: Document, results: ScanResults): void {
         impact: 'critical',
         description: 'Image is missing alt text',
         snippet: img.outerHTML,
+        ...elementContext(img),
         wcag: ['1.1.1'],
         help: 'Images must have alternative text',
         helpUrl: 'https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html'
@@ -65,21 +69,24 @@ function checkImageElements(document: Document, results: ScanResults): void {
       if (altText === '') {
         // Empty alt is valid for decorative images
         if (!isLikelyDecorativeImage(img)) {
-          results.warnings.push({
+          results.incomplete.push({
             rule: 'img-alt-decorative',
             element: info,
             impact: 'moderate',
             description: 'Image has empty alt text but may not be decorative',
             snippet: img.outerHTML,
+            ...elementContext(img),
             wcag: ['1.1.1'],
-            help: 'Verify this image is decorative; if not, add descriptive alt text'
+            reason: 'Only a person can tell whether an image is decorative',
+            review: 'Check that the image adds no information; if it does, add descriptive alt text'
           });
         } else {
           results.passes.push({
             rule: 'img-alt-decorative',
             element: info,
             description: 'Decorative image has appropriate empty alt text',
-            snippet: img.outerHTML
+            snippet: img.outerHTML,
+            ...elementContext(img)
           });
         }
       } else if (hasGenericAltText(altText)) {
@@ -90,6 +97,7 @@ function checkImageElements(document: Document, results: ScanResults): void {
           impact: 'moderate',
           description: 'Image may have generic/placeholder alt text',
           snippet: img.outerHTML,
+          ...elementContext(img),
           wcag: ['1.1.1'],
           help: 'Replace generic alt text with specific description'
         });
@@ -101,6 +109,7 @@ function checkImageElements(document: Document, results: ScanResults): void {
           impact: 'minor',
           description: 'Alt text is unusually long (over 125 characters)',
           snippet: img.outerHTML,
+          ...elementContext(img),
           wcag: ['1.1.1'],
           help: 'Consider using a more concise alt text or using a longdesc attribute'
         });
@@ -109,7 +118,8 @@ function checkImageElements(document: Document, results: ScanResults): void {
           rule: 'img-alt',
           element: info,
           description: 'Image has appropriate alt text',
-          snippet: img.outerHTML
+          snippet: img.outerHTML,
+          ...elementContext(img)
         });
       }
     }
@@ -122,6 +132,7 @@ function checkImageElements(document: Document, results: ScanResults): void {
         impact: 'minor',
         description: 'Image is missing width and/or height attributes',
         snippet: img.outerHTML,
+        ...elementContext(img),
         help: 'Set explicit width and height to prevent layout shifts'
       });
     }
@@ -151,33 +162,36 @@ function checkSvgElements(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'SVG element should have role="img"',
         snippet: svg.outerHTML.slice(0, 150) + (svg.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(svg),
         wcag: ['1.1.1'],
         help: 'Add role="img" to SVG elements'
       });
     }
     
-    // Check for accessible name via title or aria-label
-    const title = svg.querySelector('title');
-    const ariaLabel = svg.getAttribute('aria-label');
-    const ariaLabelledby = svg.getAttribute('aria-labelledby');
+    // Check for accessible name via title, aria-label or aria-labelledby
+    const title = Array.from(svg.children).find(child => child.localName === 'title');
+    const name = accessibleName(svg);
+    info.accessibleName = name || null;
     
-    if (!title && !ariaLabel && !ariaLabelledby) {
+    if (!name && !title) {
       results.violations.push({
         rule: 'svg-accessible-name',
         element: info,
         impact: 'serious',
         description: 'SVG lacks accessible name (title, aria-label, or aria-labelledby)',
         snippet: svg.outerHTML.slice(0, 150) + (svg.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(svg),
         wcag: ['1.1.1'],
         help: 'Add a <title> element or aria-label attribute to SVG'
       });
-    } else if (title && !title.textContent?.trim()) {
+    } else if (!name) {
       results.violations.push({
         rule: 'svg-title-empty',
         element: info,
         impact: 'serious',
         description: 'SVG title element is empty',
         snippet: svg.outerHTML.slice(0, 150) + (svg.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(svg),
         wcag: ['1.1.1'],
         help: 'Add content to the SVG title element'
       });
@@ -186,7 +200,8 @@ function checkSvgElements(document: Document, results: ScanResults): void {
         rule: 'svg-accessible-name',
         element: info,
         description: 'SVG has an accessible name',
-        snippet: svg.outerHTML.slice(0, 150) + (svg.outerHTML.length > 150 ? '...' : '')
+        snippet: svg.outerHTML.slice(0, 150) + (svg.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(svg)
       });
     }
   });
@@ -217,6 +232,7 @@ function checkImageMaps(document: Document, results: ScanResults): void {
         impact: 'minor',
         description: 'Image map appears to be unused',
         snippet: map.outerHTML,
+        ...elementContext(map),
         help: 'Remove unused image maps'
       });
       return;
@@ -241,6 +257,7 @@ function checkImageMaps(document: Document, results: ScanResults): void {
           impact: 'critical',
           description: 'Area element in image map is missing alt text',
           snippet: area.outerHTML,
+          ...elementContext(area),
           wcag: ['1.1.1', '2.4.4'],
           help: 'Add alt text to all area elements'
         });
@@ -249,7 +266,8 @@ function checkImageMaps(document: Document, results: ScanResults): void {
           rule: 'area-alt',
           element: areaInfo,
           description: 'Area element has alt text',
-          snippet: area.outerHTML
+          snippet: area.outerHTML,
+          ...elementContext(area)
         });
       }
     });
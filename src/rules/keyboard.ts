@@ -1,4 +1,19 @@
 import { ScannerOptions, ScanResults, ElementInfo } from '../types';
+import { elementContext } from '../utils/elements';
+
+/**
+ * Pointer targets that WCAG 2.5.8 applies to
+ */
+const TARGET_SELECTOR = [
+  'a[href]', 'button', 'input:not([type="hidden"])', 'select', 'textarea', 'summary',
+  '[role="button"]', '[role="link"]', '[role="checkbox"]', '[role="radio"]', '[role="switch"]',
+  '[role="tab"]', '[role="menuitem"]',
+].join(', ');
+
+/**
+ * Minimum target width and height in CSS pixels (WCAG 2.5.8)
+ */
+const MIN_TARGET_SIZE = 24;
 
 /**
  * Accessibility checker for tab index, keyboard events, focus indicators, and interactive elements
@@ -15,7 +30,8 @@ export default {
         const results: ScanResults = {
         passes: [],
         violations: [],
-        warnings: []
+        warnings: [],
+        incomplete: []
         };
 
         // Check tabindex values
@@ -30,6 +46,10 @@ export default {
         // Check interactive elements
         checkInteractiveElements(document, results);
         
+        // Check target sizes and sticky content over focus (WCAG 2.2)
+        checkTargetSize(document, window, results);
+        checkFocusNotObscured(document, window, results);
+        
         return results;
   }
 };
@@ -60,6 +80,7 @@ function checkTabindex(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: `Element has a positive tabindex value (${tabindexNum})`,
         snippet: element.outerHTML,
+        ...elementContext(element),
         wcag: ['2.4.3'],
         help: 'Avoid positive tabindex values as they create a custom tab order'
       });
@@ -83,6 +104,7 @@ function checkTabindex(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'Non-interactive element has a tabindex making it focusable',
         snippet: element.outerHTML,
+        ...elementContext(element),
         wcag: ['2.1.1'],
         help: 'Only make interactive elements focusable or add appropriate ARIA roles'
       });
@@ -132,6 +154,7 @@ function checkKeyboardEvents(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'Element has mouse event handlers but no keyboard event handlers',
         snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(element),
         wcag: ['2.1.1'],
         help: 'Ensure all functionality is operable through keyboard'
       });
@@ -163,19 +186,19 @@ function checkFocusIndicators(document: Document, window: Window, results: ScanR
       className: element.className || null
     };
     
-    // Check for CSS that might remove the focus outline
+    // Check for CSS that might remove the focus outline. Borders, shadows or
+    // backgrounds set in :focus rules may replace it, so this needs a human to look
     if (style.outlineStyle === 'none' || style.outlineWidth === '0px') {
-      // Look for alternative focus indicators like background-color changes or borders
-      // This is a simplified check
-      
-      results.warnings.push({
+      results.incomplete.push({
         rule: 'focus-visible',
         element: info,
         impact: 'moderate',
-        description: 'Element may be missing visible focus indicator',
+        description: 'Element removes the focus outline',
         snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(element),
         wcag: ['2.4.7'],
-        help: 'Ensure all focusable elements have visible focus indicators'
+        reason: 'Another focus indicator may replace the outline, and the scanner cannot apply :focus styles',
+        review: 'Tab to the element and check that a visible focus indicator appears'
       });
     }
   });
@@ -213,6 +236,7 @@ function checkInteractiveElements(document: Document, results: ScanResults): voi
         impact: 'serious',
         description: 'Interactive element is missing semantic role',
         snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(element),
         wcag: ['4.1.2'],
         help: 'Add role="button" to non-button elements that act as buttons'
       });
@@ -226,6 +250,7 @@ function checkInteractiveElements(document: Document, results: ScanResults): voi
         impact: 'serious',
         description: 'Interactive element is not keyboard focusable',
         snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(element),
         wcag: ['2.1.1'],
         help: 'Add tabindex="0" to make interactive elements focusable'
       });
@@ -254,9 +279,152 @@ function checkInteractiveElements(document: Document, results: ScanResults): voi
         impact: 'moderate',
         description: 'Link opens in new window without warning',
         snippet: link.outerHTML,
+        ...elementContext(link),
         wcag: ['3.2.2'],
         help: 'Indicate in the link text that it opens in a new window'
       });
     }
   });
-}
\ No newline at end of file
+}
+
+/**
+ * Check that pointer targets are at least 24 by 24 CSS pixels (WCAG 2.5.8).
+ * Small targets can still pass through spacing, so they are reported as warnings.
+ * Without layout (e.g. in jsdom) only targets with explicit pixel sizes can be measured;
+ * the others need review.
+ * @param document DOM document
+ * @param window Browser window
+ * @param results Scan results
+ */
+function checkTargetSize(document: Document, window: Window, results: ScanResults): void {
+  document.querySelectorAll(TARGET_SELECTOR).forEach(element => {
+    // Links inside a sentence are exempt
+    if (isInlineInText(element)) return;
+
+    const style = window.getComputedStyle(element);
+    if (style.display === 'none' || style.visibility === 'hidden') return;
+
+    const info: ElementInfo = {
+      tagName: element.tagName.toLowerCase(),
+      id: element.id || null,
+      className: element.className || null
+    };
+    const size = targetSize(element, style);
+    if (!size) {
+      results.incomplete.push({
+        rule: 'target-size',
+        element: info,
+        impact: 'moderate',
+        description: 'Target size could not be measured',
+        snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(element),
+        wcag: ['2.5.8'],
+        reason: 'The target has no layout box and no explicit pixel width and height',
+        review: `Check in a browser that the target is at least ${MIN_TARGET_SIZE}x${MIN_TARGET_SIZE}px, or has enough space around it`
+      });
+      return;
+    }
+
+    const dimensions = `${Math.round(size.width)}x${Math.round(size.height)}px`;
+
+    if (size.width >= MIN_TARGET_SIZE && size.height >= MIN_TARGET_SIZE) {
+      results.passes.push({
+        rule: 'target-size',
+        element: info,
+        description: `Target is at least ${MIN_TARGET_SIZE}x${MIN_TARGET_SIZE}px (${dimensions})`
+      });
+      return;
+    }
+
+    results.warnings.push({
+      rule: 'target-size',
+      element: info,
+      impact: 'moderate',
+      description: `Target is ${dimensions}, smaller than ${MIN_TARGET_SIZE}x${MIN_TARGET_SIZE}px`,
+      snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+      ...elementContext(element),
+      wcag: ['2.5.8'],
+      help: `Make the target at least ${MIN_TARGET_SIZE}x${MIN_TARGET_SIZE}px, or leave enough space around it that a ${MIN_TARGET_SIZE}px circle centred on it does not touch another target`
+    });
+  });
+}
+
+/**
+ * Check for fixed or sticky content that can cover the focused element (WCAG 2.4.11)
+ * @param document DOM document
+ * @param window Browser window
+ * @param results Scan results
+ */
+function checkFocusNotObscured(document: Document, window: Window, results: ScanResults): void {
+  const rootStyle = window.getComputedStyle(document.documentElement);
+  const scrollPadding = [
+    rootStyle.getPropertyValue('scroll-padding-top'),
+    rootStyle.getPropertyValue('scroll-padding-bottom'),
+    rootStyle.getPropertyValue('scroll-padding'),
+  ].some(value => value && !['auto', '0', '0px'].includes(value.trim()));
+
+  document.querySelectorAll('body *').forEach(element => {
+    const style = window.getComputedStyle(element);
+    if (style.position !== 'fixed' && style.position !== 'sticky') return;
+    if (style.display === 'none' || style.visibility === 'hidden' || element.hasAttribute('hidden')) return;
+    // Modal dialogs take focus with them, so they do not cover it
+    if (element.matches('dialog, [role="dialog"], [role="alertdialog"], [aria-modal="true"]')) return;
+    if (!element.textContent?.trim() && !element.querySelector('img, svg, input, button')) return;
+
+    const info: ElementInfo = {
+      tagName: element.tagName.toLowerCase(),
+      id: element.id || null,
+      className: element.className || null
+    };
+
+    if (scrollPadding) {
+      results.passes.push({
+        rule: 'focus-not-obscured',
+        element: info,
+        description: `Page reserves scroll padding for ${style.position} content`
+      });
+      return;
+    }
+
+    results.incomplete.push({
+      rule: 'focus-not-obscured',
+      element: info,
+      impact: 'moderate',
+      description: `${style.position === 'fixed' ? 'Fixed' : 'Sticky'} content may cover focused elements`,
+      snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+      ...elementContext(element),
+      wcag: ['2.4.11'],
+      reason: 'Whether the content covers a focused element depends on layout and scroll position',
+      review: 'Tab through the page and check that no focused element is entirely hidden behind this content; if one is, set scroll-padding on the html element to its height'
+    });
+  });
+}
+
+/**
+ * Size of a target: its layout box, or its explicit pixel width and height when there is no layout (e.g. in jsdom)
+ * @returns Size in CSS pixels, or null if it cannot be determined
+ */
+function targetSize(element: Element, style: CSSStyleDeclaration): { width: number; height: number } | null {
+  const rect = element.getBoundingClientRect();
+  if (rect.width > 0 && rect.height > 0) {
+    return { width: rect.width, height: rect.height };
+  }
+
+  const pixels = (value: string | undefined) => {
+    const match = (value || '').trim().match(/^(\d+(?:\.\d+)?)px$/);
+    return match ? parseFloat(match[1]) : null;
+  };
+  const width = pixels(style.width);
+  const height = pixels(style.height);
+  return width !== null && height !== null ? { width, height } : null;
+}
+
+/**
+ * Whether a link sits inside a line of text, where its size is set by the text around it
+ */
+function isInlineInText(element: Element): boolean {
+  if (element.tagName.toLowerCase() !== 'a' || !element.parentElement) return false;
+  return Array.from(element.parentElement.childNodes).some(
+    node => node.nodeType === 3 && Boolean(node.textContent?.trim())
+  );
+}
@@ -1,4 +1,14 @@
 import { ScannerOptions, ScanResults, ElementInfo } from '../types';
+import { elementContext } from '../utils/elements';
+import { accessibleName, isHidden } from '../utils/accname';
+
+/**
+ * Link text that says nothing about where the link goes
+ */
+const GENERIC_LINK_TEXT = [
+  'click', 'click here', 'continue', 'details', 'go', 'here', 'learn more', 'link',
+  'more', 'more info', 'more information', 'read more', 'this', 'this link',
+];
 
 /**
  * Check accessibility of structural elements (headings, landmarks, etc.)
@@ -15,7 +25,8 @@ export default {
         const results: ScanResults = {
         passes: [],
         violations: [],
-        warnings: []
+        warnings: [],
+        incomplete: []
         };
 
         // Check heading structure
@@ -33,6 +44,10 @@ export default {
         // Check tables
         checkTables(document, results);
         
+        // Check link text and abbreviations (AAA)
+        checkLinkPurpose(document, results);
+        checkAbbreviations(document, results);
+        
         return results;
     }
 };
@@ -59,7 +74,7 @@ function checkHeadings(document: Document, results: ScanResults): void {
       rule: 'heading-h1',
       impact: 'serious',
       description: 'Document does not have an h1 heading',
-      wcag: ['1.3.1', '2.4.6'],
+      wcag: ['2.4.6'],
       help: 'Pages should contain at least one h1 heading for the main content'
     });
   } else if (headingLevels[1] > 1) {
@@ -68,7 +83,7 @@ function checkHeadings(document: Document, results: ScanResults): void {
       rule: 'heading-h1-multiple',
       impact: 'moderate',
       description: `Document has multiple h1 headings (${headingLevels[1]})`,
-      wcag: ['1.3.1', '2.4.6'],
+      wcag: ['2.4.6'],
       help: 'Consider using only one h1 heading for the main content title'
     });
   }
@@ -76,20 +91,23 @@ function checkHeadings(document: Document, results: ScanResults): void {
   // Check heading hierarchy
   headings.forEach((heading, index) => {
     const level = parseInt(heading.tagName.substring(1));
+    const name = accessibleName(heading);
     const info: ElementInfo = {
       tagName: heading.tagName.toLowerCase(),
       id: heading.id || null,
-      textContent: heading.textContent?.trim() || null
+      textContent: heading.textContent?.trim() || null,
+      accessibleName: name || null
     };
     
-    // Check for empty headings
-    if (!heading.textContent?.trim()) {
+    // Check for empty headings; an image with alt text counts as content
+    if (!name && !isHidden(heading)) {
       results.violations.push({
         rule: 'heading-empty',
         element: info,
         impact: 'serious',
         description: `Empty ${heading.tagName} element`,
         snippet: heading.outerHTML,
+        ...elementContext(heading),
         wcag: ['1.3.1', '2.4.6'],
         help: 'Headings must have text content'
       });
@@ -103,6 +121,7 @@ function checkHeadings(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: `Heading level skipped from h${previousLevel} to h${level}`,
         snippet: heading.outerHTML,
+        ...elementContext(heading),
         wcag: ['1.3.1'],
         help: 'Heading levels should not be skipped (e.g., h2 to h4)'
       });
@@ -188,15 +207,15 @@ function checkLandmarkElement(document: Document, selector: string, landmarkName
     
     // Check if landmarks have accessible names
     elements.forEach(element => {
-      const hasAccessibleName = element.hasAttribute('aria-label') || 
-                               element.hasAttribute('aria-labelledby') ||
-                               element.hasAttribute('title');
+      // aria-labelledby pointing at a missing or empty element does not name the landmark
+      const hasAccessibleName = accessibleName(element) !== '';
       
       if (!hasAccessibleName && elements.length > 1) {
         const info: ElementInfo = {
           tagName: element.tagName.toLowerCase(),
           role: element.getAttribute('role') || null,
-          id: element.id || null
+          id: element.id || null,
+          accessibleName: null
         };
         
         results.warnings.push({
@@ -205,6 +224,7 @@ function checkLandmarkElement(document: Document, selector: string, landmarkName
           impact: 'moderate',
           description: `${landmarkName} landmark has no accessible name`,
           snippet: element.outerHTML.slice(0, 150) + (element.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(element),
           help: `When multiple ${landmarkName} landmarks exist, they should have accessible names`
         });
       }
@@ -225,6 +245,7 @@ function checkDocumentStructure(document: Document, results: ScanResults): void
       impact: 'serious',
       description: 'Document language is not specified',
       snippet: document.documentElement.outerHTML.substring(0, 150) + '...',
+      ...elementContext(document.documentElement),
       wcag: ['3.1.1'],
       help: 'Add a lang attribute to the html element'
     });
@@ -251,6 +272,7 @@ function checkDocumentStructure(document: Document, results: ScanResults): void
       rule: 'document-title-empty',
       impact: 'serious',
       description: 'Document title is empty',
+      ...elementContext(title),
       wcag: ['2.4.2'],
       help: 'The title element must contain text'
     });
@@ -308,6 +330,7 @@ function checkLists(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: `${list.tagName} has no list items`,
         snippet: list.outerHTML.slice(0, 150) + (list.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(list),
         wcag: ['1.3.1'],
         help: `${list.tagName} elements must contain li elements`
       });
@@ -324,6 +347,7 @@ function checkLists(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: `${list.tagName} contains direct children that are not li elements`,
         snippet: list.outerHTML.slice(0, 150) + (list.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(list),
         wcag: ['1.3.1'],
         help: `${list.tagName} should only have li elements as direct children`
       });
@@ -350,6 +374,7 @@ function checkLists(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'Definition list has no terms (dt elements)',
         snippet: dl.outerHTML.slice(0, 150) + (dl.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(dl),
         wcag: ['1.3.1'],
         help: 'Definition lists must have at least one dt element'
       });
@@ -362,6 +387,7 @@ function checkLists(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'Definition list has no descriptions (dd elements)',
         snippet: dl.outerHTML.slice(0, 150) + (dl.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(dl),
         wcag: ['1.3.1'],
         help: 'Definition lists must have at least one dd element'
       });
@@ -381,6 +407,7 @@ function checkLists(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'Definition list contains invalid direct children',
         snippet: dl.outerHTML.slice(0, 150) + (dl.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(dl),
         wcag: ['1.3.1'],
         help: 'Definition lists should only contain dt, dd, and div elements'
       });
@@ -412,6 +439,7 @@ function checkTables(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'Table appears to be used for layout',
         snippet: table.outerHTML.slice(0, 150) + (table.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(table),
         help: 'Use CSS for layout instead of tables'
       });
       return;
@@ -427,6 +455,7 @@ function checkTables(document: Document, results: ScanResults): void {
         impact: 'serious',
         description: 'Data table has no headers (th elements)',
         snippet: table.outerHTML.slice(0, 150) + (table.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(table),
         wcag: ['1.3.1'],
         help: 'Data tables should have headers using th elements'
       });
@@ -451,6 +480,7 @@ function checkTables(document: Document, results: ScanResults): void {
             impact: 'serious',
             description: 'Table header is empty',
             snippet: header.outerHTML,
+            ...elementContext(header),
             wcag: ['1.3.1'],
             help: 'Table headers must have text content'
           });
@@ -468,6 +498,7 @@ function checkTables(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'Table does not have a caption',
         snippet: table.outerHTML.slice(0, 150) + (table.outerHTML.length > 150 ? '...' : ''),
+        ...elementContext(table),
         wcag: ['1.3.1'],
         help: 'Data tables should have captions to describe the table content'
       });
@@ -478,6 +509,7 @@ function checkTables(document: Document, results: ScanResults): void {
         impact: 'moderate',
         description: 'Table caption is empty',
         snippet: caption.outerHTML,
+        ...elementContext(caption),
         wcag: ['1.3.1'],
         help: 'Table captions must have text content'
       });
@@ -506,6 +538,7 @@ function checkTables(document: Document, results: ScanResults): void {
           impact: 'moderate',
           description: 'Table headers do not have scope attributes',
           snippet: table.outerHTML.slice(0, 150) + (table.outerHTML.length > 150 ? '...' : ''),
+          ...elementContext(table),
           wcag: ['1.3.1'],
           help: 'Add scope="col" or scope="row" to table headers'
         });
@@ -544,4 +577,72 @@ function isLikelyLayoutTable(table: HTMLTableElement): boolean {
   }
   
   return false;
-}
\ No newline at end of file
+}
+
+/**
+ * Check that link text describes the link's purpose on its own (WCAG 2.4.9, AAA)
+ * @param document DOM document
+ * @param results Results
+ */
+function checkLinkPurpose(document: Document, results: ScanResults): void {
+  document.querySelectorAll('a[href], [role="link"]').forEach(link => {
+    if (isHidden(link)) return;
+
+    const name = accessibleName(link);
+    const normalized = name.toLowerCase().replace(/[^\w\s]/g, '').replace(/\s+/g, ' ').trim();
+    if (!GENERIC_LINK_TEXT.includes(normalized)) return;
+
+    const info: ElementInfo = {
+      tagName: link.tagName.toLowerCase(),
+      id: link.id || null,
+      href: link.getAttribute('href'),
+      accessibleName: name
+    };
+
+    results.violations.push({
+      rule: 'link-purpose',
+      element: info,
+      impact: 'moderate',
+      description: `Link text does not describe its purpose: "${name}"`,
+      snippet: link.outerHTML.slice(0, 150) + (link.outerHTML.length > 150 ? '...' : ''),
+      ...elementContext(link),
+      wcag: ['2.4.9'],
+      help: 'Use link text that makes sense out of context, e.g. "Read more about pricing"'
+    });
+  });
+}
+
+/**
+ * Check that abbreviations carry their expanded form (WCAG 3.1.4, AAA)
+ * @param document DOM document
+ * @param results Results
+ */
+function checkAbbreviations(document: Document, results: ScanResults): void {
+  document.querySelectorAll('abbr').forEach(abbr => {
+    const info: ElementInfo = {
+      tagName: 'abbr',
+      id: abbr.id || null,
+      textContent: abbr.textContent?.trim() || null
+    };
+
+    if (abbr.getAttribute('title')?.trim()) {
+      results.passes.push({
+        rule: 'abbr-expansion',
+        element: info,
+        description: `Abbreviation "${info.textContent}" has an expansion`
+      });
+      return;
+    }
+
+    results.warnings.push({
+      rule: 'abbr-expansion',
+      element: info,
+      impact: 'minor',
+      description: `Abbreviation "${info.textContent}" has no expansion`,
+      snippet: abbr.outerHTML,
+      ...elementContext(abbr),
+      wcag: ['3.1.4'],
+      help: 'Give <abbr> a title with the expanded form, or spell it out the first time it is used'
+    });
+  });
+}
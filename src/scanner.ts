@@ -1,8 +1,26 @@
-import { JSDOM } from "jsdom";
-import { ScannerOptions, ScanResults, Rule } from "./types";
+import { JSDOM, VirtualConsole } from "jsdom";
+import { DefinedRule, ScannerOptions, ScanResults, Rule, RuleError } from "./types";
 import fs from "fs";
 import path from "path";
-import { FAST_RULES, resolveRuleNames } from './rules/presets';
+import { isDefinedRule, selectDefinedRules } from './defineRule';
+import { RuleFailureError, toRuleError } from './ruleErrors';
+import { loadRulePacks } from './rulePacks';
+import { FAST_RULES, resolveRuleNames } from './presets';
+import { applyLevel } from './metadata';
+import { applyRuleOverrides } from './overrides';
+import { applySuppressions } from './suppressions';
+import { loadConfig, mergeOptions } from './config';
+import { baselinePage, compareWithBaseline, loadBaseline } from './baseline';
+import { addLocations, elementLocation } from './utils/locations';
+import { clearIdCache, registerLocator } from './utils/elements';
+import {
+    blockNetworkRequests,
+    RequestTracker,
+    SandboxedResourceLoader,
+    ScriptError,
+    waitForLoad,
+    waitForSettle
+} from './resources';
 
 /**
  * Main WCAG Scanner class
@@ -14,23 +32,26 @@ export class WCAGScanner {
     private window?: Window;
     private results: ScanResults;
     private rules: Map<string, Rule> = new Map();
+    private definedRules: Map<string, DefinedRule> = new Map();
+    private rulePacksLoaded = false;
 
     /**
      * Create a new WCAG Scanner options
-     * @param options Scanner options
+     * @param options Scanner options, merged over any discovered config file
      */
     constructor(options: ScannerOptions = {}) {
-        this.options = {
-            preset: 'fast',
-            level: 'AA',
-            ai: true,
-            ...options
-        };
+        this.options = mergeOptions<ScannerOptions>(
+            { preset: 'fast', level: 'AA', ai: true },
+            loadConfig({ configPath: options.config }),
+            options
+        );
 
         this.results = {
             passes: [],
             violations: [],
-            warnings: []
+            warnings: [],
+            incomplete: [],
+            errors: []
         };
     }
 
@@ -39,16 +60,35 @@ export class WCAGScanner {
      * @param html HTML content to scan
      * @param baseUrl Base URL for relative paths
      * @returns Promise<boolean> True if loaded successfully
+     * @throws ScriptError when runScripts is set, the page's scripts throw and ignoreScriptErrors is not set
+     * @throws Error when runScripts is set and the settle selector is invalid
      */
     async loadHTML(html: string, baseUrl = 'https://example.org'): Promise<boolean> {
+        const runScripts = Boolean(this.options.runScripts);
+        const loadStylesheets = Boolean(this.options.loadStylesheets || this.options.resourceLoader || runScripts);
+        const tracker = new RequestTracker();
+        const scriptErrors: string[] = [];
+        let loaded = false;
+
         try {
-            this.dom?.window.close();
+            this.close();
 
             // Create virtual DOM with robust error handling
             this.dom = new JSDOM(html, {
                 url: baseUrl,
-                runScripts: 'outside-only',
+                runScripts: runScripts ? 'dangerously' : 'outside-only',
                 pretendToBeVisual: true,
+                includeNodeLocations: true,
+                virtualConsole: runScripts ? collectScriptErrors(scriptErrors) : undefined,
+                resources: loadStylesheets
+                    ? new SandboxedResourceLoader({
+                        pageUrl: baseUrl,
+                        stylesheets: true,
+                        scripts: runScripts,
+                        loader: this.options.resourceLoader,
+                        tracker
+                    })
+                    : undefined,
                 beforeParse(window) {
                     // Mock modern browser APIs that may be missing in JSDOM
                     if (!window.ReadableStream) {
@@ -66,30 +106,86 @@ export class WCAGScanner {
                             text: () => Promise.resolve("")
                         }) as any;
                     }
+
+                    // Page scripts must not reach the network
+                    blockNetworkRequests(window as unknown as Window);
                 }
             });
             
             this.document = this.dom.window.document;
             this.window = this.dom.window as unknown as Window;
-            return true;
+            const dom = this.dom;
+            registerLocator(this.document, element => elementLocation(dom, element));
+            loaded = true;
         } catch (error) {
             console.error('Error loading HTML:', error);
             if (this.dom) {
                 this.document = this.dom.window.document;
                 this.window = this.dom.window as unknown as Window;
-                return true;
+                loaded = true;
+            }
+        }
+
+        // Client-rendered pages need time to render; computed-style rules need linked stylesheets.
+        // Errors here, such as an invalid settle selector, are the caller's to handle
+        if (loaded && this.window) {
+            try {
+                if (runScripts) {
+                    await waitForSettle(this.window, tracker, this.options.settle);
+                } else if (loadStylesheets) {
+                    await waitForLoad(this.window, this.options.settle?.timeout);
+                }
+            } catch (error) {
+                this.close();
+                throw error;
             }
-            return false;
         }
+
+        if (scriptErrors.length > 0 && !this.options.ignoreScriptErrors) {
+            // Stop the page's timers, since the caller gets no document to close
+            this.close();
+            throw new ScriptError(scriptErrors);
+        }
+        return loaded;
     }
 
+    /**
+     * Close the loaded document, stopping any timers its scripts started
+     */
+    close(): void {
+        this.dom?.window.close();
+    }
+
+    /**
+     * Register a rule created with defineRule; it runs alongside the built-in rules
+     * @param rule Defined rule
+     */
+    registerRule(rule: DefinedRule): void;
     /**
      * Register a rule module
      * @param name Rule name
      * @param rule Rule implementation
      */
-    registerRule(name: string, rule: Rule): void {
-        this.rules.set(name, rule);
+    registerRule(name: string, rule: Rule): void;
+    registerRule(nameOrRule: string | DefinedRule, rule?: Rule): void {
+        if (typeof nameOrRule === 'string') {
+            if (rule) this.rules.set(nameOrRule, rule);
+        } else if (isDefinedRule(nameOrRule)) {
+            this.definedRules.set(nameOrRule.meta.id, nameOrRule);
+        } else {
+            throw new Error('registerRule expects a rule created with defineRule, or a name and a rule');
+        }
+    }
+
+    /**
+     * Load the rule packs listed in the rulePacks option
+     * @throws Error when a pack cannot be loaded
+     */
+    loadRulePacks(): void {
+        for (const rule of loadRulePacks(this.options.rulePacks || [])) {
+            this.registerRule(rule);
+        }
+        this.rulePacksLoaded = true;
     }
 
     /**
@@ -106,7 +202,6 @@ export class WCAGScanner {
             const ruleFiles = fs.readdirSync(rulesDir)
                 .filter(file => {
                     if (file.endsWith('.d.ts') || file.endsWith('.d.js')) return false;
-                    if (path.basename(file, path.extname(file)) === 'presets') return false;
                     return file.endsWith('.js') || file.endsWith('.ts');
                 });
             
@@ -136,28 +231,40 @@ export class WCAGScanner {
 
     /**
      * Run the accessibility scan
-     * @returns Promise<ScanResults> Scan results
+     * @returns Promise<ScanResults> Scan results; rules that threw are listed in errors
+     * @throws RuleFailureError when failOnRuleError is set and a rule threw
      */
     async scan(): Promise<ScanResults> {
         if (!this.document || !this.window) {
             throw new Error('HTML not loaded. Call loadHTML() first.');
         }
 
+        const errors: RuleError[] = [];
         this.results = {
             passes: [],
             violations: [],
-            warnings: []
+            warnings: [],
+            incomplete: [],
+            errors
         };
 
+        // Page scripts may have changed the document since the last scan
+        clearIdCache(this.document);
+
         // Load rules if not already loaded
         if (this.rules.size === 0) {
             await this.loadRules();
         }
+        if (!this.rulePacksLoaded) {
+            this.loadRulePacks();
+        }
 
-        // Run each enabled rule
-        const enabledRules = resolveRuleNames(this.options, FAST_RULES);
-        for (const ruleName of enabledRules) {
-            const rule = this.rules.get(ruleName);
+        // Run each enabled rule, then the defined rules the options select
+        const enabledRules: Array<[string, Rule | undefined]> = resolveRuleNames(this.options, FAST_RULES)
+            .map(ruleName => [ruleName, this.rules.get(ruleName)] as [string, Rule | undefined])
+            .concat(selectDefinedRules([...this.definedRules.values()], this.options)
+                .map(rule => [rule.meta.id, rule] as [string, Rule]));
+        for (const [ruleName, rule] of enabledRules) {
             if (rule) {
                 try {
                     const ruleResults = await rule.check(this.document, this.window, this.options);
@@ -166,12 +273,36 @@ export class WCAGScanner {
                     this.results.passes.push(...(ruleResults.passes || []));
                     this.results.violations.push(...(ruleResults.violations || []));
                     this.results.warnings.push(...(ruleResults.warnings || []));
+                    this.results.incomplete.push(...(ruleResults.incomplete || []));
                 } catch (error) {
-                    console.error(`Error running rule ${ruleName}:`, error);
+                    errors.push(toRuleError(ruleName, error));
                 }
             }
         }
 
+        if (errors.length > 0 && this.options.failOnRuleError) {
+            throw new RuleFailureError(errors);
+        }
+
+        // Rules record locations as they go; this covers custom rules that do not
+        if (this.dom) {
+            this.results = addLocations(this.results, this.dom);
+        }
+        this.results.url = this.document.URL;
+        this.results = applyLevel(this.results, this.options);
+        this.results = applyRuleOverrides(this.results, this.options.ruleOverrides);
+        this.results = applySuppressions(this.results, this.document);
+
+        if (this.options.baseline) {
+            const baselinePath = path.resolve(this.options.baseline);
+            this.results = compareWithBaseline(
+                this.results,
+                loadBaseline(baselinePath),
+                baselinePage(this.document.URL, baselinePath),
+                baselinePath
+            );
+        }
+
         return this.results;
     }
 
@@ -181,12 +312,23 @@ export class WCAGScanner {
      */
     getResults(): ScanResults {
         return {
+            ...this.results,
             passes: [...this.results.passes],
             violations: [...this.results.violations],
-            warnings: [...this.results.warnings]
+            warnings: [...this.results.warnings],
+            incomplete: [...this.results.incomplete],
+            errors: [...(this.results.errors || [])]
         };
     }
 
+    /**
+     * Get the loaded document
+     * @returns The document loaded by loadHTML, if any
+     */
+    getDocument(): Document | undefined {
+        return this.document;
+    }
+
     /**
      * Update scanner options
      * @param newOptions New options to apply
@@ -196,5 +338,22 @@ export class WCAGScanner {
             ...this.options,
             ...newOptions
         };
+        if (newOptions.rulePacks) {
+            this.rulePacksLoaded = false;
+        }
     }
 }
+
+/**
+ * Create a virtual console that records uncaught page script errors and drops other output
+ * @param errors Array the error messages are pushed to
+ */
+function collectScriptErrors(errors: string[]): VirtualConsole {
+    const virtualConsole = new VirtualConsole();
+    virtualConsole.on('jsdomError', (error: Error & { type?: string; detail?: unknown }) => {
+        if (error.type === 'unhandled exception' || error.message.startsWith('Uncaught')) {
+            errors.push(error.detail ? String(error.detail) : error.message);
+        }
+    });
+    return virtualConsole;
+}
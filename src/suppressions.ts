@@ -0,0 +1,159 @@
+import { Incomplete, ResultItem, ScanResults, SuppressedResult, SuppressionSource, Violation, Warning } from './types';
+
+/**
+ * Comment directive that suppresses rules for the following element and its descendants
+ */
+export const DISABLE_NEXT_LINE = 'wcag-scanner-disable-next-line';
+
+/**
+ * Attribute that suppresses rules for an element and its descendants
+ */
+export const IGNORE_ATTRIBUTE = 'data-wcag-ignore';
+
+/** NodeFilter.SHOW_COMMENT, which is not a global outside the browser */
+const SHOW_COMMENT = 0x80;
+
+/** XPathResult.FIRST_ORDERED_NODE_TYPE, likewise */
+const FIRST_ORDERED_NODE_TYPE = 9;
+
+/** The directive as a whole word, followed by an optional rule list */
+const DIRECTIVE_PATTERN = new RegExp(`^${DISABLE_NEXT_LINE}(?=$|\\s)`);
+
+/**
+ * A suppression found in the scanned markup
+ */
+export interface Suppression {
+  /** Root of the suppressed subtree */
+  element: Element;
+  /** Suppressed rule ids, or null for every rule */
+  rules: string[] | null;
+  source: SuppressionSource;
+}
+
+/**
+ * Parse a list of rule ids separated by commas or whitespace
+ * @param value Raw list
+ * @returns Rule ids, or null when the list is empty (meaning every rule)
+ */
+function parseRuleList(value: string): string[] | null {
+  const rules = value.split(/[\s,]+/).filter(Boolean);
+  return rules.length > 0 ? rules : null;
+}
+
+/**
+ * Find every suppression comment and attribute in a document
+ * @param document DOM document
+ * @returns Suppressions in document order
+ */
+export function collectSuppressions(document: Document): Suppression[] {
+  const suppressions: Suppression[] = [];
+
+  const walker = document.createTreeWalker(document, SHOW_COMMENT);
+  for (let node = walker.nextNode(); node; node = walker.nextNode()) {
+    const text = (node.nodeValue || '').trim();
+    if (!DIRECTIVE_PATTERN.test(text)) continue;
+
+    const element = (node as Comment).nextElementSibling;
+    if (!element) continue;
+
+    suppressions.push({
+      element,
+      rules: parseRuleList(text.slice(DISABLE_NEXT_LINE.length)),
+      source: 'comment',
+    });
+  }
+
+  document.querySelectorAll(`[${IGNORE_ATTRIBUTE}]`).forEach(element => {
+    suppressions.push({
+      element,
+      rules: parseRuleList(element.getAttribute(IGNORE_ATTRIBUTE) || ''),
+      source: 'attribute',
+    });
+  });
+
+  return suppressions;
+}
+
+/**
+ * Find the element a result was reported for: by the selector or XPath the rule recorded,
+ * or else by its id, or as a last resort by its HTML snippet when only one element matches it
+ * @param item Result item
+ * @param document DOM document the result came from
+ * @returns The element, or null when it cannot be identified
+ */
+export function locateResultElement(item: ResultItem, document: Document): Element | null {
+  if (item.selector) {
+    try {
+      const bySelector = document.querySelector(item.selector);
+      if (bySelector) return bySelector;
+    } catch {
+      // Fall back to the XPath, id and snippet below
+    }
+  }
+
+  if (item.xpath) {
+    try {
+      const byXpath = document.evaluate(item.xpath, document, null, FIRST_ORDERED_NODE_TYPE, null).singleNodeValue;
+      if (byXpath && byXpath.nodeType === 1) return byXpath as Element;
+    } catch {
+      // Fall back to the id and snippet below
+    }
+  }
+
+  if (item.element?.id) {
+    const byId = document.getElementById(item.element.id);
+    if (byId) return byId;
+  }
+
+  if (!item.snippet) return null;
+
+  // Rules report outerHTML, truncated with "..." for long elements
+  const snippet = item.snippet.replace(/\.\.\.$/, '');
+  const tagMatch = snippet.match(/^<([\w-]+)/);
+  if (!tagMatch) return null;
+
+  // Identical elements cannot be told apart, so none of them is picked
+  const matches = Array.from(document.getElementsByTagName(tagMatch[1]))
+    .filter(candidate => candidate.outerHTML.startsWith(snippet));
+  return matches.length === 1 ? matches[0] : null;
+}
+
+/**
+ * Move violations, warnings and incomplete results covered by a suppression into the suppressed list
+ * @param results Scan results
+ * @param document DOM document the results came from
+ * @returns Scan results with suppressed items separated out
+ */
+export function applySuppressions<T extends ScanResults>(results: T, document: Document): T {
+  const suppressions = collectSuppressions(document);
+  if (suppressions.length === 0) return results;
+
+  const suppressed: SuppressedResult[] = [...(results.suppressed || [])];
+
+  const findSuppression = (item: ResultItem): Suppression | undefined => {
+    const candidates = suppressions.filter(suppression => !suppression.rules || suppression.rules.includes(item.rule));
+    if (candidates.length === 0) return undefined;
+
+    const element = locateResultElement(item, document);
+    if (!element) return undefined;
+
+    return candidates.find(suppression => suppression.element.contains(element));
+  };
+
+  const keep = <I extends Violation | Warning | Incomplete>(items: I[], type: SuppressedResult['type']): I[] =>
+    items.filter(item => {
+      const suppression = findSuppression(item);
+      if (!suppression) return true;
+
+      suppressed.push({ ...item, type, suppressedBy: suppression.source });
+      return false;
+    });
+
+  return {
+    ...results,
+    violations: keep(results.violations, 'violation'),
+    warnings: keep(results.warnings, 'warning'),
+    incomplete: keep(results.incomplete, 'incomplete'),
+    suppressed,
+  };
+}
@@ -1,23 +1,104 @@
 export type RulePreset = 'fast' | 'full';
 
+/**
+ * WCAG conformance level
+ */
+export type WcagLevel = 'A' | 'AA' | 'AAA';
+
+/**
+ * WCAG version
+ */
+export type WcagVersion = '2.0' | '2.1' | '2.2';
+
+/**
+ * Standard to check conformance with: a WCAG version, or a standard that incorporates one
+ * (EN 301 549 incorporates WCAG 2.1, Section 508 incorporates WCAG 2.0)
+ */
+export type Standard = 'wcag20' | 'wcag21' | 'wcag22' | 'en301549' | 'section508';
+
+/**
+ * Override for a single rule id: 'off' disables it, an object can
+ * re-enable it, move it between violations and warnings, or change its impact
+ */
+export type RuleOverride = 'off' | {
+    /** Set to false to drop results for this rule id */
+    enabled?: boolean;
+    /** Report results as violations or warnings */
+    type?: 'violation' | 'warning';
+    /** Impact level to report instead of the rule's own */
+    impact?: ImpactLevel;
+};
+
+/**
+ * Rule overrides keyed by rule id (e.g. "heading-skip")
+ */
+export type RuleOverrides = Record<string, RuleOverride>;
+
 /**
  * Scanner configuration options
  */
 export interface ScannerOptions {
-    /** WCAG level to check against (A, AA or AAA) */
-    level?: 'A' | 'AA' | 'AAA';
+    /** WCAG level to check against (A, AA or AAA); rule ids above this level are not reported */
+    level?: WcagLevel;
+    /** Standard whose success criteria select the rule ids to report (default: wcag21) */
+    standard?: Standard;
+    /** Report best-practice rule ids that no success criterion strictly requires (default: true) */
+    bestPractices?: boolean;
     /** Built-in rule preset */
     preset?: RulePreset;
-    /** Specific rules to check */
+    /** Specific rules to check: rule modules, or the ids, modules or tags of defined rules */
     rules?: string[];
+    /** npm package names or paths of rule packs to load alongside the built-in rules */
+    rulePacks?: string[];
+    /** Per-rule-id overrides applied to the results of the enabled rules */
+    ruleOverrides?: RuleOverrides;
     /** Enable AI-powered suggestions */
     ai?: boolean;
     /** Base URL for relative paths */
     baseUrl?: string;
     /** Enable verbose output */
     verbose?: boolean;
-    /** Ignore JavaScript errors in scanned pages */
+    /** Execute the page's scripts before scanning, for client-rendered pages (default: false) */
+    runScripts?: boolean;
+    /** When to start scanning a page whose scripts are executed */
+    settle?: SettleOptions;
+    /** Scan anyway when the page's scripts throw; otherwise script errors abort the scan */
     ignoreScriptErrors?: boolean;
+    /** Fail the scan when a rule throws; otherwise the error is recorded in the results' errors */
+    failOnRuleError?: boolean;
+    /** Path to a config file, or false to skip config file discovery */
+    config?: string | false;
+    /** Path to a baseline file; violations are marked new or existing against it */
+    baseline?: string;
+    /** Leave violations already recorded in the baseline out of reports */
+    onlyNewViolations?: boolean;
+    /** Load `<link rel="stylesheet">` resources before scanning; `file:` URLs are read from disk */
+    loadStylesheets?: boolean;
+    /** Supplies stylesheet and script text by URL; implies loadStylesheets. Return null to fall back to disk */
+    resourceLoader?: SubresourceLoader;
+    /** How the JUnit reporter reports warnings: as skipped test cases or only as system-out (default) */
+    junitWarnings?: 'skipped' | 'system-out';
+    /** Size budget in characters for the markdown reporter (default: 65000) */
+    markdownMaxLength?: number;
+}
+
+/**
+ * Returns the text of a stylesheet or script, or null/undefined when it is not available
+ */
+export type SubresourceLoader = (url: string) => string | null | undefined | Promise<string | null | undefined>;
+
+/**
+ * Settle conditions for pages whose scripts are executed.
+ * Scanning starts when `selector` matches or, without a selector, once the network is idle;
+ * it starts anyway when `timeout` is reached.
+ */
+export interface SettleOptions {
+    /** CSS selector that appears once the page has rendered */
+    selector?: string;
+    /** Milliseconds without subresource loads that count as idle (default: 500) */
+    networkIdle?: number;
+    /** Maximum milliseconds to wait (default: 10000); also bounds waiting for linked stylesheets */
+    timeout?: number;
 }
 
 /**
@@ -54,6 +135,14 @@ export interface ElementInfo {
     tabindex?: string | null;
     /** Target */
     target?: string;
+    /** Accessible name computed for the element */
+    accessibleName?: string | null;
+    /** Computed font size in CSS pixels */
+    fontSize?: number;
+    /** Computed numeric font weight */
+    fontWeight?: number;
+    /** Whether the text counts as large (18pt, or 14pt bold) for contrast */
+    largeText?: boolean;
 }
 
 /**
@@ -62,7 +151,7 @@ export interface ElementInfo {
 export type ImpactLevel = 'critical' | 'serious' | 'moderate' | 'minor';
 
 /**
- * Base result item (common for passes, violations, and warnings)
+ * Base result item (common for passes, violations, warnings and incomplete results)
  */
 export interface ResultItem {
     /** Rule identifier */
@@ -73,6 +162,28 @@ export interface ResultItem {
     description: string;
     /** HTML snippet */
     snippet?: string;
+    /** Position of the element's start tag in the scanned source */
+    location?: SourceLocation;
+    /** CSS selector matching only the inspected element */
+    selector?: string;
+    /** XPath of the inspected element */
+    xpath?: string;
+    /** Conformance level of the rule id, set by the scanner */
+    level?: WcagLevel;
+    /** Whether the rule id is a best practice rather than a success criterion requirement */
+    bestPractice?: boolean;
+    /** Clause numbers in the chosen standard, set by the scanner when a standard is chosen */
+    clauses?: string[];
+}
+
+/**
+ * Position in the scanned HTML source; lines and columns start at 1
+ */
+export interface SourceLocation {
+    line: number;
+    column: number;
+    endLine?: number;
+    endColumn?: number;
 }
 
 /**
@@ -101,6 +212,8 @@ export interface Violation extends ResultItem {
     helpUrl?: string;
     /** Fix suggestion (will be populated by AI module) */
     fix?: FixSuggestion;
+    /** Whether the violation is new or already recorded in the baseline */
+    baselineStatus?: BaselineStatus;
 }
 
 /**
@@ -115,11 +228,75 @@ export interface Warning extends ResultItem {
     help?: string;
 }
 
+/**
+ * Result of a check that could not be decided automatically and needs human review
+ */
+export interface Incomplete extends ResultItem {
+    /** Impact if the review finds a problem */
+    impact: ImpactLevel;
+    /** WCAG success criteria */
+    wcag?: string[];
+    /** Why the check could not be decided automatically */
+    reason: string;
+    /** How to review the result by hand */
+    review: string;
+}
+
 /**
  * Accessibility pass result
  */
 export interface Pass extends ResultItem {}
 
+/**
+ * How a result was suppressed: a disable comment or a data-wcag-ignore attribute
+ */
+export type SuppressionSource = 'comment' | 'attribute';
+
+/**
+ * A violation, warning or incomplete result suppressed by markup in the scanned page
+ */
+export interface SuppressedResult extends Violation {
+    /** List the result would have been reported in */
+    type: 'violation' | 'warning' | 'incomplete';
+    /** What suppressed the result */
+    suppressedBy: SuppressionSource;
+}
+
+/**
+ * Baseline status of a violation
+ */
+export type BaselineStatus = 'new' | 'existing';
+
+/**
+ * A violation recorded in a baseline file
+ */
+export interface BaselineEntry {
+    /** Page the violation was found on (file path relative to the baseline, or URL) */
+    page: string;
+    /** Rule identifier */
+    rule: string;
+    /** Element selector used in the fingerprint */
+    selector: string;
+    /** Normalized HTML snippet used in the fingerprint */
+    snippet: string;
+    /** Hash of rule, selector and snippet */
+    fingerprint: string;
+}
+
+/**
+ * Comparison of a scan against a baseline file
+ */
+export interface BaselineSummary {
+    /** Baseline file the scan was compared against */
+    file: string;
+    /** Violations not in the baseline */
+    new: number;
+    /** Violations already in the baseline */
+    existing: number;
+    /** Baseline entries for this page that no longer occur */
+    fixed: BaselineEntry[];
+}
+
 /**
  * Scanner Result
  */
@@ -130,6 +307,92 @@ export interface ScanResults {
     violations: Violation[];
     /** Warnings */
     warnings: Warning[];
+    /** Checks that need human review */
+    incomplete: Incomplete[];
+    /** Results suppressed by disable comments or data-wcag-ignore attributes */
+    suppressed?: SuppressedResult[];
+    /** Baseline comparison, present when scanned with a baseline */
+    baseline?: BaselineSummary;
+    /** Rules that threw, set by the scanner; when not empty the scan is incomplete */
+    errors?: RuleError[];
+    /** URL of the scanned document */
+    url?: string;
+}
+
+/**
+ * A rule that threw during a scan
+ */
+export interface RuleError {
+    /** Name of the rule module or defined rule id */
+    rule: string;
+    /** Error message */
+    message: string;
+    /** Stack trace, when the rule threw an Error */
+    stack?: string;
+}
+
+/**
+ * Totals for one rule id across several pages
+ */
+export interface SiteRuleSummary {
+    /** Rule identifier */
+    rule: string;
+    /** Violations reported for the rule */
+    violations: number;
+    /** Warnings reported for the rule */
+    warnings: number;
+    /** Pages with at least one violation or warning for the rule */
+    pages: number;
+}
+
+/**
+ * Site-wide rollup of several scanned pages
+ */
+export interface SiteSummary {
+    /** Pages scanned successfully */
+    pages: number;
+    /** Pages that could not be fetched or scanned */
+    failedPages: number;
+    /** Pages with at least one violation */
+    pagesWithViolations: number;
+    /** Total violations */
+    violations: number;
+    /** Total warnings */
+    warnings: number;
+    /** Total results that need review */
+    incomplete: number;
+    /** Total passes */
+    passes: number;
+    /** Per-rule totals, most violations first */
+    rules: SiteRuleSummary[];
+}
+
+/**
+ * Scan results for a set of pages, e.g. from crawling a site
+ */
+export interface SiteResults {
+    /** Scan results keyed by page URL or file */
+    pages: Record<string, ScanResults>;
+    /** Error messages keyed by page URL or file, for pages that failed */
+    errors: Record<string, string>;
+    /** Reasons keyed by URL, for crawled links that turned out not to be HTML pages */
+    skipped?: Record<string, string>;
+    /** Site-wide rollup */
+    summary: SiteSummary;
+}
+
+/**
+ * What a rule id checks: the success criteria it maps to, each with its conformance level
+ */
+export interface RuleMetadata {
+    /** Rule identifier */
+    rule: string;
+    /** Success criteria the rule id maps to */
+    criteria: Array<{ id: string; level: WcagLevel; version: WcagVersion }>;
+    /** Lowest level the rule id applies at; undefined for best practices without criteria */
+    level?: WcagLevel;
+    /** Whether the rule id is a best practice rather than a success criterion requirement */
+    bestPractice: boolean;
 }
 
 /**
@@ -137,5 +400,117 @@ export interface ScanResults {
  */
 export interface Rule {
     /** Run the rule check */
-    check(document: Document, window: Window, options: ScannerOptions): Promise<ScanResults>;
+    check(document: Document, window: Window, options: ScannerOptions): Promise<RuleResults>;
 }
+
+/**
+ * Results returned by a rule's check. Rules that never need human review may leave out
+ * `incomplete`; the scanner fills in the rest of ScanResults.
+ */
+export interface RuleResults {
+    /** Passes */
+    passes: Pass[];
+    /** Violations */
+    violations: Violation[];
+    /** Warnings */
+    warnings: Warning[];
+    /** Checks that need human review */
+    incomplete?: Incomplete[];
+}
+
+/**
+ * Details of a violation reported through a rule context
+ */
+export interface ViolationDetails {
+    /** Description of the issue */
+    description: string;
+    /** Impact level; defaults to the rule's impact */
+    impact?: ImpactLevel;
+    /** WCAG success criteria; defaults to the rule's criteria */
+    wcag?: string[];
+    /** Help text; defaults to the rule's help */
+    help?: string;
+    /** Help URL; defaults to the rule's help URL */
+    helpUrl?: string;
+    /** Fix suggestion */
+    fix?: FixSuggestion;
+}
+
+/**
+ * Details of a warning reported through a rule context
+ */
+export type WarningDetails = Pick<ViolationDetails, 'description' | 'impact' | 'wcag' | 'help'>;
+
+/**
+ * Details of an incomplete result reported through a rule context
+ */
+export interface IncompleteDetails extends Pick<ViolationDetails, 'description' | 'impact' | 'wcag'> {
+    /** Why the check could not be decided automatically */
+    reason: string;
+    /** How to review the result by hand */
+    review: string;
+}
+
+/**
+ * Helper passed to defined rules: the scanned page and builders that fill in the rule id,
+ * criteria, element information, snippet and location of each result
+ */
+export interface RuleContext {
+    /** Scanned document */
+    document: Document;
+    /** Window of the scanned document */
+    window: Window;
+    /** Scanner options */
+    options: ScannerOptions;
+    /** Record a passed check */
+    pass(element: Element | null, description: string): void;
+    /** Record a violation */
+    violation(element: Element | null, details: ViolationDetails): void;
+    /** Record a warning */
+    warning(element: Element | null, details: WarningDetails): void;
+    /** Record a result that needs human review */
+    incomplete(element: Element | null, details: IncompleteDetails): void;
+    /** An element's HTML, truncated for reports */
+    snippet(element: Element): string;
+    /** Whether an element is exposed to assistive technology (not hidden, aria-hidden or display: none) */
+    isVisible(element: Element): boolean;
+}
+
+/**
+ * A rule written with defineRule
+ */
+export interface RuleDefinition {
+    /** Rule id reported on every result, e.g. "ds-button-label" */
+    id: string;
+    /** Name used to group rules, e.g. the rule pack; defaults to the id */
+    module?: string;
+    /** What the rule checks */
+    description?: string;
+    /** WCAG success criteria the rule maps to */
+    wcag?: string[];
+    /** Conformance level, for rules without success criteria */
+    level?: WcagLevel;
+    /** Free-form tags; "best-practice" marks the rule as a best practice */
+    tags?: string[];
+    /** Default impact of violations, warnings and incomplete results (default: moderate) */
+    impact?: ImpactLevel;
+    /** Default help text */
+    help?: string;
+    /** Default help URL */
+    helpUrl?: string;
+    /** Run the check, reporting results through the context */
+    check(context: RuleContext): void | Promise<void>;
+}
+
+/**
+ * A rule created by defineRule: a regular rule that also carries its definition
+ */
+export interface DefinedRule extends Rule {
+    /** Id, module, criteria and tags of the rule */
+    meta: Omit<RuleDefinition, 'check'> & { module: string; wcag: string[]; tags: string[] };
+}
+
+/**
+ * What a rule pack package exports: defined rules, either directly or as `rules`
+ */
+export type RulePack = DefinedRule[] | { rules: DefinedRule[] };
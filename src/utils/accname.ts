@@ -0,0 +1,344 @@
+import { getRole } from './roles';
+
+/**
+ * Where an accessible name came from
+ */
+export type NameSource =
+  | 'aria-labelledby'
+  | 'aria-label'
+  | 'native'
+  | 'contents'
+  | 'title'
+  | 'placeholder'
+  | 'none';
+
+/**
+ * A computed accessible name
+ */
+export interface AccessibleName {
+  /** The name, with whitespace collapsed; empty when the element has none */
+  name: string;
+  /** Which step of the algorithm produced the name */
+  source: NameSource;
+}
+
+/**
+ * Roles whose name is computed from their contents (WAI-ARIA 1.2 "name from: contents")
+ */
+const NAME_FROM_CONTENT_ROLES = new Set([
+  'button', 'cell', 'checkbox', 'columnheader', 'gridcell', 'heading', 'link', 'menuitem',
+  'menuitemcheckbox', 'menuitemradio', 'option', 'radio', 'row', 'rowheader', 'switch', 'tab',
+  'tooltip', 'treeitem',
+]);
+
+const RANGE_ROLES = new Set(['slider', 'spinbutton', 'progressbar', 'scrollbar', 'meter']);
+
+/**
+ * Elements rendered as blocks, whose content is separated from its neighbours by a space
+ */
+const BLOCK_ELEMENTS = new Set([
+  'address', 'article', 'aside', 'blockquote', 'br', 'dd', 'div', 'dl', 'dt', 'fieldset',
+  'figcaption', 'figure', 'footer', 'form', 'h1', 'h2', 'h3', 'h4', 'h5', 'h6', 'header', 'hr',
+  'li', 'main', 'nav', 'ol', 'p', 'pre', 'section', 'table', 'td', 'th', 'tr', 'ul',
+]);
+
+interface Traversal {
+  /** Element whose name is being computed */
+  root: Element;
+  /** Inside an aria-labelledby or aria-describedby traversal */
+  referenced: boolean;
+  /** Hidden nodes are included because the referenced node itself is hidden */
+  includeHidden: boolean;
+  /** Computing the name of another element from this node's subtree */
+  recursing: boolean;
+}
+
+/**
+ * Compute an element's accessible name following W3C Accessible Name and Description
+ * Computation 1.2 and the HTML-AAM host language rules
+ * @param element Element to name
+ * @returns The name and where it came from
+ */
+export function computeAccessibleName(element: Element): AccessibleName {
+  const result: AccessibleName = { name: '', source: 'none' };
+  const name = computeName(element, {
+    root: element,
+    referenced: false,
+    includeHidden: false,
+    recursing: false,
+  }, result);
+
+  result.name = normalize(name);
+  if (!result.name) result.source = 'none';
+  return result;
+}
+
+/**
+ * Compute an element's accessible name
+ * @param element Element to name
+ * @returns The name, or an empty string when the element has none
+ */
+export function accessibleName(element: Element): string {
+  return computeAccessibleName(element).name;
+}
+
+/**
+ * Compute an element's accessible description from aria-describedby, aria-description,
+ * or a title attribute that was not used for the name
+ * @param element Element to describe
+ * @returns The description, or an empty string
+ */
+export function accessibleDescription(element: Element): string {
+  const references = resolveIdReferences(element, 'aria-describedby');
+  if (references.length > 0) {
+    const description = normalize(references
+      .map(reference => computeName(reference, {
+        root: element,
+        referenced: true,
+        includeHidden: isHidden(reference),
+        recursing: true,
+      }, { name: '', source: 'none' }))
+      .join(' '));
+    if (description) return description;
+  }
+
+  const ariaDescription = normalize(element.getAttribute('aria-description') || '');
+  if (ariaDescription) return ariaDescription;
+
+  const title = normalize(element.getAttribute('title') || '');
+  return title && computeAccessibleName(element).source !== 'title' ? title : '';
+}
+
+/**
+ * Elements referenced by an id-list attribute such as aria-labelledby, skipping missing ids
+ * @param element Element with the attribute
+ * @param attribute Attribute name
+ * @returns Referenced elements in attribute order
+ */
+export function resolveIdReferences(element: Element, attribute: string): Element[] {
+  const document = element.ownerDocument;
+  return (element.getAttribute(attribute) || '')
+    .split(/\s+/)
+    .filter(Boolean)
+    .map(id => document.getElementById(id))
+    .filter((reference): reference is HTMLElement => reference !== null);
+}
+
+/**
+ * Whether an element is excluded from the accessibility tree: it or an ancestor is
+ * hidden, aria-hidden or display: none, or it has visibility: hidden
+ * @param element Element to check
+ */
+export function isHidden(element: Element): boolean {
+  if (isHiddenNode(element)) return true;
+
+  // visibility is inherited but can be overridden, so only the element's own value counts
+  for (let node = element.parentElement; node; node = node.parentElement) {
+    if (isHiddenNode(node, false)) return true;
+  }
+  return false;
+}
+
+function computeName(node: Node, traversal: Traversal, result: AccessibleName): string {
+  if (node.nodeType === 3) {
+    // 2G: text node
+    return node.textContent || '';
+  }
+  if (node.nodeType !== 1) return '';
+
+  const element = node as Element;
+  const isRoot = element === traversal.root && !traversal.recursing;
+
+  // 2A: hidden nodes contribute nothing unless a hidden node was referenced directly
+  if (!traversal.includeHidden && (isRoot ? isHidden(element) : isHiddenNode(element))) {
+    return '';
+  }
+
+  // 2B: aria-labelledby, not followed again inside a referenced node
+  if (!traversal.referenced) {
+    const references = resolveIdReferences(element, 'aria-labelledby');
+    if (references.length > 0) {
+      const name = normalize(references
+        .map(reference => computeName(reference, {
+          root: traversal.root,
+          referenced: true,
+          includeHidden: isHidden(reference),
+          recursing: true,
+        }, result))
+        .join(' '));
+      if (name) return setSource(result, isRoot, 'aria-labelledby', name);
+    }
+  }
+
+  const role = getRole(element);
+
+  // 2C: a control embedded in another element's label contributes its value
+  if (traversal.recursing && element !== traversal.root) {
+    const value = embeddedControlValue(element, role);
+    if (value !== null) return value;
+  }
+
+  // 2D: aria-label
+  const ariaLabel = normalize(element.getAttribute('aria-label') || '');
+  if (ariaLabel) return setSource(result, isRoot, 'aria-label', ariaLabel);
+
+  // 2E: host language label, unless the element is presentational
+  if (role !== 'presentation' && role !== 'none') {
+    const native = nativeName(element, traversal);
+    if (native) return setSource(result, isRoot, 'native', native);
+  }
+
+  // 2F/2H: name from content, for roles that allow it and inside other names
+  if (traversal.recursing || (role !== null && NAME_FROM_CONTENT_ROLES.has(role))) {
+    const content = nameFromContent(element, traversal, result);
+    if (normalize(content)) return setSource(result, isRoot, 'contents', content);
+  }
+
+  // 2I: tooltip attribute, then placeholder for text fields (HTML-AAM)
+  const title = normalize(element.getAttribute('title') || '');
+  if (title) return setSource(result, isRoot, 'title', title);
+
+  if (isRoot && (element.localName === 'input' || element.localName === 'textarea')) {
+    const placeholder = normalize(element.getAttribute('placeholder') || '');
+    if (placeholder) return setSource(result, isRoot, 'placeholder', placeholder);
+  }
+
+  return '';
+}
+
+function setSource(result: AccessibleName, isRoot: boolean, source: NameSource, name: string): string {
+  if (isRoot) result.source = source;
+  return name;
+}
+
+/**
+ * The value of a form control used inside another element's name, or null for other elements
+ */
+function embeddedControlValue(element: Element, role: string | null): string | null {
+  const tag = element.localName;
+
+  if (role === 'textbox' || role === 'searchbox') {
+    return tag === 'input' || tag === 'textarea'
+      ? (element as HTMLInputElement).value
+      : element.textContent || '';
+  }
+
+  if (role === 'combobox' || role === 'listbox') {
+    if (tag === 'select') {
+      return Array.from((element as HTMLSelectElement).options)
+        .filter(option => option.selected)
+        .map(option => option.textContent || '')
+        .join(' ');
+    }
+    if (tag === 'input') {
+      return (element as HTMLInputElement).value;
+    }
+    const selected = element.querySelectorAll('[role="option"][aria-selected="true"]');
+    return Array.from(selected).map(option => option.textContent || '').join(' ');
+  }
+
+  if (role !== null && RANGE_ROLES.has(role)) {
+    const valueText = element.getAttribute('aria-valuetext');
+    if (valueText) return valueText;
+    const valueNow = element.getAttribute('aria-valuenow');
+    if (valueNow) return valueNow;
+    return tag === 'input' ? (element as HTMLInputElement).value : '';
+  }
+
+  return null;
+}
+
+/**
+ * Host language labels: label elements, alt text, legends, captions, SVG titles, button values
+ */
+function nativeName(element: Element, traversal: Traversal): string {
+  const tag = element.localName;
+  const contentOf = (source: Element) => nameFromContent(source, { ...traversal, recursing: true }, { name: '', source: 'none' });
+
+  if (tag === 'input') {
+    const type = (element.getAttribute('type') || '').toLowerCase();
+    if (type === 'button' || type === 'submit' || type === 'reset') {
+      const value = element.getAttribute('value');
+      if (value !== null) return value;
+      return type === 'submit' ? 'Submit' : type === 'reset' ? 'Reset' : '';
+    }
+    if (type === 'image') {
+      return element.getAttribute('alt') || element.getAttribute('value') || 'Submit';
+    }
+  }
+
+  if (['input', 'select', 'textarea', 'meter', 'output', 'progress'].includes(tag)) {
+    // Only the element being named looks up its labels; a control met inside a label would
+    // otherwise find that same label again and recurse forever
+    if (element !== traversal.root || traversal.recursing) return '';
+    const labels = (element as HTMLInputElement).labels;
+    return labels ? Array.from(labels).map(contentOf).join(' ') : '';
+  }
+
+  if (tag === 'img' || tag === 'area') {
+    return element.getAttribute('alt') || '';
+  }
+
+  const captionFor: Record<string, string> = {
+    fieldset: 'legend',
+    figure: 'figcaption',
+    table: 'caption',
+  };
+  if (captionFor[tag]) {
+    const caption = Array.from(element.children).find(child => child.localName === captionFor[tag]);
+    return caption ? contentOf(caption) : '';
+  }
+
+  if (tag === 'svg') {
+    const title = Array.from(element.children).find(child => child.localName === 'title');
+    return title ? title.textContent || '' : '';
+  }
+
+  if (tag === 'optgroup') {
+    return element.getAttribute('label') || '';
+  }
+
+  return '';
+}
+
+function nameFromContent(element: Element, traversal: Traversal, result: AccessibleName): string {
+  const childTraversal = { ...traversal, recursing: true };
+  let name = '';
+
+  element.childNodes.forEach(child => {
+    // The element being named never labels itself, e.g. an input inside its own label
+    if (child === traversal.root) return;
+
+    const text = computeName(child, childTraversal, result);
+    const isBlock = child.nodeType === 1 && BLOCK_ELEMENTS.has((child as Element).localName);
+    name += isBlock ? ` ${text} ` : text;
+  });
+
+  return name;
+}
+
+/**
+ * Whether an element itself is hidden, ignoring its ancestors
+ * @param element Element to check
+ * @param checkVisibility Also check its computed visibility
+ */
+function isHiddenNode(element: Element, checkVisibility = true): boolean {
+  if (element.hasAttribute('hidden') || element.getAttribute('aria-hidden') === 'true') {
+    return true;
+  }
+
+  const view = element.ownerDocument.defaultView;
+  if (!view) return false;
+
+  try {
+    const style = view.getComputedStyle(element);
+    if (style.display === 'none') return true;
+    return checkVisibility && (style.visibility === 'hidden' || style.visibility === 'collapse');
+  } catch {
+    return false;
+  }
+}
+
+function normalize(value: string): string {
+  return value.replace(/\s+/g, ' ').trim();
+}
@@ -0,0 +1,389 @@
+/**
+ * Value types of ARIA states and properties (WAI-ARIA 1.2, section 6.2)
+ */
+export type AriaValueType =
+  | 'true/false'
+  | 'true/false/undefined'
+  | 'tristate'
+  | 'idref'
+  | 'idrefs'
+  | 'integer'
+  | 'number'
+  | 'string'
+  | 'token'
+  | 'tokens';
+
+/**
+ * An ARIA state or property
+ */
+export interface AriaAttribute {
+  /** Value type */
+  type: AriaValueType;
+  /** Allowed values for token and token list attributes */
+  values?: string[];
+  /** Supported on every role */
+  global?: boolean;
+  /** Deprecated everywhere */
+  deprecated?: boolean;
+  /** Deprecated on roles that do not list it as supported (it was global in ARIA 1.1) */
+  deprecatedAsGlobal?: boolean;
+}
+
+/**
+ * An ARIA role
+ */
+export interface AriaRole {
+  /** Abstract roles exist to build the taxonomy and must not be used in content */
+  abstract?: boolean;
+  /** Deprecated in ARIA 1.2 */
+  deprecated?: boolean;
+  /** Roles this one inherits supported attributes from */
+  superclass: string[];
+  /** Role-specific supported attributes, in addition to inherited and global ones */
+  supported?: string[];
+  /** Attributes authors must provide */
+  required?: string[];
+  /** Attributes that must not be used with the role */
+  prohibited?: string[];
+  /** Roles of which the element must own at least one; "group > option" also accepts options inside a group */
+  requiredOwned?: string[];
+  /** Roles one of which the element's parent must have */
+  requiredContext?: string[];
+}
+
+const BOOLEAN: AriaAttribute = { type: 'true/false' };
+const INTEGER: AriaAttribute = { type: 'integer' };
+const NUMBER: AriaAttribute = { type: 'number' };
+const STRING: AriaAttribute = { type: 'string' };
+const IDREF: AriaAttribute = { type: 'idref' };
+const IDREFS: AriaAttribute = { type: 'idrefs' };
+
+/**
+ * WAI-ARIA 1.2 states and properties, plus aria-description from ARIA 1.3 which browsers support
+ */
+export const ARIA_ATTRIBUTES: Record<string, AriaAttribute> = {
+  'aria-activedescendant': IDREF,
+  'aria-atomic': { ...BOOLEAN, global: true },
+  'aria-autocomplete': { type: 'token', values: ['inline', 'list', 'both', 'none'] },
+  'aria-busy': { ...BOOLEAN, global: true },
+  'aria-checked': { type: 'tristate' },
+  'aria-colcount': INTEGER,
+  'aria-colindex': INTEGER,
+  'aria-colspan': INTEGER,
+  'aria-controls': { ...IDREFS, global: true },
+  'aria-current': { type: 'token', values: ['page', 'step', 'location', 'date', 'time', 'true', 'false'], global: true },
+  'aria-describedby': { ...IDREFS, global: true },
+  'aria-description': { ...STRING, global: true },
+  'aria-details': { ...IDREF, global: true },
+  'aria-disabled': { ...BOOLEAN, global: true, deprecatedAsGlobal: true },
+  'aria-dropeffect': {
+    type: 'tokens',
+    values: ['copy', 'execute', 'link', 'move', 'none', 'popup'],
+    global: true,
+    deprecated: true,
+  },
+  'aria-errormessage': { ...IDREF, global: true, deprecatedAsGlobal: true },
+  'aria-expanded': { type: 'true/false/undefined' },
+  'aria-flowto': { ...IDREFS, global: true },
+  'aria-grabbed': { type: 'true/false/undefined', global: true, deprecated: true },
+  'aria-haspopup': {
+    type: 'token',
+    values: ['false', 'true', 'menu', 'listbox', 'tree', 'grid', 'dialog'],
+    global: true,
+    deprecatedAsGlobal: true,
+  },
+  'aria-hidden': { type: 'true/false/undefined', global: true },
+  'aria-invalid': {
+    type: 'token',
+    values: ['grammar', 'false', 'spelling', 'true'],
+    global: true,
+    deprecatedAsGlobal: true,
+  },
+  'aria-keyshortcuts': { ...STRING, global: true },
+  'aria-label': { ...STRING, global: true },
+  'aria-labelledby': { ...IDREFS, global: true },
+  'aria-level': INTEGER,
+  'aria-live': { type: 'token', values: ['assertive', 'off', 'polite'], global: true },
+  'aria-modal': BOOLEAN,
+  'aria-multiline': BOOLEAN,
+  'aria-multiselectable': BOOLEAN,
+  'aria-orientation': { type: 'token', values: ['horizontal', 'undefined', 'vertical'] },
+  'aria-owns': { ...IDREFS, global: true },
+  'aria-placeholder': STRING,
+  'aria-posinset': INTEGER,
+  'aria-pressed': { type: 'tristate' },
+  'aria-readonly': BOOLEAN,
+  'aria-relevant': { type: 'tokens', values: ['additions', 'all', 'removals', 'text'], global: true },
+  'aria-required': BOOLEAN,
+  'aria-roledescription': { ...STRING, global: true },
+  'aria-rowcount': INTEGER,
+  'aria-rowindex': INTEGER,
+  'aria-rowspan': INTEGER,
+  'aria-selected': { type: 'true/false/undefined' },
+  'aria-setsize': INTEGER,
+  'aria-sort': { type: 'token', values: ['ascending', 'descending', 'none', 'other'] },
+  'aria-valuemax': NUMBER,
+  'aria-valuemin': NUMBER,
+  'aria-valuenow': NUMBER,
+  'aria-valuetext': STRING,
+};
+
+const NAME_PROHIBITED = ['aria-label', 'aria-labelledby'];
+const MENU_ITEMS = ['group > menuitem', 'group > menuitemcheckbox', 'group > menuitemradio'];
+const ROWS = ['rowgroup > row'];
+const CELLS = ['cell', 'columnheader', 'gridcell', 'rowheader'];
+const VALUE_ATTRIBUTES = ['aria-valuemax', 'aria-valuemin', 'aria-valuenow', 'aria-valuetext'];
+
+/**
+ * WAI-ARIA 1.2 roles, abstract roles included
+ */
+export const ARIA_ROLES: Record<string, AriaRole> = {
+  // Abstract roles
+  command: { abstract: true, superclass: ['widget'] },
+  composite: { abstract: true, superclass: ['widget'], supported: ['aria-activedescendant'] },
+  input: { abstract: true, superclass: ['widget'], supported: ['aria-disabled'] },
+  landmark: { abstract: true, superclass: ['section'] },
+  range: { abstract: true, superclass: ['structure'], supported: VALUE_ATTRIBUTES },
+  roletype: { abstract: true, superclass: [] },
+  section: { abstract: true, superclass: ['structure'] },
+  sectionhead: { abstract: true, superclass: ['structure'] },
+  select: { abstract: true, superclass: ['composite', 'group'], supported: ['aria-orientation'] },
+  structure: { abstract: true, superclass: ['roletype'] },
+  widget: { abstract: true, superclass: ['roletype'] },
+  window: { abstract: true, superclass: ['roletype'], supported: ['aria-modal'] },
+
+  alert: { superclass: ['section'] },
+  alertdialog: { superclass: ['alert', 'dialog'] },
+  application: {
+    superclass: ['structure'],
+    supported: ['aria-activedescendant', 'aria-disabled', 'aria-errormessage', 'aria-expanded', 'aria-haspopup', 'aria-invalid'],
+  },
+  article: { superclass: ['document'] },
+  banner: { superclass: ['landmark'] },
+  blockquote: { superclass: ['section'] },
+  button: { superclass: ['command'], supported: ['aria-disabled', 'aria-expanded', 'aria-haspopup', 'aria-pressed'] },
+  caption: { superclass: ['section'], prohibited: NAME_PROHIBITED, requiredContext: ['figure', 'grid', 'table', 'treegrid'] },
+  cell: {
+    superclass: ['section'],
+    supported: ['aria-colindex', 'aria-colspan', 'aria-rowindex', 'aria-rowspan'],
+    requiredContext: ['row'],
+  },
+  checkbox: {
+    superclass: ['input'],
+    supported: ['aria-checked', 'aria-errormessage', 'aria-expanded', 'aria-invalid', 'aria-readonly', 'aria-required'],
+    required: ['aria-checked'],
+  },
+  code: { superclass: ['section'], prohibited: NAME_PROHIBITED },
+  columnheader: { superclass: ['cell', 'gridcell', 'sectionhead'], supported: ['aria-sort'], requiredContext: ['row'] },
+  combobox: {
+    superclass: ['input'],
+    supported: [
+      'aria-activedescendant', 'aria-autocomplete', 'aria-controls', 'aria-errormessage', 'aria-expanded',
+      'aria-haspopup', 'aria-invalid', 'aria-readonly', 'aria-required',
+    ],
+    required: ['aria-expanded', 'aria-controls'],
+  },
+  complementary: { superclass: ['landmark'] },
+  contentinfo: { superclass: ['landmark'] },
+  definition: { superclass: ['section'] },
+  deletion: { superclass: ['section'], prohibited: NAME_PROHIBITED },
+  dialog: { superclass: ['window'] },
+  directory: { deprecated: true, superclass: ['list'] },
+  document: { superclass: ['structure'], supported: ['aria-expanded'] },
+  emphasis: { superclass: ['section'], prohibited: NAME_PROHIBITED },
+  feed: { superclass: ['list'], requiredOwned: ['article'] },
+  figure: { superclass: ['section'] },
+  form: { superclass: ['landmark'] },
+  generic: { superclass: ['structure'], prohibited: NAME_PROHIBITED },
+  grid: { superclass: ['composite', 'table'], supported: ['aria-multiselectable', 'aria-readonly'], requiredOwned: ROWS },
+  gridcell: {
+    superclass: ['cell', 'widget'],
+    supported: [
+      'aria-disabled', 'aria-errormessage', 'aria-expanded', 'aria-haspopup', 'aria-invalid', 'aria-readonly',
+      'aria-required', 'aria-selected',
+    ],
+    requiredContext: ['row'],
+  },
+  group: { superclass: ['section'], supported: ['aria-activedescendant', 'aria-disabled'] },
+  heading: { superclass: ['sectionhead'], supported: ['aria-level'] },
+  img: { superclass: ['section'] },
+  insertion: { superclass: ['section'], prohibited: NAME_PROHIBITED },
+  link: { superclass: ['command'], supported: ['aria-disabled', 'aria-expanded', 'aria-haspopup'] },
+  list: { superclass: ['section'], requiredOwned: ['listitem'] },
+  listbox: {
+    superclass: ['select'],
+    supported: ['aria-errormessage', 'aria-expanded', 'aria-invalid', 'aria-multiselectable', 'aria-readonly', 'aria-required'],
+    requiredOwned: ['group > option'],
+  },
+  listitem: {
+    superclass: ['section'],
+    supported: ['aria-level', 'aria-posinset', 'aria-setsize'],
+    requiredContext: ['directory', 'list'],
+  },
+  log: { superclass: ['section'] },
+  main: { superclass: ['landmark'] },
+  marquee: { superclass: ['section'] },
+  math: { superclass: ['section'] },
+  menu: { superclass: ['select'], requiredOwned: MENU_ITEMS },
+  menubar: { superclass: ['menu'], requiredOwned: MENU_ITEMS },
+  menuitem: {
+    superclass: ['command'],
+    supported: ['aria-disabled', 'aria-expanded', 'aria-haspopup', 'aria-posinset', 'aria-setsize'],
+    requiredContext: ['group', 'menu', 'menubar'],
+  },
+  menuitemcheckbox: {
+    superclass: ['menuitem'],
+    supported: ['aria-checked'],
+    required: ['aria-checked'],
+    requiredContext: ['group', 'menu', 'menubar'],
+  },
+  menuitemradio: {
+    superclass: ['menuitemcheckbox'],
+    required: ['aria-checked'],
+    requiredContext: ['group', 'menu', 'menubar'],
+  },
+  meter: { superclass: ['range'], required: ['aria-valuenow'] },
+  navigation: { superclass: ['landmark'] },
+  none: { superclass: ['structure'], prohibited: NAME_PROHIBITED },
+  note: { superclass: ['section'] },
+  option: {
+    superclass: ['input'],
+    supported: ['aria-checked', 'aria-posinset', 'aria-selected', 'aria-setsize'],
+    requiredContext: ['group', 'listbox'],
+  },
+  paragraph: { superclass: ['section'], prohibited: NAME_PROHIBITED },
+  presentation: { superclass: ['structure'], prohibited: NAME_PROHIBITED },
+  progressbar: { superclass: ['range', 'widget'] },
+  radio: {
+    superclass: ['input'],
+    supported: ['aria-checked', 'aria-posinset', 'aria-setsize'],
+    required: ['aria-checked'],
+  },
+  radiogroup: { superclass: ['select'], supported: ['aria-errormessage', 'aria-invalid', 'aria-readonly', 'aria-required'] },
+  region: { superclass: ['landmark'] },
+  row: {
+    superclass: ['group', 'widget'],
+    supported: [
+      'aria-colindex', 'aria-expanded', 'aria-level', 'aria-posinset', 'aria-rowindex', 'aria-selected', 'aria-setsize',
+    ],
+    requiredOwned: CELLS,
+    requiredContext: ['grid', 'rowgroup', 'table', 'treegrid'],
+  },
+  rowgroup: { superclass: ['structure'], requiredOwned: ['row'], requiredContext: ['grid', 'table', 'treegrid'] },
+  rowheader: { superclass: ['cell', 'gridcell', 'sectionhead'], supported: ['aria-sort'], requiredContext: ['row'] },
+  scrollbar: {
+    superclass: ['range', 'widget'],
+    supported: ['aria-controls', 'aria-orientation'],
+    required: ['aria-controls', 'aria-valuenow'],
+  },
+  search: { superclass: ['landmark'] },
+  searchbox: { superclass: ['textbox'] },
+  separator: { superclass: ['structure', 'widget'], supported: ['aria-orientation', ...VALUE_ATTRIBUTES] },
+  slider: {
+    superclass: ['input', 'range'],
+    supported: ['aria-errormessage', 'aria-haspopup', 'aria-invalid', 'aria-orientation', 'aria-readonly'],
+    // aria-valuemin and aria-valuemax default to 0 and 100
+    required: ['aria-valuenow'],
+  },
+  spinbutton: {
+    superclass: ['composite', 'input', 'range'],
+    supported: ['aria-errormessage', 'aria-invalid', 'aria-readonly', 'aria-required'],
+  },
+  status: { superclass: ['section'] },
+  strong: { superclass: ['section'], prohibited: NAME_PROHIBITED },
+  subscript: { superclass: ['section'], prohibited: NAME_PROHIBITED },
+  superscript: { superclass: ['section'], prohibited: NAME_PROHIBITED },
+  switch: { superclass: ['checkbox'], required: ['aria-checked'] },
+  tab: {
+    superclass: ['sectionhead', 'widget'],
+    supported: ['aria-disabled', 'aria-expanded', 'aria-haspopup', 'aria-posinset', 'aria-selected', 'aria-setsize'],
+    requiredContext: ['tablist'],
+  },
+  table: { superclass: ['section'], supported: ['aria-colcount', 'aria-rowcount'], requiredOwned: ROWS },
+  tablist: { superclass: ['composite'], supported: ['aria-multiselectable', 'aria-orientation'], requiredOwned: ['tab'] },
+  tabpanel: { superclass: ['section'] },
+  term: { superclass: ['section'] },
+  textbox: {
+    superclass: ['input'],
+    supported: [
+      'aria-activedescendant', 'aria-autocomplete', 'aria-errormessage', 'aria-haspopup', 'aria-invalid',
+      'aria-multiline', 'aria-placeholder', 'aria-readonly', 'aria-required',
+    ],
+  },
+  time: { superclass: ['section'] },
+  timer: { superclass: ['status'] },
+  toolbar: { superclass: ['group'], supported: ['aria-orientation'] },
+  tooltip: { superclass: ['section'] },
+  tree: {
+    superclass: ['select'],
+    supported: ['aria-errormessage', 'aria-invalid', 'aria-multiselectable', 'aria-required'],
+    requiredOwned: ['group > treeitem'],
+  },
+  treegrid: { superclass: ['grid', 'tree'], requiredOwned: ROWS },
+  treeitem: {
+    superclass: ['listitem', 'option'],
+    supported: ['aria-expanded', 'aria-haspopup'],
+    requiredContext: ['group', 'tree'],
+  },
+};
+
+/**
+ * Whether a role exists and may be used in content
+ * @param role Role name
+ */
+export function isConcreteRole(role: string): boolean {
+  return Object.prototype.hasOwnProperty.call(ARIA_ROLES, role) && !ARIA_ROLES[role].abstract;
+}
+
+/**
+ * Attributes a role supports: global attributes plus its own and inherited ones
+ * @param role Role name, or null for elements without a role
+ * @returns Supported attribute names
+ */
+export function supportedAttributes(role: string | null): Set<string> {
+  const supported = new Set(Object.keys(ARIA_ATTRIBUTES).filter(name => {
+    const attribute = ARIA_ATTRIBUTES[name];
+    return attribute.global && !attribute.deprecatedAsGlobal;
+  }));
+
+  const visit = (name: string) => {
+    if (!Object.prototype.hasOwnProperty.call(ARIA_ROLES, name)) return;
+    const definition = ARIA_ROLES[name];
+    (definition.supported || []).forEach(attribute => supported.add(attribute));
+    (definition.required || []).forEach(attribute => supported.add(attribute));
+    definition.superclass.forEach(visit);
+  };
+  if (role) visit(role);
+
+  return supported;
+}
+
+/**
+ * Whether a value is allowed for an ARIA attribute; values of unknown attributes are not checked
+ * @param name Attribute name
+ * @param value Attribute value
+ */
+export function isValidAttributeValue(name: string, value: string): boolean {
+  const attribute = ARIA_ATTRIBUTES[name];
+  if (!attribute) return true;
+
+  const normalized = value.trim().toLowerCase();
+  switch (attribute.type) {
+    case 'true/false':
+      return normalized === 'true' || normalized === 'false';
+    case 'true/false/undefined':
+      return ['true', 'false', 'undefined'].includes(normalized);
+    case 'tristate':
+      return ['true', 'false', 'mixed', 'undefined'].includes(normalized);
+    case 'integer':
+      return /^-?\d+$/.test(normalized);
+    case 'number':
+      return /^-?(\d+\.?\d*|\.\d+)(e[-+]?\d+)?$/.test(normalized);
+    case 'token':
+      return (attribute.values || []).includes(normalized);
+    case 'tokens':
+      return normalized.split(/\s+/).every(token => (attribute.values || []).includes(token));
+    default:
+      return true;
+  }
+}
@@ -0,0 +1,414 @@
+/**
+ * An sRGB color: channels from 0 to 255 (not rounded) and alpha from 0 to 1
+ */
+export interface RGBA {
+  r: number;
+  g: number;
+  b: number;
+  a: number;
+}
+
+type Vector = [number, number, number];
+type Matrix = [Vector, Vector, Vector];
+
+/**
+ * CSS named colors (CSS Color 4, section 6.1)
+ */
+const NAMED_COLORS: Record<string, string> = {
+  aliceblue: 'f0f8ff', antiquewhite: 'faebd7', aqua: '00ffff', aquamarine: '7fffd4', azure: 'f0ffff',
+  beige: 'f5f5dc', bisque: 'ffe4c4', black: '000000', blanchedalmond: 'ffebcd', blue: '0000ff',
+  blueviolet: '8a2be2', brown: 'a52a2a', burlywood: 'deb887', cadetblue: '5f9ea0', chartreuse: '7fff00',
+  chocolate: 'd2691e', coral: 'ff7f50', cornflowerblue: '6495ed', cornsilk: 'fff8dc', crimson: 'dc143c',
+  cyan: '00ffff', darkblue: '00008b', darkcyan: '008b8b', darkgoldenrod: 'b8860b', darkgray: 'a9a9a9',
+  darkgreen: '006400', darkgrey: 'a9a9a9', darkkhaki: 'bdb76b', darkmagenta: '8b008b', darkolivegreen: '556b2f',
+  darkorange: 'ff8c00', darkorchid: '9932cc', darkred: '8b0000', darksalmon: 'e9967a', darkseagreen: '8fbc8f',
+  darkslateblue: '483d8b', darkslategray: '2f4f4f', darkslategrey: '2f4f4f', darkturquoise: '00ced1',
+  darkviolet: '9400d3', deeppink: 'ff1493', deepskyblue: '00bfff', dimgray: '696969', dimgrey: '696969',
+  dodgerblue: '1e90ff', firebrick: 'b22222', floralwhite: 'fffaf0', forestgreen: '228b22', fuchsia: 'ff00ff',
+  gainsboro: 'dcdcdc', ghostwhite: 'f8f8ff', gold: 'ffd700', goldenrod: 'daa520', gray: '808080',
+  green: '008000', greenyellow: 'adff2f', grey: '808080', honeydew: 'f0fff0', hotpink: 'ff69b4',
+  indianred: 'cd5c5c', indigo: '4b0082', ivory: 'fffff0', khaki: 'f0e68c', lavender: 'e6e6fa',
+  lavenderblush: 'fff0f5', lawngreen: '7cfc00', lemonchiffon: 'fffacd', lightblue: 'add8e6', lightcoral: 'f08080',
+  lightcyan: 'e0ffff', lightgoldenrodyellow: 'fafad2', lightgray: 'd3d3d3', lightgreen: '90ee90', lightgrey: 'd3d3d3',
+  lightpink: 'ffb6c1', lightsalmon: 'ffa07a', lightseagreen: '20b2aa', lightskyblue: '87cefa',
+  lightslategray: '778899', lightslategrey: '778899', lightsteelblue: 'b0c4de', lightyellow: 'ffffe0',
+  lime: '00ff00', limegreen: '32cd32', linen: 'faf0e6', magenta: 'ff00ff', maroon: '800000',
+  mediumaquamarine: '66cdaa', mediumblue: '0000cd', mediumorchid: 'ba55d3', mediumpurple: '9370db',
+  mediumseagreen: '3cb371', mediumslateblue: '7b68ee', mediumspringgreen: '00fa9a', mediumturquoise: '48d1cc',
+  mediumvioletred: 'c71585', midnightblue: '191970', mintcream: 'f5fffa', mistyrose: 'ffe4e1', moccasin: 'ffe4b5',
+  navajowhite: 'ffdead', navy: '000080', oldlace: 'fdf5e6', olive: '808000', olivedrab: '6b8e23',
+  orange: 'ffa500', orangered: 'ff4500', orchid: 'da70d6', palegoldenrod: 'eee8aa', palegreen: '98fb98',
+  paleturquoise: 'afeeee', palevioletred: 'db7093', papayawhip: 'ffefd5', peachpuff: 'ffdab9', peru: 'cd853f',
+  pink: 'ffc0cb', plum: 'dda0dd', powderblue: 'b0e0e6', purple: '800080', rebeccapurple: '663399',
+  red: 'ff0000', rosybrown: 'bc8f8f', royalblue: '4169e1', saddlebrown: '8b4513', salmon: 'fa8072',
+  sandybrown: 'f4a460', seagreen: '2e8b57', seashell: 'fff5ee', sienna: 'a0522d', silver: 'c0c0c0',
+  skyblue: '87ceeb', slateblue: '6a5acd', slategray: '708090', slategrey: '708090', snow: 'fffafa',
+  springgreen: '00ff7f', steelblue: '4682b4', tan: 'd2b48c', teal: '008080', thistle: 'd8bfd8',
+  tomato: 'ff6347', turquoise: '40e0d0', violet: 'ee82ee', wheat: 'f5deb3', white: 'ffffff',
+  whitesmoke: 'f5f5f5', yellow: 'ffff00', yellowgreen: '9acd32',
+  // System colors, using common light color scheme values
+  canvas: 'ffffff', canvastext: '000000', linktext: '0000ee', visitedtext: '551a8b', activetext: 'ff0000',
+  buttonface: 'efefef', buttontext: '000000', field: 'ffffff', fieldtext: '000000', graytext: '808080',
+  mark: 'ffff00', marktext: '000000',
+};
+
+const SRGB_FROM_XYZ: Matrix = [
+  [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+  [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+  [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+
+const D65_FROM_D50: Matrix = [
+  [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+  [-0.0283697093338637, 1.0099953980813041, 0.021041441191917323],
+  [0.012314014864481998, -0.020507649298898964, 1.330365926242124],
+];
+
+const D50_WHITE: Vector = [0.3457 / 0.3585, 1, (1 - 0.3457 - 0.3585) / 0.3585];
+
+/**
+ * Predefined RGB color() spaces other than sRGB: transfer function to linear light and matrix to XYZ
+ */
+const COLOR_SPACES: Record<string, { toLinear: (value: number) => number; toXyz: Matrix; d50?: boolean }> = {
+  'display-p3': {
+    toLinear: srgbToLinear,
+    toXyz: [
+      [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+      [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+      [0, 0.04511338185890264, 1.043944368900976],
+    ],
+  },
+  'a98-rgb': {
+    toLinear: value => Math.sign(value) * Math.pow(Math.abs(value), 563 / 256),
+    toXyz: [
+      [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+      [0.29734497525053605, 0.6273635662554661, 0.0752914584939978],
+      [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+    ],
+  },
+  'prophoto-rgb': {
+    toLinear: value => (Math.abs(value) <= 16 / 512 ? value / 16 : Math.sign(value) * Math.pow(Math.abs(value), 1.8)),
+    toXyz: [
+      [0.7977604896723027, 0.13518583717574031, 0.0313493495815248],
+      [0.2880711282292934, 0.7118432178101014, 0.00008565396060525902],
+      [0, 0, 0.8251046025104601],
+    ],
+    d50: true,
+  },
+  'rec2020': {
+    toLinear: value => {
+      const alpha = 1.09929682680944;
+      const beta = 0.018053968510807;
+      const magnitude = Math.abs(value);
+      return magnitude < beta * 4.5
+        ? value / 4.5
+        : Math.sign(value) * Math.pow((magnitude + alpha - 1) / alpha, 1 / 0.45);
+    },
+    toXyz: [
+      [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+      [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+      [0, 0.028072693049087428, 1.060985057710791],
+    ],
+  },
+};
+
+const IDENTITY: Matrix = [[1, 0, 0], [0, 1, 0], [0, 0, 1]];
+
+/**
+ * Parse a CSS color (CSS Color Module Level 4): named and system colors, transparent,
+ * currentColor, hex with alpha, rgb(), hsl(), hwb(), lab(), lch(), oklab(), oklch()
+ * and color(), in legacy comma and modern space-separated syntax.
+ * Colors outside sRGB are clipped to it.
+ * @param value CSS color value
+ * @param currentColor Color to use for currentColor
+ * @returns The color, or null when the value is not a color this parser understands
+ */
+export function parseColor(value: string, currentColor: RGBA | null = null): RGBA | null {
+  const color = value.trim().toLowerCase();
+
+  if (color === 'transparent') return { r: 0, g: 0, b: 0, a: 0 };
+  if (color === 'currentcolor') return currentColor;
+  if (Object.prototype.hasOwnProperty.call(NAMED_COLORS, color)) return parseHex(NAMED_COLORS[color]);
+  if (color.startsWith('#')) return parseHex(color.slice(1));
+
+  const match = color.match(/^([a-z0-9-]+)\((.*)\)$/);
+  if (!match) return null;
+
+  const args = parseArguments(match[2]);
+  if (!args) return null;
+  const alpha = args.alpha === null ? 1 : parseAlpha(args.alpha);
+  if (alpha === null) return null;
+
+  const rgb = parseFunction(match[1], args.components, args.legacy);
+  if (!rgb) return null;
+
+  return {
+    r: clamp(rgb[0], 0, 1) * 255,
+    g: clamp(rgb[1], 0, 1) * 255,
+    b: clamp(rgb[2], 0, 1) * 255,
+    a: alpha,
+  };
+}
+
+/**
+ * Paint one color over another (Porter-Duff source-over)
+ * @param top Color on top
+ * @param bottom Color below
+ * @returns The blended color
+ */
+export function compositeColors(top: RGBA, bottom: RGBA): RGBA {
+  const a = top.a + bottom.a * (1 - top.a);
+  if (a === 0) return { r: 0, g: 0, b: 0, a: 0 };
+
+  const blend = (over: number, under: number) => (over * top.a + under * bottom.a * (1 - top.a)) / a;
+  return {
+    r: blend(top.r, bottom.r),
+    g: blend(top.g, bottom.g),
+    b: blend(top.b, bottom.b),
+    a,
+  };
+}
+
+/**
+ * Relative luminance as defined by WCAG 2
+ * @param color Opaque color
+ */
+export function relativeLuminance(color: RGBA): number {
+  const channel = (value: number) => {
+    const srgb = value / 255;
+    return srgb <= 0.03928 ? srgb / 12.92 : Math.pow((srgb + 0.055) / 1.055, 2.4);
+  };
+  return 0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b);
+}
+
+/**
+ * WCAG 2 contrast ratio between two opaque colors
+ * @param foreground First color
+ * @param background Second color
+ * @returns Ratio from 1 to 21
+ */
+export function contrastRatio(foreground: RGBA, background: RGBA): number {
+  const lighter = Math.max(relativeLuminance(foreground), relativeLuminance(background));
+  const darker = Math.min(relativeLuminance(foreground), relativeLuminance(background));
+  return (lighter + 0.05) / (darker + 0.05);
+}
+
+/**
+ * Serialize a color as rgb() or, when translucent, rgba()
+ * @param color Color to format
+ */
+export function formatColor(color: RGBA): string {
+  const channels = `${Math.round(color.r)}, ${Math.round(color.g)}, ${Math.round(color.b)}`;
+  return color.a >= 1 ? `rgb(${channels})` : `rgba(${channels}, ${Number(color.a.toFixed(3))})`;
+}
+
+function parseHex(hex: string): RGBA | null {
+  if (!/^([0-9a-f]{3,4}|[0-9a-f]{6}|[0-9a-f]{8})$/i.test(hex)) return null;
+
+  const digits = hex.length <= 4 ? hex.split('').map(digit => digit + digit).join('') : hex;
+  const channel = (index: number) => parseInt(digits.slice(index * 2, index * 2 + 2), 16);
+  return {
+    r: channel(0),
+    g: channel(1),
+    b: channel(2),
+    a: digits.length === 8 ? channel(3) / 255 : 1,
+  };
+}
+
+/**
+ * Split function arguments into three components and an optional alpha
+ */
+function parseArguments(body: string): { components: string[]; alpha: string | null; legacy: boolean } | null {
+  if (body.includes(',')) {
+    const parts = body.split(',').map(part => part.trim());
+    if (parts.length !== 3 && parts.length !== 4) return null;
+    return { components: parts.slice(0, 3), alpha: parts[3] ?? null, legacy: true };
+  }
+
+  const [main, alpha, extra] = body.split('/').map(part => part.trim());
+  if (extra !== undefined || alpha === '') return null;
+  return { components: main.split(/\s+/).filter(Boolean), alpha: alpha ?? null, legacy: false };
+}
+
+/**
+ * Convert a color function's components to sRGB channels from 0 to 1
+ */
+function parseFunction(name: string, components: string[], legacy: boolean): Vector | null {
+  if (name === 'color') {
+    if (legacy || components.length !== 4) return null;
+    return colorFunction(components[0], components.slice(1));
+  }
+
+  // Only rgb() and hsl() accept the legacy comma syntax
+  if (legacy && !['rgb', 'rgba', 'hsl', 'hsla'].includes(name)) return null;
+  if (components.length !== 3) return null;
+  const [first, second, third] = components;
+
+  switch (name) {
+    case 'rgb':
+    case 'rgba': {
+      const channels = components.map(component => parseNumberOrPercentage(component, 255));
+      if (channels.some(channel => channel === null)) return null;
+      return (channels as number[]).map(channel => channel / 255) as Vector;
+    }
+    case 'hsl':
+    case 'hsla': {
+      const hue = parseHue(first);
+      const saturation = parseNumberOrPercentage(second, 100);
+      const lightness = parseNumberOrPercentage(third, 100);
+      if (hue === null || saturation === null || lightness === null) return null;
+      return hslToRgb(hue, clamp(saturation / 100, 0, 1), clamp(lightness / 100, 0, 1));
+    }
+    case 'hwb': {
+      const hue = parseHue(first);
+      const whiteness = parseNumberOrPercentage(second, 100);
+      const blackness = parseNumberOrPercentage(third, 100);
+      if (hue === null || whiteness === null || blackness === null) return null;
+      return hwbToRgb(hue, clamp(whiteness / 100, 0, 1), clamp(blackness / 100, 0, 1));
+    }
+    case 'lab':
+    case 'lch': {
+      const lightness = parseNumberOrPercentage(first, 100);
+      const a = parseNumberOrPercentage(second, name === 'lab' ? 125 : 150);
+      const b = name === 'lab' ? parseNumberOrPercentage(third, 125) : parseHue(third);
+      if (lightness === null || a === null || b === null) return null;
+      const [labA, labB] = name === 'lab' ? [a, b] : polarToCartesian(a, b);
+      return xyzToSrgb(multiply(D65_FROM_D50, labToXyzD50(clamp(lightness, 0, 100), labA, labB)));
+    }
+    case 'oklab':
+    case 'oklch': {
+      const lightness = parseNumberOrPercentage(first, 1);
+      const a = parseNumberOrPercentage(second, 0.4);
+      const b = name === 'oklab' ? parseNumberOrPercentage(third, 0.4) : parseHue(third);
+      if (lightness === null || a === null || b === null) return null;
+      const [labA, labB] = name === 'oklab' ? [a, b] : polarToCartesian(a, b);
+      return oklabToSrgb(clamp(lightness, 0, 1), labA, labB);
+    }
+    default:
+      return null;
+  }
+}
+
+/**
+ * color(<space> c1 c2 c3) for the predefined RGB and XYZ spaces
+ */
+function colorFunction(space: string, components: string[]): Vector | null {
+  const values = components.map(component => parseNumberOrPercentage(component, 1));
+  if (values.some(value => value === null)) return null;
+  const vector = values as number[] as Vector;
+
+  if (space === 'srgb') return vector;
+  if (space === 'srgb-linear') return vector.map(linearToSrgb) as Vector;
+  if (space === 'xyz' || space === 'xyz-d65') return xyzToSrgb(vector);
+  if (space === 'xyz-d50') return xyzToSrgb(multiply(D65_FROM_D50, vector));
+
+  const definition = COLOR_SPACES[space];
+  if (!definition) return null;
+  const xyz = multiply(definition.toXyz, vector.map(definition.toLinear) as Vector);
+  return xyzToSrgb(multiply(definition.d50 ? D65_FROM_D50 : IDENTITY, xyz));
+}
+
+function parseAlpha(value: string): number | null {
+  const alpha = parseNumberOrPercentage(value, 1);
+  return alpha === null ? null : clamp(alpha, 0, 1);
+}
+
+/**
+ * Parse a number, a percentage of `percentReference`, or `none` (zero)
+ */
+function parseNumberOrPercentage(value: string, percentReference: number): number | null {
+  if (value === 'none') return 0;
+  if (value.endsWith('%')) {
+    const number = parseNumber(value.slice(0, -1));
+    return number === null ? null : (number / 100) * percentReference;
+  }
+  return parseNumber(value);
+}
+
+/**
+ * Parse an angle in degrees; plain numbers are degrees
+ */
+function parseHue(value: string): number | null {
+  if (value === 'none') return 0;
+
+  const match = value.match(/^(.*?)(deg|grad|rad|turn)?$/);
+  const number = match ? parseNumber(match[1]) : null;
+  if (number === null || !match) return null;
+
+  const factor: Record<string, number> = { deg: 1, grad: 0.9, rad: 180 / Math.PI, turn: 360 };
+  return number * factor[match[2] || 'deg'];
+}
+
+function parseNumber(value: string): number | null {
+  return /^[+-]?(\d+\.?\d*|\.\d+)(e[+-]?\d+)?$/.test(value) ? parseFloat(value) : null;
+}
+
+function hslToRgb(hue: number, saturation: number, lightness: number): Vector {
+  const h = ((hue % 360) + 360) % 360;
+  const channel = (n: number) => {
+    const k = (n + h / 30) % 12;
+    const a = saturation * Math.min(lightness, 1 - lightness);
+    return lightness - a * Math.max(-1, Math.min(k - 3, 9 - k, 1));
+  };
+  return [channel(0), channel(8), channel(4)];
+}
+
+function hwbToRgb(hue: number, whiteness: number, blackness: number): Vector {
+  if (whiteness + blackness >= 1) {
+    const gray = whiteness / (whiteness + blackness);
+    return [gray, gray, gray];
+  }
+  return hslToRgb(hue, 1, 0.5).map(channel => channel * (1 - whiteness - blackness) + whiteness) as Vector;
+}
+
+function polarToCartesian(chroma: number, hue: number): [number, number] {
+  const radians = (hue * Math.PI) / 180;
+  return [Math.max(chroma, 0) * Math.cos(radians), Math.max(chroma, 0) * Math.sin(radians)];
+}
+
+function labToXyzD50(lightness: number, a: number, b: number): Vector {
+  const kappa = 24389 / 27;
+  const epsilon = 216 / 24389;
+  const fy = (lightness + 16) / 116;
+  const fx = a / 500 + fy;
+  const fz = fy - b / 200;
+
+  const x = Math.pow(fx, 3) > epsilon ? Math.pow(fx, 3) : (116 * fx - 16) / kappa;
+  const y = lightness > kappa * epsilon ? Math.pow(fy, 3) : lightness / kappa;
+  const z = Math.pow(fz, 3) > epsilon ? Math.pow(fz, 3) : (116 * fz - 16) / kappa;
+  return [x * D50_WHITE[0], y * D50_WHITE[1], z * D50_WHITE[2]];
+}
+
+function oklabToSrgb(lightness: number, a: number, b: number): Vector {
+  const l = Math.pow(lightness + 0.3963377774 * a + 0.2158037573 * b, 3);
+  const m = Math.pow(lightness - 0.1055613458 * a - 0.0638541728 * b, 3);
+  const s = Math.pow(lightness - 0.0894841775 * a - 1.291485548 * b, 3);
+  const linear: Vector = [
+    4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+    -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+    -0.0041960863 * l - 0.7034186147 * m + 1.707614701 * s,
+  ];
+  return linear.map(linearToSrgb) as Vector;
+}
+
+function xyzToSrgb(xyz: Vector): Vector {
+  return multiply(SRGB_FROM_XYZ, xyz).map(linearToSrgb) as Vector;
+}
+
+function srgbToLinear(value: number): number {
+  const magnitude = Math.abs(value);
+  return magnitude <= 0.04045 ? value / 12.92 : Math.sign(value) * Math.pow((magnitude + 0.055) / 1.055, 2.4);
+}
+
+function linearToSrgb(value: number): number {
+  const magnitude = Math.abs(value);
+  return magnitude <= 0.0031308 ? value * 12.92 : Math.sign(value) * (1.055 * Math.pow(magnitude, 1 / 2.4) - 0.055);
+}
+
+function multiply(matrix: Matrix, vector: Vector): Vector {
+  return matrix.map(row => row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2]) as Vector;
+}
+
+function clamp(value: number, min: number, max: number): number {
+  return Math.min(max, Math.max(min, value));
+}
@@ -0,0 +1,155 @@
+import { ResultItem, SourceLocation } from '../types';
+
+const XHTML_NAMESPACE = 'http://www.w3.org/1999/xhtml';
+
+/**
+ * Returns the source position of an element, or null when it was not in the parsed source
+ */
+export type ElementLocator = (element: Element) => SourceLocation | null;
+
+// Keyed by document so rules stay free of jsdom; documents without a locator (e.g. in a browser) get no locations
+const locators = new WeakMap<Document, ElementLocator>();
+
+/**
+ * Register how to find source positions for the elements of a document
+ * @param document Document being scanned
+ * @param locator Locator for its elements
+ */
+export function registerLocator(document: Document, locator: ElementLocator): void {
+  locators.set(document, locator);
+}
+
+// How many elements carry each id, counted once per document rather than per result
+const idCounts = new WeakMap<Document, Map<string, number>>();
+
+/**
+ * Forget the ids counted for a document, e.g. before rescanning a page that has changed
+ * @param document Scanned document
+ */
+export function clearIdCache(document: Document): void {
+  idCounts.delete(document);
+}
+
+/**
+ * Details rules record for the element a result is about, spread into the result
+ * @param element Element the rule inspected
+ * @returns A unique selector and XPath for the element, and its source location when known
+ */
+export function elementContext(element: Element): Pick<ResultItem, 'location' | 'selector' | 'xpath'> {
+  const locator = element.ownerDocument ? locators.get(element.ownerDocument) : undefined;
+  const location = locator ? locator(element) : null;
+  return {
+    selector: uniqueSelector(element),
+    xpath: elementXPath(element),
+    ...(location ? { location } : {}),
+  };
+}
+
+/**
+ * Build a CSS selector that matches only this element: an nth-child path from the
+ * nearest ancestor with a unique id, or from the root element
+ * @param element Element to select
+ * @returns Selector such as "#nav > ul:nth-child(1) > li:nth-child(3)"
+ */
+export function uniqueSelector(element: Element): string {
+  const parts: string[] = [];
+  let current: Element | null = element;
+
+  while (current) {
+    const parent: Element | null = current.parentElement;
+    if (current.id && hasUniqueId(current)) {
+      parts.unshift(`#${cssEscape(current.id)}`);
+      break;
+    }
+
+    const name = cssEscape(current.localName);
+    if (!parent) {
+      parts.unshift(name);
+      break;
+    }
+
+    const index = Array.from(parent.children).indexOf(current) + 1;
+    parts.unshift(`${name}:nth-child(${index})`);
+    current = parent;
+  }
+
+  return parts.join(' > ');
+}
+
+/**
+ * Build an XPath for the element, anchored at the nearest ancestor with a unique id
+ * @param element Element to locate
+ * @returns XPath such as "/html/body/main/img[2]"
+ */
+export function elementXPath(element: Element): string {
+  const steps: string[] = [];
+  let current: Element | null = element;
+
+  while (current) {
+    const node: Element = current;
+    if (node.id && hasUniqueId(node) && !node.id.includes('"')) {
+      return `//*[@id="${node.id}"]${steps.length > 0 ? '/' : ''}${steps.join('/')}`;
+    }
+
+    // Elements outside the HTML namespace (SVG, MathML) need a namespace-agnostic test
+    const name = node.namespaceURI === XHTML_NAMESPACE
+      ? node.localName
+      : `*[local-name()="${node.localName}"]`;
+    const parent = node.parentElement;
+    const siblings = parent
+      ? Array.from(parent.children).filter(sibling =>
+        sibling.localName === node.localName && sibling.namespaceURI === node.namespaceURI)
+      : [node];
+    const position = siblings.length > 1 ? `[${siblings.indexOf(node) + 1}]` : '';
+
+    steps.unshift(`${name}${position}`);
+    current = parent;
+  }
+
+  return `/${steps.join('/')}`;
+}
+
+/**
+ * Escape a string for use as a CSS identifier, like CSS.escape (which jsdom lacks)
+ * @param value Identifier to escape
+ * @returns Escaped identifier
+ */
+export function cssEscape(value: string): string {
+  let result = '';
+  for (let i = 0; i < value.length; i++) {
+    const code = value.charCodeAt(i);
+    const char = value.charAt(i);
+    const isDigit = code >= 0x30 && code <= 0x39;
+
+    if (code === 0) {
+      result += '\uFFFD';
+    } else if (
+      (code >= 0x01 && code <= 0x1f) || code === 0x7f ||
+      (i === 0 && isDigit) ||
+      (i === 1 && isDigit && value.charCodeAt(0) === 0x2d)
+    ) {
+      result += `\\${code.toString(16)} `;
+    } else if (i === 0 && value.length === 1 && code === 0x2d) {
+      result += `\\${char}`;
+    } else if (code >= 0x80 || code === 0x2d || code === 0x5f || isDigit || /[a-zA-Z]/.test(char)) {
+      result += char;
+    } else {
+      result += `\\${char}`;
+    }
+  }
+  return result;
+}
+
+function hasUniqueId(element: Element): boolean {
+  const root = element.ownerDocument;
+  if (!root || !root.documentElement || !root.documentElement.contains(element)) return false;
+
+  let counts = idCounts.get(root);
+  if (!counts) {
+    const counted = new Map<string, number>();
+    root.querySelectorAll('[id]').forEach(withId => counted.set(withId.id, (counted.get(withId.id) || 0) + 1));
+    idCounts.set(root, counted);
+    counts = counted;
+  }
+  return counts.get(element.id) === 1;
+}
@@ -0,0 +1,54 @@
+/**
+ * Convert a glob pattern to a regular expression.
+ * Supports `*`, `**`, `?`, `[...]` character classes and `{a,b}` alternatives.
+ * @param glob Glob pattern using forward slashes
+ * @returns Anchored regular expression
+ */
+export function globToRegExp(glob: string): RegExp {
+  let source = '';
+
+  for (let i = 0; i < glob.length; i++) {
+    const char = glob[i];
+
+    if (char === '*') {
+      if (glob[i + 1] === '*') {
+        if (glob[i + 2] === '/') {
+          // `**/` matches zero or more directories
+          source += '(?:.*/)?';
+          i += 2;
+        } else {
+          source += '.*';
+          i += 1;
+        }
+      } else {
+        source += '[^/]*';
+      }
+    } else if (char === '?') {
+      source += '[^/]';
+    } else if (char === '[') {
+      const end = glob.indexOf(']', i + 1);
+      if (end === -1) {
+        source += '\\[';
+      } else {
+        source += glob.slice(i, end + 1).replace(/^\[!/, '[^');
+        i = end;
+      }
+    } else if (char === '{') {
+      const end = glob.indexOf('}', i + 1);
+      if (end === -1) {
+        source += '\\{';
+      } else {
+        source += `(?:${glob.slice(i + 1, end).split(',').map(escapeRegExp).join('|')})`;
+        i = end;
+      }
+    } else {
+      source += escapeRegExp(char);
+    }
+  }
+
+  return new RegExp(`^${source}$`);
+}
+
+function escapeRegExp(value: string): string {
+  return value.replace(/[.*+?^${}()|[\]\\/]/g, '\\$&');
+}
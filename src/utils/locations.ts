@@ -0,0 +1,76 @@
+import path from 'path';
+import { fileURLToPath } from 'url';
+import { JSDOM } from 'jsdom';
+import { ResultItem, ScanResults, SourceLocation } from '../types';
+import { locateResultElement } from '../suppressions';
+
+/**
+ * Get the source position of an element's start tag
+ * @param dom DOM created with includeNodeLocations
+ * @param element Element to locate
+ * @returns The location, or null for elements that were not in the parsed source
+ */
+export function elementLocation(dom: JSDOM, element: Element): SourceLocation | null {
+  const location = dom.nodeLocation(element);
+  if (!location) return null;
+
+  const tag = location.startTag || location;
+  return {
+    line: tag.startLine,
+    column: tag.startCol,
+    endLine: tag.endLine,
+    endColumn: tag.endCol,
+  };
+}
+
+/**
+ * Attach source locations to violations, warnings and incomplete results that do not have one yet
+ * @param results Scan results
+ * @param dom DOM the results came from, created with includeNodeLocations
+ * @returns Scan results with locations where the element could be identified
+ */
+export function addLocations<T extends ScanResults>(results: T, dom: JSDOM): T {
+  const document = dom.window.document;
+  const locate = <I extends ResultItem>(item: I): I => {
+    if (item.location) return item;
+
+    const element = locateResultElement(item, document);
+    const location = element ? elementLocation(dom, element) : null;
+    return location ? { ...item, location } : item;
+  };
+
+  return {
+    ...results,
+    violations: results.violations.map(locate),
+    warnings: results.warnings.map(locate),
+    incomplete: results.incomplete.map(locate),
+  };
+}
+
+/**
+ * Turn a document URL into a path for reports: file URLs become paths relative to cwd
+ * @param url Document URL
+ * @param cwd Base directory
+ * @returns The path or URL, or null without a URL
+ */
+export function displayPath(url: string | undefined, cwd: string = process.cwd()): string | null {
+  if (!url) return null;
+  if (!url.startsWith('file:')) return url;
+
+  try {
+    return path.relative(cwd, fileURLToPath(url)).split(path.sep).join('/');
+  } catch {
+    return url;
+  }
+}
+
+/**
+ * Format a result location as `file:line:col`, or `line:col` when the page has no URL
+ * @param url URL of the scanned document
+ * @param location Location of the result
+ * @param cwd Base directory for file URLs
+ */
+export function formatLocation(url: string | undefined, location: SourceLocation, cwd: string = process.cwd()): string {
+  const file = displayPath(url, cwd);
+  return `${file ? `${file}:` : ''}${location.line}:${location.column}`;
+}
@@ -0,0 +1,195 @@
+import { isConcreteRole } from './ariaSpec';
+
+/**
+ * Input types whose implicit role is textbox
+ */
+const TEXTBOX_INPUT_TYPES = ['', 'text', 'email', 'tel', 'url'];
+
+/**
+ * Ancestors that stop header and footer from being page-level landmarks
+ */
+const SECTIONING_ANCESTORS = 'article, aside, main, nav, section, [role="article"], [role="complementary"], [role="main"], [role="navigation"], [role="region"]';
+
+/**
+ * Get the role of an element: the role its role attribute gives it, or its implicit role
+ * @param element Element to inspect
+ * @returns Role name, or null for elements without a role
+ */
+export function getRole(element: Element): string | null {
+  return explicitRole(element) || implicitRole(element);
+}
+
+/**
+ * Get the role an element's role attribute gives it: the first token that is a concrete
+ * ARIA role, so that later tokens act as fallbacks (e.g. role="switch checkbox")
+ * @param element Element to inspect
+ * @returns Role name, or null when the attribute is missing or has no valid token
+ */
+export function explicitRole(element: Element): string | null {
+  return roleTokens(element).find(isConcreteRole) || null;
+}
+
+/**
+ * Split an element's role attribute into lowercase tokens
+ * @param element Element to inspect
+ * @returns Tokens in attribute order; empty when the attribute is missing or blank
+ */
+export function roleTokens(element: Element): string[] {
+  return (element.getAttribute('role') || '').toLowerCase().split(/\s+/).filter(Boolean);
+}
+
+/**
+ * Get the role HTML assigns an element without a role attribute (HTML-AAM)
+ * @param element Element to inspect
+ * @returns Role name, or null for elements without an implicit role
+ */
+export function implicitRole(element: Element): string | null {
+  const tag = element.localName;
+
+  switch (tag) {
+    case 'a':
+      return element.hasAttribute('href') ? 'link' : 'generic';
+    case 'area':
+      return element.hasAttribute('href') ? 'link' : null;
+    case 'article':
+      return 'article';
+    case 'aside':
+      return 'complementary';
+    case 'b':
+    case 'bdi':
+    case 'bdo':
+    case 'data':
+    case 'div':
+    case 'i':
+    case 'pre':
+    case 'q':
+    case 'samp':
+    case 'small':
+    case 'span':
+    case 'u':
+      return 'generic';
+    case 'blockquote':
+      return 'blockquote';
+    case 'button':
+    case 'summary':
+      return 'button';
+    case 'caption':
+      return 'caption';
+    case 'code':
+      return 'code';
+    case 'dd':
+      return 'definition';
+    case 'del':
+    case 's':
+      return 'deletion';
+    case 'details':
+    case 'fieldset':
+    case 'optgroup':
+      return 'group';
+    case 'dialog':
+      return 'dialog';
+    case 'dt':
+      return 'term';
+    case 'em':
+      return 'emphasis';
+    case 'figure':
+      return 'figure';
+    case 'footer':
+      return element.closest(SECTIONING_ANCESTORS) ? 'generic' : 'contentinfo';
+    case 'form':
+      return 'form';
+    case 'h1':
+    case 'h2':
+    case 'h3':
+    case 'h4':
+    case 'h5':
+    case 'h6':
+      return 'heading';
+    case 'header':
+      return element.closest(SECTIONING_ANCESTORS) ? 'generic' : 'banner';
+    case 'hr':
+      return 'separator';
+    case 'img':
+      return element.getAttribute('alt') === '' ? 'presentation' : 'img';
+    case 'input':
+      return inputRole(element);
+    case 'ins':
+      return 'insertion';
+    case 'li':
+      return 'listitem';
+    case 'main':
+      return 'main';
+    case 'menu':
+    case 'ol':
+    case 'ul':
+      return 'list';
+    case 'meter':
+      return 'meter';
+    case 'nav':
+      return 'navigation';
+    case 'option':
+      return 'option';
+    case 'output':
+      return 'status';
+    case 'p':
+      return 'paragraph';
+    case 'progress':
+      return 'progressbar';
+    case 'section':
+      return 'region';
+    case 'select':
+      return element.hasAttribute('multiple') || Number(element.getAttribute('size')) > 1 ? 'listbox' : 'combobox';
+    case 'strong':
+      return 'strong';
+    case 'sub':
+      return 'subscript';
+    case 'sup':
+      return 'superscript';
+    case 'table':
+      return 'table';
+    case 'tbody':
+    case 'tfoot':
+    case 'thead':
+      return 'rowgroup';
+    case 'td':
+      return 'cell';
+    case 'textarea':
+      return 'textbox';
+    case 'th':
+      return element.getAttribute('scope') === 'row' ? 'rowheader' : 'columnheader';
+    case 'time':
+      return 'time';
+    case 'tr':
+      return 'row';
+    default:
+      return null;
+  }
+}
+
+function inputRole(input: Element): string | null {
+  const type = (input.getAttribute('type') || '').toLowerCase();
+  const hasList = input.hasAttribute('list');
+
+  switch (type) {
+    case 'button':
+    case 'image':
+    case 'reset':
+    case 'submit':
+      return 'button';
+    case 'checkbox':
+      return 'checkbox';
+    case 'radio':
+      return 'radio';
+    case 'range':
+      return 'slider';
+    case 'number':
+      return 'spinbutton';
+    case 'search':
+      return hasList ? 'combobox' : 'searchbox';
+    default:
+      if (TEXTBOX_INPUT_TYPES.includes(type)) {
+        return hasList ? 'combobox' : 'textbox';
+      }
+      return null;
+  }
+}
@@ -0,0 +1,176 @@
+/**
+ * Font size of the root element when nothing sets it
+ */
+const DEFAULT_FONT_SIZE = 16;
+
+const ABSOLUTE_SIZES: Record<string, number> = {
+  'xx-small': 9,
+  'x-small': 10,
+  'small': 13,
+  'medium': 16,
+  'large': 18,
+  'x-large': 24,
+  'xx-large': 32,
+  'xxx-large': 48,
+};
+
+const PIXELS_PER_UNIT: Record<string, number> = {
+  px: 1,
+  pt: 96 / 72,
+  pc: 16,
+  in: 96,
+  cm: 96 / 2.54,
+  mm: 96 / 25.4,
+  q: 96 / 101.6,
+};
+
+/**
+ * User agent font sizes, used when the computed style has no value (e.g. in jsdom)
+ */
+const DEFAULT_SIZES: Record<string, string> = {
+  h1: '2em',
+  h2: '1.5em',
+  h3: '1.17em',
+  h4: '1em',
+  h5: '0.83em',
+  h6: '0.67em',
+  small: 'smaller',
+  big: 'larger',
+};
+
+/**
+ * Elements user agents render bold
+ */
+const BOLD_ELEMENTS = ['b', 'strong', 'th', 'h1', 'h2', 'h3', 'h4', 'h5', 'h6'];
+
+/**
+ * Resolve an element's font size in CSS pixels, following em, rem, percentage and
+ * keyword sizes up the ancestor chain
+ * @param element Element to measure
+ * @param window Browser window
+ * @param cache Sizes already resolved in this scan
+ * @returns Font size in CSS pixels
+ */
+export function fontSizeInPixels(element: Element, window: Window, cache = new WeakMap<Element, number>()): number {
+  const cached = cache.get(element);
+  if (cached !== undefined) return cached;
+
+  const document = element.ownerDocument;
+  const parent = element.parentElement;
+  const parentSize = () => (parent ? fontSizeInPixels(parent, window, cache) : DEFAULT_FONT_SIZE);
+  const rootSize = () => (
+    document.documentElement && document.documentElement !== element
+      ? fontSizeInPixels(document.documentElement, window, cache)
+      : DEFAULT_FONT_SIZE
+  );
+
+  const computed = (window.getComputedStyle(element).fontSize || '').trim().toLowerCase();
+  const value = computed || DEFAULT_SIZES[element.localName] || '';
+  const size = resolveFontSize(value, parentSize, rootSize, window);
+
+  cache.set(element, size);
+  return size;
+}
+
+/**
+ * Resolve an element's numeric font weight, following bolder, lighter and inheritance
+ * @param element Element to measure
+ * @param window Browser window
+ * @param cache Weights already resolved in this scan
+ * @returns Weight from 1 to 1000
+ */
+export function fontWeightValue(element: Element, window: Window, cache = new WeakMap<Element, number>()): number {
+  const cached = cache.get(element);
+  if (cached !== undefined) return cached;
+
+  const parent = element.parentElement;
+  const parentWeight = () => (parent ? fontWeightValue(parent, window, cache) : 400);
+  const computed = (window.getComputedStyle(element).fontWeight || '').trim().toLowerCase();
+
+  let weight: number;
+  if (!computed || computed === 'inherit' || computed === 'unset') {
+    weight = BOLD_ELEMENTS.includes(element.localName) ? 700 : parentWeight();
+  } else if (computed === 'normal' || computed === 'initial') {
+    weight = 400;
+  } else if (computed === 'bold') {
+    weight = 700;
+  } else if (computed === 'bolder') {
+    weight = bolder(parentWeight());
+  } else if (computed === 'lighter') {
+    weight = lighter(parentWeight());
+  } else {
+    const numeric = Number(computed);
+    weight = numeric >= 1 && numeric <= 1000 ? numeric : parentWeight();
+  }
+
+  cache.set(element, weight);
+  return weight;
+}
+
+/**
+ * Whether text counts as large for WCAG contrast: at least 18pt, or 14pt and bold
+ * @param fontSize Font size in CSS pixels
+ * @param fontWeight Numeric font weight
+ */
+export function isLargeText(fontSize: number, fontWeight: number): boolean {
+  // Round to avoid 14pt coming back as 13.999999pt after the px conversion
+  const points = Math.round(fontSize * 0.75 * 100) / 100;
+  return points >= 18 || (points >= 14 && fontWeight >= 700);
+}
+
+function resolveFontSize(
+  value: string,
+  parentSize: () => number,
+  rootSize: () => number,
+  window: Window,
+): number {
+  if (!value || value === 'inherit' || value === 'unset') return parentSize();
+  if (value === 'initial') return DEFAULT_FONT_SIZE;
+  if (Object.prototype.hasOwnProperty.call(ABSOLUTE_SIZES, value)) return ABSOLUTE_SIZES[value];
+  if (value === 'smaller') return parentSize() / 1.2;
+  if (value === 'larger') return parentSize() * 1.2;
+
+  const match = value.match(/^([+-]?(?:\d+\.?\d*|\.\d+))([a-z%]*)$/);
+  if (!match) return parentSize();
+
+  const number = parseFloat(match[1]);
+  const unit = match[2];
+  if (number < 0) return parentSize();
+
+  switch (unit) {
+    case '%':
+      return (parentSize() * number) / 100;
+    case 'em':
+      return parentSize() * number;
+    case 'ex':
+    case 'ch':
+      // Without font metrics, both are close to half an em
+      return parentSize() * number * 0.5;
+    case 'rem':
+      return rootSize() * number;
+    case 'vw':
+      return (window.innerWidth * number) / 100;
+    case 'vh':
+      return (window.innerHeight * number) / 100;
+    case '':
+      return number === 0 ? 0 : parentSize();
+    default:
+      return Object.prototype.hasOwnProperty.call(PIXELS_PER_UNIT, unit)
+        ? number * PIXELS_PER_UNIT[unit]
+        : parentSize();
+  }
+}
+
+// Relative weights from the CSS Fonts Level 4 table
+function bolder(weight: number): number {
+  if (weight < 350) return 400;
+  if (weight < 550) return 700;
+  return 900;
+}
+
+function lighter(weight: number): number {
+  if (weight < 100) return weight;
+  if (weight < 550) return 100;
+  if (weight < 750) return 400;
+  return 700;
+}
@@ -4,11 +4,12 @@ import { createRoot, Root } from 'react-dom/client';
 import { act } from 'react-dom/test-utils';
 import { WcagDevOverlay } from '../src/react/WcagDevOverlay';
 import type { BrowserScanResults } from '../src/react/browserScanner';
+import type { ScannerOptions } from '../src/types';
 
-const mockScanBrowserPage = jest.fn<Promise<BrowserScanResults>, []>();
+const mockScanBrowserPage = jest.fn<Promise<BrowserScanResults>, [ScannerOptions?]>();
 
 jest.mock('../src/react/browserScanner', () => ({
-  scanBrowserPage: () => mockScanBrowserPage(),
+  scanBrowserPage: (options?: ScannerOptions) => mockScanBrowserPage(options),
 }));
 
 jest.mock('../src/react/gemini', () => ({
@@ -105,7 +106,9 @@ describe('WcagDevOverlay', () => {
           domElement: target!,
         }],
         warnings: [],
+        incomplete: [],
         passes: [],
+        errors: [],
         duration: 10,
       })
       .mockResolvedValueOnce({
@@ -116,7 +119,9 @@ describe('WcagDevOverlay', () => {
           description: 'Potential decorative image',
           domElement: target!,
         }],
+        incomplete: [],
         passes: [],
+        errors: [],
         duration: 8,
       });
 
@@ -152,7 +157,9 @@ describe('WcagDevOverlay', () => {
         domElement: target!,
       }],
       warnings: [],
+      incomplete: [],
       passes: [],
+      errors: [],
       duration: 12,
     });
 
@@ -186,18 +193,112 @@ describe('WcagDevOverlay', () => {
     expect(target?.scrollIntoView).toHaveBeenCalled();
   });
 
+  it('lists results that need review with their reason and instructions', async () => {
+    mockScanBrowserPage.mockResolvedValue({
+      violations: [],
+      warnings: [],
+      incomplete: [{
+        rule: 'focus-visible',
+        impact: 'moderate',
+        description: 'Element removes the focus outline',
+        reason: 'Another focus indicator may replace the outline',
+        review: 'Tab to the element and check that a visible focus indicator appears',
+        domElement: target!,
+      }],
+      passes: [],
+      errors: [],
+      duration: 7,
+    });
+
+    await act(async () => {
+      root!.render(<WcagDevOverlay />);
+    });
+    await nextTick();
+
+    const reviewTab = Array.from(dom!.window.document.querySelectorAll('button'))
+      .find(button => button.textContent === 'Needs review (1)');
+    expect(reviewTab).toBeDefined();
+
+    await act(async () => {
+      reviewTab!.dispatchEvent(new dom!.window.MouseEvent('click', { bubbles: true }));
+    });
+    await nextTick();
+
+    const issueToggle = Array.from(dom!.window.document.querySelectorAll('button'))
+      .find(button => button.textContent === '▼');
+    await act(async () => {
+      issueToggle!.dispatchEvent(new dom!.window.MouseEvent('click', { bubbles: true }));
+    });
+    await nextTick();
+
+    const text = dom!.window.document.body.textContent || '';
+    expect(text).toContain('Element removes the focus outline');
+    expect(text).toContain('Another focus indicator may replace the outline');
+    expect(text).toContain('Tab to the element and check that a visible focus indicator appears');
+  });
+
+  it('shows a scan incomplete banner when a rule threw', async () => {
+    mockScanBrowserPage.mockResolvedValue({
+      violations: [],
+      warnings: [],
+      incomplete: [],
+      passes: [],
+      errors: [{ rule: 'contrast', message: 'Cannot read color' }],
+      duration: 4,
+    });
+
+    await act(async () => {
+      root!.render(<WcagDevOverlay />);
+    });
+    await nextTick();
+
+    const alert = dom!.window.document.querySelector('[role="alert"]');
+    expect(alert?.textContent).toContain('Scan incomplete: 1 rule(s) failed (contrast)');
+    expect(alert?.textContent).toContain('contrast: Cannot read color');
+    expect(mockScanBrowserPage).toHaveBeenLastCalledWith(expect.objectContaining({ failOnRuleError: false }));
+  });
+
+  it('does not rescan when the parent re-renders with equal inline options', async () => {
+    mockScanBrowserPage.mockResolvedValue({
+      violations: [],
+      warnings: [],
+      incomplete: [],
+      passes: [],
+      errors: [],
+      duration: 3,
+    });
+
+    for (let render = 0; render < 3; render++) {
+      await act(async () => {
+        root!.render(<WcagDevOverlay options={{ standard: 'wcag22' }} rules={['images']} />);
+      });
+      await nextTick();
+    }
+    expect(mockScanBrowserPage).toHaveBeenCalledTimes(1);
+
+    await act(async () => {
+      root!.render(<WcagDevOverlay options={{ standard: 'wcag21' }} rules={['images']} />);
+    });
+    await nextTick();
+    expect(mockScanBrowserPage).toHaveBeenCalledTimes(2);
+  });
+
   it('lets the user switch scan presets from settings', async () => {
     mockScanBrowserPage
       .mockResolvedValueOnce({
         violations: [],
         warnings: [],
+        incomplete: [],
         passes: [],
+        errors: [],
         duration: 5,
       })
       .mockResolvedValueOnce({
         violations: [],
         warnings: [],
+        incomplete: [],
         passes: [],
+        errors: [],
         duration: 6,
       });
 
@@ -0,0 +1,104 @@
+import { JSDOM } from 'jsdom';
+import { accessibleDescription, accessibleName, computeAccessibleName, isHidden } from '../src/utils/accname';
+
+describe('accessible name computation', () => {
+  const createDocument = (html: string): Document => new JSDOM(html).window.document;
+  const nameOf = (html: string, selector = '#target') => {
+    const document = createDocument(html);
+    return computeAccessibleName(document.querySelector(selector)!);
+  };
+
+  it('should follow aria-labelledby in order, skipping missing ids', () => {
+    expect(nameOf('<span id="a">Billing</span><span id="b">address</span><input id="target" aria-labelledby="b missing a">'))
+      .toEqual({ name: 'address Billing', source: 'aria-labelledby' });
+  });
+
+  it('should fall back when aria-labelledby only points at missing or empty elements', () => {
+    expect(nameOf('<span id="empty"> </span><input id="target" aria-labelledby="empty missing">'))
+      .toEqual({ name: '', source: 'none' });
+    expect(nameOf('<input id="target" aria-labelledby="missing" aria-label="Search">'))
+      .toEqual({ name: 'Search', source: 'aria-label' });
+  });
+
+  it('should use hidden elements referenced by aria-labelledby, but not hidden descendants otherwise', () => {
+    expect(nameOf('<div id="l" hidden>Secret label</div><button id="target" aria-labelledby="l">x</button>').name)
+      .toBe('Secret label');
+    expect(nameOf('<div id="l">Visible <span aria-hidden="true">icon</span></div><button id="target" aria-labelledby="l">x</button>').name)
+      .toBe('Visible');
+  });
+
+  it('should not follow aria-labelledby inside a referenced element', () => {
+    expect(nameOf('<span id="a" aria-labelledby="b">Alpha</span><span id="b">Beta</span><input id="target" aria-labelledby="a">').name)
+      .toBe('Alpha');
+  });
+
+  it('should use label elements for form controls', () => {
+    expect(nameOf('<label for="target">Email</label><input id="target">'))
+      .toEqual({ name: 'Email', source: 'native' });
+    expect(nameOf('<label>Name <input id="target"></label>').name).toBe('Name');
+    expect(nameOf('<label for="target">  </label><input id="target">').name).toBe('');
+  });
+
+  it('should not look up labels again for controls inside a label', () => {
+    const html = '<label><input id="other" type="checkbox"> Other: <input id="target" type="text"></label>';
+    expect(nameOf(html).name).toBe('Other:');
+    expect(nameOf(html, '#other').name).toBe('Other:');
+  });
+
+  it('should include the values of embedded controls', () => {
+    const html = `
+      <label for="target">Flash the screen
+        <select><option>1</option><option selected>3</option></select> times
+      </label>
+      <input id="target" type="checkbox">
+    `;
+    expect(nameOf(html).name).toBe('Flash the screen 3 times');
+    expect(nameOf('<div id="target" role="button">Volume <span role="slider" aria-valuenow="5" aria-valuetext="loud"></span></div>').name)
+      .toBe('Volume loud');
+  });
+
+  it('should compute names from content for roles that allow it', () => {
+    expect(nameOf('<a id="target" href="/">Home <img src="i.png" alt="page"></a>'))
+      .toEqual({ name: 'Home page', source: 'contents' });
+    expect(nameOf('<button id="target"><span hidden>Hidden</span>Save</button>').name).toBe('Save');
+    expect(nameOf('<nav id="target">Links</nav>').name).toBe('');
+  });
+
+  it('should separate block-level content with spaces', () => {
+    expect(nameOf('<a id="target" href="/"><div>Read</div><div>more</div></a>').name).toBe('Read more');
+  });
+
+  it('should use host language labels', () => {
+    expect(nameOf('<img id="target" src="a.png" alt="Logo">').name).toBe('Logo');
+    expect(nameOf('<fieldset id="target"><legend>Shipping</legend></fieldset>').name).toBe('Shipping');
+    expect(nameOf('<table id="target"><caption>Prices</caption></table>').name).toBe('Prices');
+    expect(nameOf('<svg id="target"><title>Chart</title></svg>').name).toBe('Chart');
+    expect(nameOf('<input id="target" type="submit">').name).toBe('Submit');
+  });
+
+  it('should fall back to title and then placeholder', () => {
+    expect(nameOf('<input id="target" title="Tip" placeholder="Hint">')).toEqual({ name: 'Tip', source: 'title' });
+    expect(nameOf('<input id="target" placeholder="Hint">')).toEqual({ name: 'Hint', source: 'placeholder' });
+  });
+
+  it('should return an empty name for hidden elements', () => {
+    const document = createDocument('<div style="display: none"><button id="target">Go</button></div>');
+    const button = document.querySelector('#target')!;
+
+    expect(isHidden(button)).toBe(true);
+    expect(accessibleName(button)).toBe('');
+  });
+
+  it('should compute descriptions from aria-describedby or an unused title', () => {
+    const document = createDocument(`
+      <p id="hint">Use 8 or more characters</p>
+      <input id="password" aria-label="Password" aria-describedby="hint">
+      <input id="titled" aria-label="Code" title="Six digits">
+      <input id="title-only" title="Only a title">
+    `);
+
+    expect(accessibleDescription(document.querySelector('#password')!)).toBe('Use 8 or more characters');
+    expect(accessibleDescription(document.querySelector('#titled')!)).toBe('Six digits');
+    expect(accessibleDescription(document.querySelector('#title-only')!)).toBe('');
+  });
+});
@@ -45,6 +45,28 @@ describe('ARIA Rule', () => {
       expect(violation).toBeDefined();
     });
 
+    it('should accept fallback role lists and resolve them to the first valid role', async () => {
+      const html = '<div role="switch checkbox" aria-checked="true">Dark mode</div><div role="foo bar">Text</div>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      const violations = results.violations.filter(v => v.rule === 'aria-role-valid');
+      expect(violations.map(v => v.description)).toEqual(['Invalid ARIA role: "foo bar"']);
+      expect(results.passes.find(p => p.rule === 'aria-role-valid')?.element.role).toBe('switch');
+      expect(results.violations.find(v => v.rule === 'aria-required-attr')).toBeUndefined();
+    });
+
+    it('should flag abstract roles', async () => {
+      const html = '<div role="widget">text</div>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      const violation = results.violations.find(v => v.rule === 'aria-role-valid');
+      expect(violation?.description).toBe('Abstract ARIA role used in content: "widget"');
+    });
+
+    it('should warn about deprecated roles', async () => {
+      const html = '<div role="directory"><div role="listitem">Item</div></div>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.warnings.find(w => w.rule === 'aria-deprecated-role')).toBeDefined();
+    });
+
     it('should flag role=link on anchor as incompatible', async () => {
       const html = '<a href="#" role="link">link</a>';
       const results = await ariaRule.check(createDoc(html), createWin(html), {});
@@ -58,13 +80,11 @@ describe('ARIA Rule', () => {
       const html = '<div role="slider">slider</div>';
       const results = await ariaRule.check(createDoc(html), createWin(html), {});
       const violations = results.violations.filter(v => v.rule === 'aria-required-attr');
-      expect(violations.some(v => v.description.includes('aria-valuemin'))).toBe(true);
-      expect(violations.some(v => v.description.includes('aria-valuemax'))).toBe(true);
-      expect(violations.some(v => v.description.includes('aria-valuenow'))).toBe(true);
+      expect(violations.map(v => v.description)).toEqual(['Element with role="slider" is missing required attribute: aria-valuenow']);
     });
 
     it('should pass slider with all required attributes', async () => {
-      const html = '<div role="slider" aria-valuemin="0" aria-valuemax="100" aria-valuenow="50">slider</div>';
+      const html = '<div role="slider" aria-valuenow="50">slider</div>';
       const results = await ariaRule.check(createDoc(html), createWin(html), {});
       const violations = results.violations.filter(v => v.rule === 'aria-required-attr');
       expect(violations).toHaveLength(0);
@@ -77,18 +97,27 @@ describe('ARIA Rule', () => {
       expect(violations.length).toBeGreaterThan(0);
     });
 
-    it('should detect missing required attributes on progressbar', async () => {
-      const html = '<div role="progressbar">loading</div>';
+    it('should not require value attributes on progressbar or spinbutton', async () => {
+      const html = '<div role="progressbar" aria-valuenow="50">loading</div><div role="progressbar">busy</div>' +
+        '<div role="spinbutton">3</div>';
       const results = await ariaRule.check(createDoc(html), createWin(html), {});
       const violations = results.violations.filter(v => v.rule === 'aria-required-attr');
-      expect(violations.some(v => v.description.includes('aria-valuenow'))).toBe(true);
+      expect(violations).toHaveLength(0);
     });
 
     it('should detect missing required attributes on scrollbar', async () => {
       const html = '<div role="scrollbar">scroll</div>';
       const results = await ariaRule.check(createDoc(html), createWin(html), {});
       const violations = results.violations.filter(v => v.rule === 'aria-required-attr');
-      expect(violations.some(v => v.description.includes('aria-controls'))).toBe(true);
+      expect(violations.map(v => v.description.split(': ')[1])).toEqual(['aria-controls', 'aria-valuenow']);
+    });
+
+    it('should require aria-checked on custom checkboxes but not native ones', async () => {
+      const html = '<div role="checkbox">Subscribe</div><input type="checkbox" role="switch">';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      const violations = results.violations.filter(v => v.rule === 'aria-required-attr');
+      expect(violations).toHaveLength(1);
+      expect(violations[0].element.role).toBe('checkbox');
     });
   });
 
@@ -136,6 +165,121 @@ describe('ARIA Rule', () => {
       const violation = results.violations.find(v => v.rule === 'aria-boolean-value');
       expect(violation).toBeDefined();
     });
+
+    it('should accept "mixed" for tristate attributes', async () => {
+      const html = '<div role="checkbox" aria-checked="mixed">All</div><button aria-pressed="mixed">Bold</button>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.violations.find(v => v.rule === 'aria-boolean-value')).toBeUndefined();
+    });
+
+    it('should detect invalid token and number values', async () => {
+      const html = `
+        <a href="/" aria-current="yes">Home</a>
+        <div aria-live="polite" aria-relevant="additions text">Log</div>
+        <div role="heading" aria-level="two">Title</div>
+        <div role="slider" aria-valuemin="0" aria-valuemax="1" aria-valuenow="0.5">Volume</div>
+      `;
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      const violations = results.violations.filter(v => v.rule === 'aria-valid-attr-value');
+      expect(violations.map(v => v.element.attrName)).toEqual(['aria-current', 'aria-level']);
+      expect(violations[0].help).toContain('"page"');
+    });
+
+    it('should ignore empty values', async () => {
+      const html = '<a href="/" aria-current="">Home</a>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.violations.find(v => v.rule === 'aria-valid-attr-value')).toBeUndefined();
+    });
+
+    it('should detect attributes prohibited on the role', async () => {
+      const html = '<span aria-label="Warning">!</span><div role="presentation" aria-labelledby="x"></div>' +
+        '<div role="none" aria-label="x"></div><nav aria-label="Main"></nav>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      const violations = results.violations.filter(v => v.rule === 'aria-prohibited-attr');
+      expect(violations.map(v => v.element.role)).toEqual(['generic', 'presentation', 'none']);
+    });
+
+    it('should warn about deprecated attributes', async () => {
+      const html = `
+        <div aria-grabbed="false">Drag me</div>
+        <div aria-disabled="true">Panel</div>
+        <button aria-disabled="true">Save</button>
+      `;
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      const warnings = results.warnings.filter(w => w.rule === 'aria-deprecated-attr');
+      expect(warnings.map(w => w.description)).toEqual([
+        'ARIA attribute "aria-grabbed" is deprecated in WAI-ARIA 1.2',
+        'ARIA attribute "aria-disabled" is deprecated on role "generic"',
+      ]);
+    });
+
+    it('should accept form control states on native inputs without a role', async () => {
+      const html = `
+        <input type="password" aria-invalid="true" aria-required="true" aria-errormessage="err">
+        <input type="date" aria-disabled="true"><span id="err">Too short</span>
+      `;
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.warnings.filter(w => w.rule === 'aria-deprecated-attr')).toHaveLength(0);
+    });
+  });
+
+  describe('checkRequiredOwnedElements', () => {
+    it('should detect a list without list items', async () => {
+      const html = '<div role="list"><div>One</div><div>Two</div></div>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      const violation = results.violations.find(v => v.rule === 'aria-required-children');
+      expect(violation?.description).toBe('Element with role="list" does not contain any elements with role listitem');
+    });
+
+    it('should look through wrappers, groups and aria-owns', async () => {
+      const html = `
+        <div role="list"><div><span role="listitem">One</span></div></div>
+        <div role="listbox"><div role="group"><div role="option">A</div></div></div>
+        <div role="tablist" aria-owns="tab1"></div><div role="tab" id="tab1">Tab</div>
+        <ul role="list"><li>Native</li></ul>
+      `;
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.violations.find(v => v.rule === 'aria-required-children')).toBeUndefined();
+      expect(results.passes.filter(p => p.rule === 'aria-required-children')).toHaveLength(4);
+    });
+
+    it('should use the first role token', async () => {
+      const html = '<div role="list presentation"><div>One</div></div><div role=" list"><div>Two</div></div>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.violations.filter(v => v.rule === 'aria-required-children')).toHaveLength(2);
+    });
+
+    it('should skip empty and busy containers', async () => {
+      const html = '<div role="list"></div><div role="tree" aria-busy="true"><div>Loading</div></div>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.violations.find(v => v.rule === 'aria-required-children')).toBeUndefined();
+    });
+  });
+
+  describe('checkRequiredContext', () => {
+    it('should detect list items and tabs outside their container', async () => {
+      const html = '<div><div role="listitem">Orphan</div></div><div role="tab">Tab</div>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      const violations = results.violations.filter(v => v.rule === 'aria-required-parent');
+      expect(violations.map(v => v.element.role)).toEqual(['listitem', 'tab']);
+      expect(violations[1].description).toBe('Element with role="tab" is not inside an element with role tablist');
+    });
+
+    it('should use the first role token', async () => {
+      const html = '<div role="listitem presentation">Orphan</div>';
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.violations.filter(v => v.rule === 'aria-required-parent')).toHaveLength(1);
+    });
+
+    it('should accept native and owning containers', async () => {
+      const html = `
+        <ul><li role="listitem">One</li></ul>
+        <div role="table"><div role="rowgroup"><div role="row"><span role="cell">A</span></div></div></div>
+        <div role="menu" aria-owns="item"></div><div role="menuitem" id="item">Open</div>
+      `;
+      const results = await ariaRule.check(createDoc(html), createWin(html), {});
+      expect(results.violations.find(v => v.rule === 'aria-required-parent')).toBeUndefined();
+    });
   });
 
   describe('checkNativeSemantics', () => {
@@ -4,7 +4,7 @@ import backgroundImagesRule from '../src/rules/backgroundImages';
 describe('Background Images Rule', () => {
   const createDom = (html: string) => new JSDOM(html, { pretendToBeVisual: true });
 
-  it('should warn when a meaningful background image lacks an alternative', async () => {
+  it('should ask for review when a meaningful background image lacks an alternative', async () => {
     const dom = createDom(`
       <html>
         <body>
@@ -14,7 +14,7 @@ describe('Background Images Rule', () => {
     `);
 
     const results = await backgroundImagesRule.check(dom.window.document, dom.window as unknown as Window, { level: 'AA' });
-    expect(results.warnings.some(w => w.rule === 'background-image')).toBe(true);
+    expect(results.incomplete.some(i => i.rule === 'background-image')).toBe(true);
   });
 
   it('should skip decorative background containers', async () => {
@@ -27,7 +27,7 @@ describe('Background Images Rule', () => {
     `);
 
     const results = await backgroundImagesRule.check(dom.window.document, dom.window as unknown as Window, { level: 'AA' });
-    expect(results.warnings.some(w => w.rule === 'background-image')).toBe(false);
+    expect(results.incomplete.some(i => i.rule === 'background-image')).toBe(false);
   });
 
   it('should skip hidden and aria-hidden elements', async () => {
@@ -41,7 +41,7 @@ describe('Background Images Rule', () => {
     `);
 
     const results = await backgroundImagesRule.check(dom.window.document, dom.window as unknown as Window, { level: 'AA' });
-    expect(results.warnings.some(w => w.rule === 'background-image')).toBe(false);
+    expect(results.incomplete.some(i => i.rule === 'background-image')).toBe(false);
   });
 
   it('should skip elements with background images when they already have text alternatives', async () => {
@@ -55,7 +55,7 @@ describe('Background Images Rule', () => {
     `);
 
     const results = await backgroundImagesRule.check(dom.window.document, dom.window as unknown as Window, { level: 'AA' });
-    expect(results.warnings.some(w => w.rule === 'background-image')).toBe(false);
+    expect(results.incomplete.some(i => i.rule === 'background-image')).toBe(false);
   });
 
   it('should ignore non-inspectable elements and non-url backgrounds', async () => {
@@ -69,7 +69,7 @@ describe('Background Images Rule', () => {
     `);
 
     const results = await backgroundImagesRule.check(dom.window.document, dom.window as unknown as Window, { level: 'AA' });
-    expect(results.warnings.some(w => w.rule === 'background-image')).toBe(false);
+    expect(results.incomplete.some(i => i.rule === 'background-image')).toBe(false);
   });
 
   it('should skip elements hidden via CSS visibility/display', async () => {
@@ -83,7 +83,7 @@ describe('Background Images Rule', () => {
     `);
 
     const results = await backgroundImagesRule.check(dom.window.document, dom.window as unknown as Window, { level: 'AA' });
-    expect(results.warnings.some(w => w.rule === 'background-image')).toBe(false);
+    expect(results.incomplete.some(i => i.rule === 'background-image')).toBe(false);
   });
 
   it('should skip elements whose own text content makes the image non-actionable', async () => {
@@ -96,6 +96,6 @@ describe('Background Images Rule', () => {
     `);
 
     const results = await backgroundImagesRule.check(dom.window.document, dom.window as unknown as Window, { level: 'AA' });
-    expect(results.warnings.some(w => w.rule === 'background-image')).toBe(false);
+    expect(results.incomplete.some(i => i.rule === 'background-image')).toBe(false);
   });
 });
@@ -0,0 +1,142 @@
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { pathToFileURL } from 'url';
+import {
+  baselinePage,
+  compareWithBaseline,
+  createBaseline,
+  filterNewViolations,
+  fingerprint,
+  loadBaseline,
+  normalizeSnippet,
+  writeBaseline,
+} from '../src/baseline';
+import { formatReport, scanFile } from '../src/index';
+import { ScanResults, Violation } from '../src/types';
+
+const violation = (rule: string, snippet: string, id?: string): Violation => ({
+  rule,
+  impact: 'serious',
+  description: `${rule} failed`,
+  snippet,
+  element: { tagName: 'IMG', id },
+});
+
+const resultsWith = (...violations: Violation[]): ScanResults => ({ passes: [], violations, warnings: [], incomplete: [] });
+
+describe('baseline', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wcag-baseline-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  describe('fingerprint', () => {
+    it('should ignore whitespace and truncation differences in snippets', () => {
+      expect(normalizeSnippet('<div>\n  <img src="a.png">\n</div>...')).toBe('<div><img src="a.png"></div>');
+      expect(fingerprint(violation('img-alt', '<img  src="a.png">'))).toBe(fingerprint(violation('img-alt', '<img src="a.png">')));
+    });
+
+    it('should change with the rule, selector or snippet', () => {
+      const base = fingerprint(violation('img-alt', '<img src="a.png">'));
+      expect(fingerprint(violation('img-alt-generic', '<img src="a.png">'))).not.toBe(base);
+      expect(fingerprint(violation('img-alt', '<img src="a.png">', 'logo'))).not.toBe(base);
+      expect(fingerprint(violation('img-alt', '<img src="b.png">'))).not.toBe(base);
+    });
+  });
+
+  describe('baselinePage', () => {
+    it('should store local files relative to the baseline file', () => {
+      expect(baselinePage(pathToFileURL(path.join(dir, 'pages', 'a.html')).href, path.join(dir, 'baseline.json'))).toBe('pages/a.html');
+      expect(baselinePage(pathToFileURL(path.join(dir, 'my pages', 'a#1?.html')).href, path.join(dir, 'baseline.json')))
+        .toBe('my pages/a#1?.html');
+      expect(baselinePage('https://example.org', path.join(dir, 'baseline.json'))).toBe('https://example.org/');
+    });
+  });
+
+  describe('compareWithBaseline', () => {
+    const a = violation('img-alt', '<img src="a.png">');
+    const b = violation('img-alt', '<img src="b.png">');
+    const c = violation('img-alt', '<img src="c.png">');
+    const baseline = createBaseline([
+      { page: 'index.html', results: resultsWith(a, b, b) },
+      { page: 'other.html', results: resultsWith(c) },
+    ]);
+
+    it('should split violations into new, existing and fixed', () => {
+      const compared = compareWithBaseline(resultsWith(b, c), baseline, 'index.html', 'baseline.json');
+
+      expect(compared.violations.map(v => v.baselineStatus)).toEqual(['existing', 'new']);
+      expect(compared.baseline).toMatchObject({ file: 'baseline.json', new: 1, existing: 1 });
+      expect(compared.baseline?.fixed.map(entry => entry.snippet)).toEqual(['<img src="a.png">', '<img src="b.png">']);
+    });
+
+    it('should count repeated violations beyond the baseline as new', () => {
+      const compared = compareWithBaseline(resultsWith(a, a), baseline, 'index.html');
+      expect(compared.violations.map(v => v.baselineStatus)).toEqual(['existing', 'new']);
+    });
+
+    it('should leave only new violations when filtering', () => {
+      const compared = compareWithBaseline(resultsWith(a, c), baseline, 'index.html');
+      expect(filterNewViolations(compared).violations).toEqual([expect.objectContaining({ snippet: '<img src="c.png">' })]);
+      expect(filterNewViolations(resultsWith(a)).violations).toHaveLength(1);
+    });
+  });
+
+  describe('files', () => {
+    it('should round-trip a baseline file', () => {
+      const filePath = path.join(dir, 'baseline.json');
+      writeBaseline(filePath, [{ page: 'index.html', results: resultsWith(violation('img-alt', '<img>')) }]);
+
+      const loaded = loadBaseline(filePath);
+      expect(loaded.version).toBe(1);
+      expect(loaded.entries).toHaveLength(1);
+      expect(loaded.entries[0]).toMatchObject({ page: 'index.html', rule: 'img-alt', selector: 'img' });
+    });
+
+    it('should reject missing or malformed baselines', () => {
+      fs.writeFileSync(path.join(dir, 'bad.json'), '{"entries": {}}');
+
+      expect(() => loadBaseline(path.join(dir, 'missing.json'))).toThrow('Baseline file not found');
+      expect(() => loadBaseline(path.join(dir, 'bad.json'))).toThrow('not a version 1');
+    });
+
+    it('should compare scans against a baseline and report only new violations', async () => {
+      const page = path.join(dir, 'index.html');
+      const baselinePath = path.join(dir, 'baseline.json');
+      fs.writeFileSync(page, '<html lang="en"><head><title>T</title></head><body><img src="a.png"></body></html>');
+
+      const before = await scanFile(page, { rules: ['images'], config: false });
+      writeBaseline(baselinePath, [{ page: 'index.html', results: before }]);
+
+      fs.writeFileSync(page, '<html lang="en"><head><title>T</title></head><body><img src="a.png"><img src="b.png"></body></html>');
+      const after = await scanFile(page, { rules: ['images'], baseline: baselinePath, config: false });
+
+      expect(after.baseline).toMatchObject({ new: 1, existing: 1, fixed: [] });
+
+      const report = JSON.parse(formatReport(after, 'json', { onlyNewViolations: true }));
+      expect(report.summary.violations).toBe(1);
+      expect(report.summary.baseline).toMatchObject({ new: 1, existing: 1, fixed: 0, onlyNewViolations: true });
+      expect(report.violations[0].baselineStatus).toBe('new');
+      expect(report.violations[0].snippet).toContain('b.png');
+    });
+
+    it('should match pages whose file names need escaping in a URL', async () => {
+      const page = path.join(dir, 'my page #1.html');
+      const baselinePath = path.join(dir, 'baseline.json');
+      fs.writeFileSync(page, '<html lang="en"><head><title>T</title></head><body><img src="a.png"></body></html>');
+
+      const before = await scanFile(page, { rules: ['images'], config: false });
+      expect(before.url).toBe(pathToFileURL(page).href);
+      writeBaseline(baselinePath, [{ page: 'my page #1.html', results: before }]);
+
+      const after = await scanFile(page, { rules: ['images'], baseline: baselinePath, config: false });
+      expect(after.baseline).toMatchObject({ new: 0, existing: 1 });
+    });
+  });
+});
@@ -1,5 +1,7 @@
 import { JSDOM } from 'jsdom';
 import { scanBrowserPage, getElementPath, getNthChildSelector, findBySnippet, findElement } from '../src/react/browserScanner';
+import { RuleFailureError } from '../src/ruleErrors';
+import imagesRule from '../src/rules/images';
 
 describe('browserScanner', () => {
   const originalWindow = global.window;
@@ -48,6 +50,77 @@ describe('browserScanner', () => {
     expect(results.violations[0].element?.id).toBe('page-image');
   });
 
+  it('should apply rule overrides', async () => {
+    installDom(`
+      <html>
+        <body>
+          <img id="page-image" src="page.jpg">
+        </body>
+      </html>
+    `);
+
+    const results = await scanBrowserPage({ rules: ['images'], ruleOverrides: { 'img-alt': { type: 'warning' } } });
+
+    expect(results.violations.some(v => v.rule === 'img-alt')).toBe(false);
+    expect(results.warnings.find(w => w.rule === 'img-alt')?.domElement?.id).toBe('page-image');
+  });
+
+  it('should report rules that throw instead of skipping them silently', async () => {
+    installDom('<html><body><img src="page.jpg"><input type="text"></body></html>');
+    const spy = jest.spyOn(imagesRule, 'check').mockRejectedValue(new Error('images rule crashed'));
+
+    try {
+      const results = await scanBrowserPage({ rules: ['images', 'forms'] });
+
+      expect(results.errors).toEqual([{ rule: 'images', message: 'images rule crashed', stack: expect.any(String) }]);
+      expect(results.violations.some(v => v.rule === 'form-label')).toBe(true);
+      await expect(scanBrowserPage({ rules: ['images'], failOnRuleError: true })).rejects.toThrow(RuleFailureError);
+    } finally {
+      spy.mockRestore();
+    }
+  });
+
+  it('should honor suppression comments and attributes', async () => {
+    installDom(`
+      <html>
+        <body>
+          <!-- wcag-scanner-disable-next-line img-alt -->
+          <img id="hidden-image" src="hidden.jpg">
+          <div data-wcag-ignore><img id="ignored-image" src="ignored.jpg"></div>
+          <img id="page-image" src="page.jpg">
+        </body>
+      </html>
+    `);
+
+    const results = await scanBrowserPage({ rules: ['images'] });
+
+    expect(results.violations.map(v => v.element?.id)).toEqual(['page-image']);
+    expect(results.suppressed?.filter(s => s.type === 'violation').map(s => [s.element?.id, s.suppressedBy])).toEqual([
+      ['hidden-image', 'comment'],
+      ['ignored-image', 'attribute'],
+    ]);
+  });
+
+  it('should keep identical issues on different elements apart', async () => {
+    installDom(`
+      <html>
+        <body>
+          <img src="same.jpg">
+          <img src="same.jpg">
+        </body>
+      </html>
+    `);
+
+    const results = await scanBrowserPage({ rules: ['images'] });
+    const missingAlt = results.violations.filter(v => v.rule === 'img-alt');
+
+    expect(missingAlt.map(v => v.elementSelector)).toEqual([
+      'html > body:nth-child(2) > img:nth-child(1)',
+      'html > body:nth-child(2) > img:nth-child(2)',
+    ]);
+    expect(missingAlt[1].domElement).toBe(document.querySelectorAll('img')[1]);
+  });
+
   it('should build selectors and labels for elements', () => {
     const document = installDom(`
       <html>
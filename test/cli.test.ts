@@ -0,0 +1,276 @@
+import fs from 'fs';
+import http from 'http';
+import { AddressInfo } from 'net';
+import os from 'os';
+import path from 'path';
+import {
+  runCli,
+  parseArgs,
+  expandInputs,
+  globToRegExp,
+  exceedsThreshold,
+  CliUsageError,
+  EXIT_OK,
+  EXIT_VIOLATIONS,
+  EXIT_ERROR,
+} from '../src/cli';
+
+const INACCESSIBLE_HTML = '<!DOCTYPE html><html><head><title>Bad</title></head><body><img src="a.jpg"></body></html>';
+const ACCESSIBLE_HTML = '<!DOCTYPE html><html lang="en"><head><title>Good</title></head><body><main><h1>Hi</h1></main></body></html>';
+
+describe('parseArgs', () => {
+  it('should map flags onto scanner options', () => {
+    const cli = parseArgs([
+      '--level', 'AAA', '-p', 'full', '--rules=images,forms', '-r', 'aria',
+      '--base-url', 'https://example.com', '-v', '-f', 'json', '-o', 'out.json',
+      '--fail-on', 'serious', '--load-stylesheets', 'page.html',
+    ]);
+
+    expect(cli.scanner).toEqual({
+      level: 'AAA',
+      preset: 'full',
+      rules: ['images', 'forms', 'aria'],
+      baseUrl: 'https://example.com',
+      verbose: true,
+      loadStylesheets: true,
+    });
+    expect(cli.format).toBe('json');
+    expect(cli.output).toBe('out.json');
+    expect(cli.failOn).toBe('serious');
+    expect(cli.inputs).toEqual(['page.html']);
+  });
+
+  it('should map --fail-on-rule-error onto the scanner option', () => {
+    expect(parseArgs(['--fail-on-rule-error', 'page.html']).scanner.failOnRuleError).toBe(true);
+    expect(() => parseArgs(['--fail-on-rule-error=yes'])).toThrow('does not take a value');
+  });
+
+  it('should collect repeated --rule-pack flags', () => {
+    expect(parseArgs(['--rule-pack', '@acme/a11y-rules', '--rule-pack=./rules', 'page.html']).scanner.rulePacks)
+      .toEqual(['@acme/a11y-rules', './rules']);
+  });
+
+  it('should use console output and fail on any violation by default', () => {
+    const cli = parseArgs(['page.html']);
+    expect(cli.format).toBe('console');
+    expect(cli.failOn).toBe('minor');
+  });
+
+  it('should reject invalid values and unknown options', () => {
+    expect(() => parseArgs(['--level', 'B'])).toThrow(CliUsageError);
+    expect(() => parseArgs(['--format', 'xml'])).toThrow('Invalid value for --format');
+    expect(() => parseArgs(['--junit-warnings', 'hidden'])).toThrow('Invalid value for --junit-warnings');
+    expect(() => parseArgs(['--markdown-max-length', '0'])).toThrow('Invalid value for --markdown-max-length');
+    expect(() => parseArgs(['--standard', 'wcag3'])).toThrow('Invalid value for --standard');
+    expect(() => parseArgs(['--nope'])).toThrow('Unknown option: --nope');
+    expect(() => parseArgs(['--output'])).toThrow('requires a value');
+    expect(() => parseArgs(['--verbose=yes'])).toThrow('does not take a value');
+  });
+
+  it('should parse request flags for URL inputs', () => {
+    const cli = parseArgs(['-H', 'Authorization: Bearer t', '--cookie', 'a=1', '--cookie=b=2', '--timeout', '500', 'https://example.com']);
+    expect(cli.fetch).toEqual({ headers: { Authorization: 'Bearer t' }, cookies: 'a=1; b=2', timeout: 500 });
+    expect(() => parseArgs(['--header', 'nocolon'])).toThrow(CliUsageError);
+    expect(() => parseArgs(['--timeout', 'soon'])).toThrow(CliUsageError);
+  });
+
+  it('should parse script execution flags', () => {
+    const cli = parseArgs(['--run-scripts', '--wait-for', '#app main', '--settle-timeout', '5000', '--ignore-script-errors', 'page.html']);
+    expect(cli.scanner).toEqual({
+      runScripts: true,
+      settle: { selector: '#app main', timeout: 5000 },
+      ignoreScriptErrors: true,
+    });
+  });
+
+  it('should parse crawl flags', () => {
+    const cli = parseArgs(['--crawl', '--max-pages', '10', '--max-depth=0', 'https://example.com']);
+    expect(cli.crawl).toBe(true);
+    expect(cli.crawlLimits).toEqual({ maxPages: 10, maxDepth: 0 });
+    expect(() => parseArgs(['--max-pages', '0'])).toThrow(CliUsageError);
+  });
+
+  it('should parse baseline flags', () => {
+    const cli = parseArgs(['--baseline', 'base.json', '--update-baseline', '--only-new', 'page.html']);
+    expect(cli.scanner.baseline).toBe('base.json');
+    expect(cli.scanner.onlyNewViolations).toBe(true);
+    expect(cli.updateBaseline).toBe(true);
+  });
+
+  it('should parse config flags', () => {
+    expect(parseArgs(['-c', 'ci.json', 'page.html']).scanner.config).toBe('ci.json');
+    expect(parseArgs(['--no-config', 'page.html']).scanner.config).toBe(false);
+  });
+});
+
+describe('globToRegExp', () => {
+  it('should translate glob syntax', () => {
+    expect(globToRegExp('src/*.html').test('src/index.html')).toBe(true);
+    expect(globToRegExp('src/*.html').test('src/a/index.html')).toBe(false);
+    expect(globToRegExp('src/**/*.html').test('src/index.html')).toBe(true);
+    expect(globToRegExp('src/**/*.html').test('src/a/b/index.html')).toBe(true);
+    expect(globToRegExp('page?.{html,htm}').test('page1.htm')).toBe(true);
+    expect(globToRegExp('page[!0-9].html').test('page1.html')).toBe(false);
+  });
+});
+
+describe('CLI', () => {
+  let dir: string;
+  const stdout: string[] = [];
+  const stderr: string[] = [];
+  const io = {
+    stdout: (text: string) => { stdout.push(text); },
+    stderr: (text: string) => { stderr.push(text); },
+  };
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wcag-cli-'));
+    fs.mkdirSync(path.join(dir, 'pages', 'nested'), { recursive: true });
+    fs.writeFileSync(path.join(dir, 'pages', 'bad.html'), INACCESSIBLE_HTML);
+    fs.writeFileSync(path.join(dir, 'pages', 'nested', 'good.htm'), ACCESSIBLE_HTML);
+    fs.writeFileSync(path.join(dir, 'pages', 'notes.txt'), 'not html');
+    stdout.length = 0;
+    stderr.length = 0;
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should expand directories recursively to html files only', () => {
+    const files = expandInputs(['pages'], dir).map(file => path.relative(dir, file));
+    expect(files).toEqual([path.join('pages', 'bad.html'), path.join('pages', 'nested', 'good.htm')]);
+  });
+
+  it('should expand glob patterns', () => {
+    const files = expandInputs(['pages/**/*.htm'], dir).map(file => path.relative(dir, file));
+    expect(files).toEqual([path.join('pages', 'nested', 'good.htm')]);
+  });
+
+  it('should report missing inputs as usage errors', () => {
+    expect(() => expandInputs(['missing.html'], dir)).toThrow('No such file or directory');
+  });
+
+  it('should exit non-zero when violations meet the threshold', async () => {
+    const code = await runCli(['--rules', 'images', 'pages/bad.html'], io, dir);
+    expect(code).toBe(EXIT_VIOLATIONS);
+    expect(stdout.join('')).toContain('Image is missing alt text');
+  });
+
+  it('should exit zero when the threshold is not met', async () => {
+    expect(await runCli(['--rules', 'images', '--fail-on', 'none', 'pages/bad.html'], io, dir)).toBe(EXIT_OK);
+    expect(await runCli(['--rules', 'structure', '--fail-on', 'critical', 'pages/bad.html'], io, dir)).toBe(EXIT_OK);
+  });
+
+  it('should read options from a config file unless --no-config is given', async () => {
+    fs.writeFileSync(path.join(dir, '.wcagscannerrc'), JSON.stringify({ rules: ['structure'] }));
+
+    expect(await runCli(['--fail-on', 'critical', 'pages/bad.html'], io, dir)).toBe(EXIT_OK);
+    expect(await runCli(['--no-config', '--rules', 'images', 'pages/bad.html'], io, dir)).toBe(EXIT_VIOLATIONS);
+    expect(await runCli(['-c', '.wcagscannerrc', '--rules', 'images', 'pages/bad.html'], io, dir)).toBe(EXIT_VIOLATIONS);
+  });
+
+  it('should report an invalid config file', async () => {
+    fs.writeFileSync(path.join(dir, 'broken.json'), JSON.stringify({ level: 'B' }));
+
+    expect(await runCli(['--config', 'broken.json', 'pages/bad.html'], io, dir)).toBe(EXIT_ERROR);
+    expect(stderr.join('')).toContain('Invalid wcag-scanner config');
+  });
+
+  it('should fail only on violations missing from the baseline with --only-new', async () => {
+    expect(await runCli(['-r', 'images', '--update-baseline', 'pages'], io, dir)).toBe(EXIT_OK);
+    expect(fs.existsSync(path.join(dir, 'wcag-baseline.json'))).toBe(true);
+    expect(stderr.join('')).toContain('Baseline written');
+
+    const args = ['-r', 'images', '--baseline', 'wcag-baseline.json', '--only-new', 'pages'];
+    expect(await runCli(args, io, dir)).toBe(EXIT_OK);
+
+    fs.writeFileSync(path.join(dir, 'pages', 'nested', 'good.htm'), INACCESSIBLE_HTML);
+    expect(await runCli(args, io, dir)).toBe(EXIT_VIOLATIONS);
+    expect(await runCli(['-r', 'images', '--baseline', 'wcag-baseline.json', 'pages/bad.html'], io, dir)).toBe(EXIT_VIOLATIONS);
+  });
+
+  it('should fetch and scan URL inputs', async () => {
+    let cookie: string | undefined;
+    const server = http.createServer((req, res) => {
+      cookie = req.headers.cookie;
+      res.writeHead(200, { 'content-type': 'text/html' });
+      res.end(INACCESSIBLE_HTML);
+    });
+    await new Promise<void>(resolve => server.listen(0, '127.0.0.1', resolve));
+    const url = `http://127.0.0.1:${(server.address() as AddressInfo).port}/`;
+
+    try {
+      const code = await runCli(['-r', 'images', '-f', 'json', '--cookie', 'session=1', url, 'pages/nested/good.htm'], io, dir);
+      const report = JSON.parse(stdout.join(''));
+
+      expect(code).toBe(EXIT_VIOLATIONS);
+      expect(cookie).toBe('session=1');
+      expect(report.map((entry: { file: string }) => entry.file)).toEqual([path.join('pages', 'nested', 'good.htm'), url]);
+      expect(report[1].summary.violations).toBe(1);
+    } finally {
+      await new Promise(resolve => server.close(resolve));
+    }
+  });
+
+  it('should write a report file in the requested format', async () => {
+    const code = await runCli(['-r', 'images', '-f', 'json', '-o', 'report.json', 'pages/bad.html'], io, dir);
+    const report = JSON.parse(fs.readFileSync(path.join(dir, 'report.json'), 'utf8'));
+
+    expect(code).toBe(EXIT_VIOLATIONS);
+    expect(report.summary.violations).toBe(1);
+    expect(stdout).toHaveLength(0);
+  });
+
+  it('should write SARIF with file locations relative to cwd', async () => {
+    expect(await runCli(['-r', 'images', '-f', 'sarif', 'pages'], io, dir)).toBe(EXIT_VIOLATIONS);
+    const log = JSON.parse(stdout.join(''));
+    const result = log.runs[0].results.find((entry: { ruleId: string }) => entry.ruleId === 'img-alt');
+
+    expect(result.locations[0].physicalLocation.artifactLocation.uri).toBe('pages/bad.html');
+    expect(result.locations[0].physicalLocation.region).toMatchObject({ startLine: 1, startColumn: 59 });
+  });
+
+  it('should write one JUnit test suite per file', async () => {
+    expect(await runCli(['-r', 'images', '-f', 'junit', 'pages'], io, dir)).toBe(EXIT_VIOLATIONS);
+    const output = stdout.join('');
+
+    expect(output).toContain(`<testsuite name="${path.join('pages', 'bad.html')}"`);
+    expect(output).toContain(`<testcase classname="${path.join('pages', 'bad.html')}" name="img-alt">`);
+    expect(output).toContain('<failure message="1 violation(s):');
+  });
+
+  it('should emit one JSON entry per file when scanning several files', async () => {
+    await runCli(['-r', 'images', '-f', 'json', 'pages'], io, dir);
+    const report = JSON.parse(stdout.join(''));
+
+    expect(report).toHaveLength(2);
+    expect(report[0].file).toBe(path.join('pages', 'bad.html'));
+    expect(report[1].summary.violations).toBe(0);
+  });
+
+  it('should print usage for invalid arguments', async () => {
+    expect(await runCli(['--level', 'B', 'pages'], io, dir)).toBe(EXIT_ERROR);
+    expect(stderr.join('')).toContain('Usage: wcag-scanner');
+    expect(await runCli([], io, dir)).toBe(EXIT_ERROR);
+  });
+
+  it('should print help and version', async () => {
+    expect(await runCli(['--help'], io, dir)).toBe(EXIT_OK);
+    expect(await runCli(['--version'], io, dir)).toBe(EXIT_OK);
+    expect(stdout[0]).toContain('Usage: wcag-scanner');
+    expect(stdout[1]).toMatch(/^\d+\.\d+\.\d+\n$/);
+  });
+
+  it('should compare violation impact against the threshold', () => {
+    const results = {
+      passes: [],
+      warnings: [],
+      incomplete: [],
+      violations: [{ rule: 'x', impact: 'moderate' as const, description: 'x' }],
+    };
+    expect(exceedsThreshold(results, 'minor')).toBe(true);
+    expect(exceedsThreshold(results, 'moderate')).toBe(true);
+    expect(exceedsThreshold(results, 'serious')).toBe(false);
+  });
+});
@@ -0,0 +1,92 @@
+import { compositeColors, contrastRatio, formatColor, parseColor, RGBA } from '../src/utils/color';
+
+const rounded = (value: string, currentColor: RGBA | null = null) => {
+  const color = parseColor(value, currentColor);
+  return color && {
+    r: Math.round(color.r),
+    g: Math.round(color.g),
+    b: Math.round(color.b),
+    a: Number(color.a.toFixed(3)),
+  };
+};
+
+describe('color parsing', () => {
+  it('should parse named colors, system colors and transparent', () => {
+    expect(rounded('RebeccaPurple')).toEqual({ r: 102, g: 51, b: 153, a: 1 });
+    expect(rounded('lightgoldenrodyellow')).toEqual({ r: 250, g: 250, b: 210, a: 1 });
+    expect(rounded('CanvasText')).toEqual({ r: 0, g: 0, b: 0, a: 1 });
+    expect(rounded('transparent')).toEqual({ r: 0, g: 0, b: 0, a: 0 });
+  });
+
+  it('should parse 3, 4, 6 and 8 digit hex colors', () => {
+    expect(rounded('#fff')).toEqual({ r: 255, g: 255, b: 255, a: 1 });
+    expect(rounded('#0f08')).toEqual({ r: 0, g: 255, b: 0, a: 0.533 });
+    expect(rounded('#336699')).toEqual({ r: 51, g: 102, b: 153, a: 1 });
+    expect(rounded('#33669980')).toEqual({ r: 51, g: 102, b: 153, a: 0.502 });
+    expect(parseColor('#12345')).toBeNull();
+  });
+
+  it('should parse legacy and modern rgb() syntax with alpha', () => {
+    expect(rounded('rgba(0, 0, 255, .25)')).toEqual({ r: 0, g: 0, b: 255, a: 0.25 });
+    expect(rounded('rgb(255 0 0 / 50%)')).toEqual({ r: 255, g: 0, b: 0, a: 0.5 });
+    expect(rounded('rgb(100% 50% 0%)')).toEqual({ r: 255, g: 128, b: 0, a: 1 });
+    expect(rounded('rgb(none 300 -20)')).toEqual({ r: 0, g: 255, b: 0, a: 1 });
+  });
+
+  it('should parse hsl() and hwb() with angle units', () => {
+    expect(rounded('hsl(120 100% 50%)')).toEqual({ r: 0, g: 255, b: 0, a: 1 });
+    expect(rounded('hsla(240deg, 100%, 50%, 0.5)')).toEqual({ r: 0, g: 0, b: 255, a: 0.5 });
+    expect(rounded('hsl(0.5turn 100% 25%)')).toEqual({ r: 0, g: 128, b: 128, a: 1 });
+    expect(rounded('hwb(0 0% 0%)')).toEqual({ r: 255, g: 0, b: 0, a: 1 });
+    expect(rounded('hwb(90 60% 60%)')).toEqual({ r: 128, g: 128, b: 128, a: 1 });
+  });
+
+  it('should convert lab, lch, oklab, oklch and color() to sRGB', () => {
+    expect(rounded('lab(100 0 0)')).toEqual({ r: 255, g: 255, b: 255, a: 1 });
+    expect(rounded('lch(0% 0 0 / 0.5)')).toEqual({ r: 0, g: 0, b: 0, a: 0.5 });
+    expect(rounded('oklch(1 0 0)')).toEqual({ r: 255, g: 255, b: 255, a: 1 });
+    expect(rounded('oklab(0% 0 0)')).toEqual({ r: 0, g: 0, b: 0, a: 1 });
+    expect(rounded('color(srgb 0 0.5 1)')).toEqual({ r: 0, g: 128, b: 255, a: 1 });
+    expect(rounded('color(display-p3 1 1 1)')).toEqual({ r: 255, g: 255, b: 255, a: 1 });
+  });
+
+  it('should clip colors outside sRGB', () => {
+    const green = parseColor('color(display-p3 0 1 0)');
+    expect(green?.g).toBe(255);
+    expect(green?.r).toBe(0);
+  });
+
+  it('should resolve currentColor from the given color', () => {
+    const current = { r: 10, g: 20, b: 30, a: 1 };
+    expect(parseColor('currentColor', current)).toBe(current);
+    expect(parseColor('currentcolor')).toBeNull();
+  });
+
+  it('should reject values it does not understand', () => {
+    expect(parseColor('not-a-color')).toBeNull();
+    expect(parseColor('rgb(1 2)')).toBeNull();
+    expect(parseColor('hsl(10, 20%)')).toBeNull();
+    expect(parseColor('lab(50, 10, 10)')).toBeNull();
+    expect(parseColor('color(unknown 1 1 1)')).toBeNull();
+  });
+});
+
+describe('color math', () => {
+  const white = { r: 255, g: 255, b: 255, a: 1 };
+  const black = { r: 0, g: 0, b: 0, a: 1 };
+
+  it('should composite translucent colors with source-over', () => {
+    expect(compositeColors({ ...black, a: 0.5 }, white)).toEqual({ r: 127.5, g: 127.5, b: 127.5, a: 1 });
+    expect(compositeColors({ ...black, a: 0 }, { ...white, a: 0 }).a).toBe(0);
+  });
+
+  it('should compute WCAG contrast ratios', () => {
+    expect(contrastRatio(black, white)).toBeCloseTo(21, 5);
+    expect(contrastRatio(white, white)).toBe(1);
+  });
+
+  it('should format colors as rgb() or rgba()', () => {
+    expect(formatColor({ r: 127.5, g: 0, b: 255, a: 1 })).toBe('rgb(128, 0, 255)');
+    expect(formatColor({ r: 0, g: 0, b: 0, a: 0.25 })).toBe('rgba(0, 0, 0, 0.25)');
+  });
+});
@@ -0,0 +1,159 @@
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import {
+  ConfigError,
+  clearConfigCache,
+  findConfigFile,
+  loadConfig,
+  mergeOptions,
+  resolveOptions,
+  validateConfig,
+} from '../src/config';
+import { WCAGScanner } from '../src/scanner';
+
+describe('config', () => {
+  let tmpDir: string;
+
+  const write = (relative: string, content: string) => {
+    const filePath = path.join(tmpDir, relative);
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(filePath, content);
+    return filePath;
+  };
+
+  beforeEach(() => {
+    tmpDir = fs.mkdtempSync(path.join(os.tmpdir(), 'wcag-config-'));
+    clearConfigCache();
+  });
+
+  afterEach(() => {
+    fs.rmSync(tmpDir, { recursive: true, force: true });
+    clearConfigCache();
+  });
+
+  describe('validateConfig', () => {
+    it('should accept valid options', () => {
+      expect(validateConfig({ level: 'AAA', preset: 'full', rules: ['images'] }, 'test'))
+        .toEqual({ level: 'AAA', preset: 'full', rules: ['images'] });
+    });
+
+    it('should list every problem in one error', () => {
+      let error: unknown;
+      try {
+        validateConfig({ level: 'AAAA', rules: 'images', colour: true }, '.wcagscannerrc');
+      } catch (err) {
+        error = err;
+      }
+
+      expect(error).toBeInstanceOf(ConfigError);
+      const message = (error as Error).message;
+      expect(message).toContain('.wcagscannerrc');
+      expect(message).toContain('"level"');
+      expect(message).toContain('"rules"');
+      expect(message).toContain('unknown option "colour"');
+    });
+
+    it('should require markdownMaxLength to be a positive integer, as on the command line', () => {
+      expect(validateConfig({ markdownMaxLength: 4000 }, 'test')).toEqual({ markdownMaxLength: 4000 });
+      expect(() => validateConfig({ markdownMaxLength: 0 }, 'test')).toThrow('must be a positive integer');
+      expect(() => validateConfig({ markdownMaxLength: 12.5 }, 'test')).toThrow('must be a positive integer');
+    });
+
+    it('should reject non-object configs', () => {
+      expect(() => validateConfig(['AA'], 'test')).toThrow(ConfigError);
+    });
+
+    it('should not allow a config file to point at another config', () => {
+      expect(() => validateConfig({ config: 'other.json' }, 'test')).toThrow(/config/);
+    });
+  });
+
+  describe('mergeOptions', () => {
+    it('should let later layers win and skip undefined values', () => {
+      expect(mergeOptions({ level: 'A', preset: 'fast' }, { level: 'AA', preset: undefined }))
+        .toEqual({ level: 'AA', preset: 'fast' });
+    });
+  });
+
+  describe('findConfigFile', () => {
+    it('should find a .wcagscannerrc in a parent directory', () => {
+      write('package.json', '{"name": "app"}');
+      const rc = write('.wcagscannerrc', '{"level": "AAA"}');
+      const nested = path.join(tmpDir, 'src', 'pages');
+      fs.mkdirSync(nested, { recursive: true });
+
+      expect(findConfigFile(nested)).toBe(rc);
+    });
+
+    it('should use the wcagScanner key in package.json', () => {
+      const pkg = write('package.json', JSON.stringify({ name: 'app', wcagScanner: { preset: 'full' } }));
+
+      expect(findConfigFile(tmpDir)).toBe(pkg);
+      expect(loadConfig({ cwd: tmpDir })).toEqual({ preset: 'full' });
+    });
+
+    it('should stop at the package root', () => {
+      write('.wcagscannerrc', '{"level": "AAA"}');
+      write('app/package.json', '{"name": "app"}');
+
+      expect(findConfigFile(path.join(tmpDir, 'app'))).toBeNull();
+    });
+  });
+
+  describe('loadConfig', () => {
+    it('should load a JavaScript config file', () => {
+      write('package.json', '{}');
+      write('wcag-scanner.config.js', "module.exports = { level: 'A', rules: ['forms'] };");
+
+      expect(loadConfig({ cwd: tmpDir })).toEqual({ level: 'A', rules: ['forms'] });
+    });
+
+    it('should load an explicit config path relative to cwd', () => {
+      write('configs/strict.json', '{"level": "AAA"}');
+
+      expect(loadConfig({ cwd: tmpDir, configPath: 'configs/strict.json' })).toEqual({ level: 'AAA' });
+    });
+
+    it('should skip discovery when configPath is false', () => {
+      write('package.json', '{}');
+      write('.wcagscannerrc', '{"level": "AAA"}');
+
+      expect(loadConfig({ cwd: tmpDir, configPath: false })).toEqual({});
+    });
+
+    it('should report the file name for invalid JSON', () => {
+      write('package.json', '{}');
+      write('.wcagscannerrc', '{ level: AAA }');
+
+      expect(() => loadConfig({ cwd: tmpDir })).toThrow(/\.wcagscannerrc/);
+    });
+
+    it('should fail when an explicit config file is missing', () => {
+      expect(() => loadConfig({ cwd: tmpDir, configPath: 'missing.json' })).toThrow(ConfigError);
+    });
+  });
+
+  describe('resolveOptions', () => {
+    it('should put explicit options over the config file', () => {
+      write('package.json', '{}');
+      write('.wcagscannerrc', '{"level": "AAA", "preset": "full"}');
+
+      expect(resolveOptions({ level: 'A' }, tmpDir)).toEqual({ level: 'A', preset: 'full' });
+    });
+  });
+
+  describe('WCAGScanner', () => {
+    it('should apply an explicit config file under constructor options', async () => {
+      const configPath = write('strict.json', '{"rules": ["images"], "level": "AAA"}');
+      const scanner = new WCAGScanner({ config: configPath, level: 'A' });
+
+      await scanner.loadHTML('<html lang="en"><head><title>T</title></head><body><img src="a.png"><input type="text"></body></html>');
+      const results = await scanner.scan();
+
+      const rules = results.violations.map(v => v.rule);
+      expect(rules).toContain('img-alt');
+      expect(rules).not.toContain('form-label');
+    });
+  });
+});
@@ -90,6 +90,152 @@ describe('Contrast Rule', () => {
     });
   });
 
+  describe('Alpha compositing', () => {
+    it('should blend translucent text over the background', async () => {
+      const html = '<html><body style="background-color: rgb(255,255,255)"><p style="color: rgba(0, 0, 0, 0.3)">faint text</p></body></html>';
+      const { document, window } = createDocAndWin(html);
+      const results = await contrastRule.check(document, window, { level: 'AA' });
+      const violation = results.violations.find(v => v.rule === 'color-contrast');
+      expect(violation?.description).toContain('2.11:1');
+    });
+
+    it('should composite translucent backgrounds over their ancestors', async () => {
+      const html = `
+        <html>
+          <body style="background-color: rgb(255,255,255)">
+            <div style="background-color: rgba(0, 0, 0, 0.1)">
+              <p style="color: rgb(0,0,0)">dark text on a light tint</p>
+            </div>
+          </body>
+        </html>
+      `;
+      const { document, window } = createDocAndWin(html);
+      const results = await contrastRule.check(document, window, { level: 'AA' });
+      const pass = results.passes.find(p => p.element?.tagName === 'p');
+      expect(pass?.description).toBe('Sufficient color contrast ratio: 16.75:1 (required: 4.5:1 for normal text)');
+    });
+  });
+
+  describe('Large text', () => {
+    it('should apply the large text threshold to 14pt bold and em-sized text', async () => {
+      // rgb(120,120,120) on white is about 4.42:1, enough only for large text at AA
+      const html = `
+        <html>
+          <body style="background-color: rgb(255,255,255)">
+            <p id="bold" style="color: rgb(120,120,120); font-size: 14pt; font-weight: bold">bold text</p>
+            <div style="font-size: 12pt"><p id="em" style="color: rgb(120,120,120); font-size: 1.5em">big text</p></div>
+            <p id="small" style="color: rgb(120,120,120); font-size: 13pt; font-weight: bold">small bold text</p>
+          </body>
+        </html>
+      `;
+      const { document, window } = createDocAndWin(html);
+      const results = await contrastRule.check(document, window, { level: 'AA' });
+      const resultFor = (id: string) =>
+        [...results.passes, ...results.violations].find(r => r.rule === 'color-contrast' && r.element?.id === id);
+
+      expect(resultFor('bold')?.description).toBe('Sufficient color contrast ratio: 4.42:1 (required: 3:1 for large text)');
+      expect(resultFor('bold')?.element).toMatchObject({ fontSize: 18.67, fontWeight: 700, largeText: true });
+      expect(resultFor('em')?.element).toMatchObject({ fontSize: 24, largeText: true });
+      expect(results.violations.find(v => v.element?.id === 'small')?.description)
+        .toBe('Insufficient color contrast ratio: 4.42:1 (required: 4.5:1 for normal text)');
+    });
+  });
+
+  describe('Background images and gradients', () => {
+    // Serve background-image from a map of element ids, so layered cases need no stylesheet;
+    // the stylesheet test below covers jsdom's own parsing of gradients
+    const mockBackgroundImages = (window: Window, images: Record<string, string>) => {
+      const original = window.getComputedStyle.bind(window);
+      jest.spyOn(window, 'getComputedStyle').mockImplementation((element: Element) => {
+        const style = original(element);
+        const image = images[element.id];
+        if (image === undefined) return style;
+        return new Proxy(style, {
+          get: (target, property) => (property === 'backgroundImage' ? image : Reflect.get(target, property)),
+        });
+      });
+    };
+
+    it('should ask for review instead of passing text over an image', async () => {
+      const html = `
+        <html>
+          <body>
+            <div id="hero"><p id="over" style="color: rgb(0,0,0)">On the photo</p></div>
+            <div id="card"><p id="backed" style="color: rgb(0,0,0); background-color: rgb(255,255,255)">On a panel</p></div>
+          </body>
+        </html>
+      `;
+      const { document, window } = createDocAndWin(html);
+      mockBackgroundImages(window, { hero: 'url("hero.jpg")', card: 'url(card.png)' });
+
+      const results = await contrastRule.check(document, window, { level: 'AA' });
+      const review = results.incomplete.find(i => i.element?.id === 'over');
+
+      expect(review?.description).toBe('Text is over a background image (required: 4.5:1 for normal text)');
+      expect(review?.review).toContain('at least 4.5:1');
+      expect([...results.passes, ...results.violations, ...results.warnings].some(r => r.element?.id === 'over')).toBe(false);
+      expect(results.passes.some(p => p.element?.id === 'backed')).toBe(true);
+    });
+
+    it('should report the worst-case contrast over gradient stops', async () => {
+      const html = `
+        <html>
+          <body>
+            <div id="banner"><p id="text" style="color: rgb(0,0,0)">Gradient banner</p></div>
+          </body>
+        </html>
+      `;
+      const { document, window } = createDocAndWin(html);
+      mockBackgroundImages(window, { banner: 'linear-gradient(to right, #fff, rgb(90, 90, 90) 80%)' });
+
+      const results = await contrastRule.check(document, window, { level: 'AA' });
+      const violation = results.violations.find(v => v.element?.id === 'text');
+
+      expect(violation?.description)
+        .toBe('Insufficient color contrast ratio: 3.04:1 at the worst gradient stop (required: 4.5:1 for normal text)');
+      expect(violation?.fix?.description).toContain('rgb(90, 90, 90)');
+    });
+
+    it('should read gradients from a stylesheet without mocking', async () => {
+      const html = `
+        <html>
+          <head>
+            <style>#banner { background-image: linear-gradient(to right, #fff, rgb(90, 90, 90) 80%); }</style>
+          </head>
+          <body>
+            <div id="banner"><p id="text" style="color: rgb(0,0,0)">Gradient banner</p></div>
+          </body>
+        </html>
+      `;
+      const { document, window } = createDocAndWin(html);
+
+      expect(window.getComputedStyle(document.getElementById('banner')!).backgroundImage).toContain('linear-gradient');
+
+      const results = await contrastRule.check(document, window, { level: 'AA' });
+      const violation = results.violations.find(v => v.element?.id === 'text');
+
+      expect(violation?.description)
+        .toBe('Insufficient color contrast ratio: 3.04:1 at the worst gradient stop (required: 4.5:1 for normal text)');
+    });
+
+    it('should let an opaque gradient cover an image beneath it', async () => {
+      const html = `
+        <html>
+          <body>
+            <div id="layered"><p id="text" style="color: rgb(255,255,255)">Scrim over photo</p></div>
+          </body>
+        </html>
+      `;
+      const { document, window } = createDocAndWin(html);
+      mockBackgroundImages(window, { layered: 'linear-gradient(rgb(0, 0, 0), rgb(0, 0, 80)), url(photo.jpg)' });
+
+      const results = await contrastRule.check(document, window, { level: 'AA' });
+
+      expect(results.warnings).toHaveLength(0);
+      expect(results.passes.find(p => p.element?.id === 'text')?.description).toContain('at the worst gradient stop');
+    });
+  });
+
   describe('WCAG levels', () => {
     it('should reference 1.4.3 for AA violations and 1.4.6 for AAA violations', async () => {
       // rgb(120,120,120) on white ≈ 4.4:1 — fails AA (requires 4.5:1) and AAA (requires 7:1)
@@ -128,11 +274,37 @@ describe('Contrast Rule', () => {
       expect(results.violations.some(v => v.rule === 'color-contrast')).toBe(true);
     });
 
-    it('should treat unknown colors as insufficient contrast', async () => {
-      const html = '<html><body><p style="color: currentColor">unknown parse</p></body></html>';
+    it('should ask for review when the text color cannot be parsed', async () => {
+      const html = '<html><body><p>unknown parse</p></body></html>';
       const { document, window } = createDocAndWin(html);
+      const original = window.getComputedStyle.bind(window);
+
+      jest.spyOn(window, 'getComputedStyle').mockImplementation((element: Element) => {
+        const style = original(element);
+        return element.tagName === 'P' ? { ...style, color: 'color-mix(in srgb, red, blue)' } as CSSStyleDeclaration : style;
+      });
+
       const results = await contrastRule.check(document, window, { level: 'AA' });
-      expect(results.violations.some(v => v.rule === 'color-contrast')).toBe(true);
+      const review = results.incomplete.find(i => i.element?.tagName === 'p');
+
+      expect(results.violations.some(v => v.rule === 'color-contrast')).toBe(false);
+      expect(review?.description)
+        .toBe('Text color could not be determined: "color-mix(in srgb, red, blue)" (required: 4.5:1 for normal text)');
+      expect(review?.reason).toContain('cannot parse');
+    });
+
+    it('should resolve currentColor to the inherited text color', async () => {
+      const html = `
+        <html>
+          <body style="background-color: rgb(255,255,255)">
+            <div style="color: rgb(200,200,200)"><p style="color: currentColor">inherits light grey</p></div>
+          </body>
+        </html>
+      `;
+      const { document, window } = createDocAndWin(html);
+      const results = await contrastRule.check(document, window, { level: 'AA' });
+      const violation = results.violations.find(v => v.element?.tagName === 'p');
+      expect(violation?.description).toContain('1.67:1');
     });
 
     it('should skip elements when computed colors are unavailable', async () => {
@@ -0,0 +1,176 @@
+import http from 'http';
+import { AddressInfo, Socket } from 'net';
+import { crawlSite, parseRobotsTxt, parseSitemap, summarizeSite } from '../src/crawler';
+
+const page = (body: string) =>
+  `<!DOCTYPE html><html lang="en"><head><title>T</title></head><body>${body}</body></html>`;
+
+const SITE: Record<string, string> = {
+  '/': page(`
+    <a href="/a">A</a>
+    <a href="b#section">B</a>
+    <a href="/private/x">Private</a>
+    <a href="/skip" rel="nofollow">Skip</a>
+    <a href="/file.pdf" download>File</a>
+    <a href="https://other.example/">Elsewhere</a>
+    <a href="mailto:team@example.com">Mail</a>
+  `),
+  '/a': page('<a href="/c">C</a><img src="a.png">'),
+  '/b': page('<a href="/">Home</a><a href="/report.pdf">Report</a><a href="/feed">Feed</a>'),
+  '/c': page('<a href="/d">D</a>'),
+  '/d': page('<p>Deep</p>'),
+  '/private/x': page('<p>Private</p>'),
+};
+
+describe('crawlSite', () => {
+  const sockets: Socket[] = [];
+  let server: http.Server;
+  let base: string;
+  let requested: string[];
+
+  beforeEach(async () => {
+    requested = [];
+    server = http.createServer((req, res) => {
+      const url = req.url || '/';
+      requested.push(url);
+
+      if (url === '/robots.txt') {
+        res.writeHead(200, { 'content-type': 'text/plain' });
+        res.end('User-agent: *\nDisallow: /private\n');
+      } else if (url === '/sitemap.xml') {
+        res.writeHead(200, { 'content-type': 'application/xml' });
+        res.end(`<?xml version="1.0"?><urlset><url><loc>${base}/a</loc></url><url><loc>${base}/d</loc></url></urlset>`);
+      } else if (url === '/feed') {
+        res.writeHead(200, { 'content-type': 'application/json' });
+        res.end('{}');
+      } else if (SITE[url]) {
+        res.writeHead(200, { 'content-type': 'text/html' });
+        res.end(SITE[url]);
+      } else {
+        res.writeHead(404, { 'content-type': 'text/html' });
+        res.end('Not found');
+      }
+    });
+    server.on('connection', socket => sockets.push(socket));
+    await new Promise<void>(resolve => server.listen(0, '127.0.0.1', resolve));
+    base = `http://127.0.0.1:${(server.address() as AddressInfo).port}`;
+  });
+
+  afterEach(async () => {
+    sockets.splice(0).forEach(socket => socket.destroy());
+    await new Promise(resolve => server.close(resolve));
+  });
+
+  it('should follow same-origin links up to the depth limit', async () => {
+    const site = await crawlSite(`${base}/`, { rules: ['images'], config: false });
+
+    expect(Object.keys(site.pages).sort()).toEqual([`${base}/`, `${base}/a`, `${base}/b`, `${base}/c`]);
+    expect(requested).not.toContain('/skip');
+    expect(requested).not.toContain('/file.pdf');
+    expect(requested).not.toContain('/report.pdf');
+    expect(site.skipped).toEqual({ [`${base}/feed`]: 'Expected an HTML page but received application/json' });
+    expect(site.summary.pages).toBe(4);
+    expect(site.summary.failedPages).toBe(0);
+    expect(site.summary.pagesWithViolations).toBe(1);
+    expect(site.summary.rules[0]).toMatchObject({ rule: 'img-alt', violations: 1, pages: 1 });
+  });
+
+  it('should honor robots.txt unless disabled', async () => {
+    const polite = await crawlSite(`${base}/`, { rules: ['images'], config: false, maxDepth: 1 });
+    const rude = await crawlSite(`${base}/`, { rules: ['images'], config: false, maxDepth: 1, robotsTxt: false });
+
+    expect(polite.pages[`${base}/private/x`]).toBeUndefined();
+    expect(rude.pages[`${base}/private/x`]).toBeDefined();
+  });
+
+  it('should stop at the page limit', async () => {
+    const site = await crawlSite(`${base}/`, { rules: ['images'], config: false, maxPages: 2, concurrency: 1 });
+
+    expect(Object.keys(site.pages)).toEqual([`${base}/`, `${base}/a`]);
+  });
+
+  it('should apply include and exclude patterns to discovered links', async () => {
+    const site = await crawlSite(`${base}/`, {
+      rules: ['images'],
+      config: false,
+      include: ['/{a,b,c}'],
+      exclude: [/\/b$/],
+    });
+
+    expect(Object.keys(site.pages).sort()).toEqual([`${base}/`, `${base}/a`, `${base}/c`]);
+  });
+
+  it('should seed the crawl from a sitemap and record failed pages', async () => {
+    const onPage = jest.fn();
+    const site = await crawlSite(`${base}/sitemap.xml`, { rules: ['images'], config: false, maxDepth: 0, onPage });
+
+    expect(Object.keys(site.pages).sort()).toEqual([`${base}/a`, `${base}/d`]);
+    expect(onPage).toHaveBeenCalledTimes(2);
+
+    const missing = await crawlSite(`${base}/missing`, { rules: ['images'], config: false });
+    expect(missing.summary.failedPages).toBe(1);
+    expect(missing.errors[`${base}/missing`]).toContain('404');
+  });
+});
+
+describe('parseRobotsTxt', () => {
+  it('should prefer the most specific rule and our user agent group', () => {
+    const robots = parseRobotsTxt([
+      'User-agent: *',
+      'Disallow: /',
+      '',
+      'User-agent: wcag-scanner',
+      'Disallow: /admin',
+      'Allow: /admin/public',
+      'Disallow: /*.json$',
+    ].join('\n'));
+
+    expect(robots.isAllowed('/docs')).toBe(true);
+    expect(robots.isAllowed('/admin/users')).toBe(false);
+    expect(robots.isAllowed('/admin/public/page')).toBe(true);
+    expect(robots.isAllowed('/data.json')).toBe(false);
+    expect(robots.isAllowed('/data.json?v=1')).toBe(true);
+  });
+
+  it('should ignore blank user agent lines', () => {
+    const robots = parseRobotsTxt('User-agent:\nDisallow: /\n\nUser-agent: *\nDisallow: /tmp');
+
+    expect(robots.isAllowed('/')).toBe(true);
+    expect(robots.isAllowed('/tmp/file')).toBe(false);
+  });
+
+  it('should fall back to the wildcard group', () => {
+    const robots = parseRobotsTxt('User-agent: other\nDisallow: /\n\nUser-agent: *\nDisallow: /tmp # scratch');
+
+    expect(robots.isAllowed('/tmp/file')).toBe(false);
+    expect(robots.isAllowed('/')).toBe(true);
+  });
+});
+
+describe('parseSitemap', () => {
+  it('should read page and nested sitemap locations', () => {
+    expect(parseSitemap('<urlset><url><loc>/a?x=1&amp;y=2</loc></url></urlset>', 'https://example.com/sitemap.xml'))
+      .toEqual({ pages: ['https://example.com/a?x=1&y=2'], sitemaps: [] });
+    expect(parseSitemap('<sitemapindex><sitemap><loc>https://example.com/s1.xml</loc></sitemap></sitemapindex>', 'https://example.com/'))
+      .toEqual({ pages: [], sitemaps: ['https://example.com/s1.xml'] });
+  });
+
+  it('should leave out-of-range character references undecoded', () => {
+    const xml = '<urlset><url><loc>/&#x41;</loc></url><url><loc>/b&#x110000;</loc></url><url><loc>/c&#99999999;</loc></url></urlset>';
+    expect(parseSitemap(xml, 'https://example.com/sitemap.xml').pages)
+      .toEqual(['https://example.com/A', 'https://example.com/b&#x110000;', 'https://example.com/c&#99999999;']);
+  });
+});
+
+describe('summarizeSite', () => {
+  it('should count pages, failures and rules across pages', () => {
+    const violation = { rule: 'img-alt', impact: 'critical' as const, description: 'Missing alt' };
+    const site = summarizeSite({
+      one: { passes: [], violations: [violation, violation], warnings: [], incomplete: [] },
+      two: { passes: [{ rule: 'html-lang', description: 'ok' }], violations: [violation], warnings: [], incomplete: [] },
+    }, { three: 'boom' });
+
+    expect(site.summary).toMatchObject({ pages: 2, failedPages: 1, pagesWithViolations: 2, violations: 3, passes: 1 });
+    expect(site.summary.rules).toEqual([{ rule: 'img-alt', violations: 3, warnings: 0, pages: 2 }]);
+  });
+});
@@ -0,0 +1,190 @@
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { JSDOM } from 'jsdom';
+import { defineRule, selectDefinedRules } from '../src/defineRule';
+import { loadRulePack } from '../src/rulePacks';
+import { getRuleMetadata } from '../src/metadata';
+import { WCAGScanner } from '../src/scanner';
+import { ScannerOptions } from '../src/types';
+
+const buttonLabel = defineRule({
+  id: 'ds-button-label',
+  module: 'design-system',
+  wcag: ['4.1.2'],
+  tags: ['buttons'],
+  impact: 'serious',
+  help: 'Give every ds-button a label',
+  check(context) {
+    context.document.querySelectorAll('ds-button').forEach(button => {
+      if (!context.isVisible(button)) return;
+      if (button.getAttribute('label')) {
+        context.pass(button, 'ds-button has a label');
+      } else {
+        context.violation(button, { description: 'ds-button has no label' });
+      }
+    });
+  },
+});
+
+const scanWith = async (html: string, options: ScannerOptions = {}) => {
+  const scanner = new WCAGScanner({ config: false, rules: ['design-system'], ...options });
+  scanner.registerRule(buttonLabel);
+  await scanner.loadHTML(html);
+  return scanner.scan();
+};
+
+describe('defineRule', () => {
+  it('should fill in the rule id, criteria, impact, help and element details', async () => {
+    const dom = new JSDOM('<ds-button id="save" class="primary"></ds-button>');
+    const results = await buttonLabel.check(dom.window.document, dom.window as unknown as Window, {});
+
+    expect(results.violations).toEqual([{
+      rule: 'ds-button-label',
+      description: 'ds-button has no label',
+      impact: 'serious',
+      wcag: ['4.1.2'],
+      help: 'Give every ds-button a label',
+      element: { tagName: 'ds-button', id: 'save', className: 'primary' },
+      snippet: '<ds-button id="save" class="primary"></ds-button>',
+      selector: '#save',
+      xpath: '//*[@id="save"]',
+    }]);
+    expect(results.incomplete).toEqual([]);
+  });
+
+  it('should let results override the defaults and truncate snippets', async () => {
+    const rule = defineRule({
+      id: 'ds-long-text',
+      check(context) {
+        const paragraph = context.document.querySelector('p');
+        context.warning(paragraph, { description: 'Long text', impact: 'minor', wcag: ['1.4.8'] });
+        context.incomplete(null, { description: 'Check reading order', reason: 'Layout is visual', review: 'Read the page in order' });
+      },
+    });
+    const dom = new JSDOM(`<p>${'x'.repeat(200)}</p>`);
+    const results = await rule.check(dom.window.document, dom.window as unknown as Window, {});
+
+    expect(results.warnings[0]).toMatchObject({ rule: 'ds-long-text', impact: 'minor', wcag: ['1.4.8'] });
+    expect(results.warnings[0].snippet).toHaveLength(153);
+    expect(results.incomplete).toEqual([{
+      rule: 'ds-long-text',
+      description: 'Check reading order',
+      impact: 'moderate',
+      reason: 'Layout is visual',
+      review: 'Read the page in order',
+    }]);
+  });
+
+  it('should register the rule metadata', () => {
+    defineRule({ id: 'ds-focus-ring', level: 'AA', tags: ['best-practice'], check: () => undefined });
+
+    expect(getRuleMetadata('ds-button-label')).toMatchObject({ level: 'A', bestPractice: false });
+    expect(getRuleMetadata('ds-focus-ring')).toEqual({ rule: 'ds-focus-ring', criteria: [], level: 'AA', bestPractice: true });
+  });
+
+  it('should reject rule ids of built-in rules, unknown criteria and missing checks', () => {
+    expect(() => defineRule({ id: 'img-alt', check: () => undefined }))
+      .toThrow('Rule id "img-alt" is already used by a built-in rule');
+    expect(() => defineRule({ id: 'ds-bad', wcag: ['9.9.9'], check: () => undefined }))
+      .toThrow('Rule "ds-bad" cites unknown success criteria: 9.9.9');
+    expect(() => defineRule({ id: 'ds-no-check' } as never)).toThrow('Rule "ds-no-check" requires a check function');
+  });
+
+  it('should select defined rules by id, module or tag', () => {
+    expect(selectDefinedRules([buttonLabel], {})).toEqual([buttonLabel]);
+    expect(selectDefinedRules([buttonLabel], { rules: ['buttons'] })).toEqual([buttonLabel]);
+    expect(selectDefinedRules([buttonLabel], { rules: ['ds-button-label'] })).toEqual([buttonLabel]);
+    expect(selectDefinedRules([buttonLabel], { rules: ['images'] })).toEqual([]);
+  });
+});
+
+describe('WCAGScanner with defined rules', () => {
+  it('should run registered defined rules and skip hidden elements', async () => {
+    const results = await scanWith(`
+      <ds-button label="Save"></ds-button>
+      <ds-button></ds-button>
+      <ds-button hidden></ds-button>
+    `);
+
+    expect(results.violations.map(v => v.rule)).toEqual(['ds-button-label']);
+    expect(results.violations[0]).toMatchObject({ level: 'A', location: { line: 3 } });
+    expect(results.passes.map(p => p.description)).toEqual(['ds-button has a label']);
+  });
+
+  it('should run defined rules alongside a preset', async () => {
+    const results = await scanWith('<img src="a.png"><ds-button></ds-button>', { rules: undefined });
+
+    expect(results.violations.map(v => v.rule)).toEqual(expect.arrayContaining(['img-alt', 'ds-button-label']));
+  });
+
+  it('should filter defined rule results by standard and overrides', async () => {
+    const html = '<ds-button></ds-button>';
+
+    expect((await scanWith(html, { ruleOverrides: { 'ds-button-label': 'off' } })).violations).toHaveLength(0);
+    expect((await scanWith(html, { standard: 'en301549' })).violations[0].clauses).toEqual(['9.4.1.2']);
+  });
+});
+
+describe('rule packs', () => {
+  let tmpDir: string;
+
+  const writePack = (name: string, content: string) => {
+    const dir = path.join(tmpDir, 'node_modules', name);
+    fs.mkdirSync(dir, { recursive: true });
+    fs.writeFileSync(path.join(dir, 'index.js'), content);
+  };
+
+  beforeEach(() => {
+    tmpDir = fs.mkdtempSync(path.join(os.tmpdir(), 'wcag-packs-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it('should load defined rules from a package', () => {
+    writePack('ds-rules', `
+      const { defineRule } = require(${JSON.stringify(path.join(__dirname, '../src/defineRule'))});
+      module.exports = {
+        rules: [defineRule({ id: 'ds-card-heading', module: 'ds-rules', check: () => undefined })],
+      };
+    `);
+
+    expect(loadRulePack('ds-rules', tmpDir).map(rule => rule.meta)).toEqual([
+      { id: 'ds-card-heading', module: 'ds-rules', wcag: [], tags: [] },
+    ]);
+  });
+
+  it('should report packs that are missing or export something else', () => {
+    writePack('not-rules', 'module.exports = { rules: [{ check: () => undefined }] };');
+
+    expect(() => loadRulePack('missing-rules', tmpDir)).toThrow('Could not load rule pack "missing-rules"');
+    expect(() => loadRulePack('not-rules', tmpDir))
+      .toThrow('Rule pack "not-rules" must export an array of rules created with defineRule, or { rules }');
+  });
+
+  it('should run rule packs listed in the scanner options', async () => {
+    writePack('ds-image-rules', `
+      const { defineRule } = require(${JSON.stringify(path.join(__dirname, '../src/defineRule'))});
+      module.exports = [defineRule({
+        id: 'ds-img-lazy',
+        wcag: ['1.1.1'],
+        check(context) {
+          context.document.querySelectorAll('img:not([loading])').forEach(img => {
+            context.warning(img, { description: 'Image is not lazy loaded' });
+          });
+        },
+      })];
+    `);
+    const scanner = new WCAGScanner({
+      config: false,
+      rules: ['ds-img-lazy'],
+      rulePacks: [path.join(tmpDir, 'node_modules', 'ds-image-rules')],
+    });
+    await scanner.loadHTML('<img src="a.png" alt="A">');
+    const results = await scanner.scan();
+
+    expect(results.warnings.map(w => w.description)).toEqual(['Image is not lazy loaded']);
+  });
+});
@@ -0,0 +1,92 @@
+import { JSDOM } from 'jsdom';
+import { clearIdCache, cssEscape, elementContext, elementXPath, uniqueSelector } from '../src/utils/elements';
+import imagesRule from '../src/rules/images';
+
+describe('element selectors', () => {
+  const createDocument = (html: string): Document => new JSDOM(html).window.document;
+
+  it('should build an nth-child selector that matches only the element', () => {
+    const document = createDocument(`
+      <main>
+        <p>Intro</p>
+        <img src="a.jpg">
+        <img src="a.jpg">
+      </main>
+    `);
+    const second = document.querySelectorAll('img')[1];
+    const selector = uniqueSelector(second);
+
+    expect(selector).toBe('html > body:nth-child(2) > main:nth-child(1) > img:nth-child(3)');
+    expect(document.querySelectorAll(selector)).toHaveLength(1);
+    expect(document.querySelector(selector)).toBe(second);
+  });
+
+  it('should anchor selectors and XPaths at the nearest unique id', () => {
+    const document = createDocument('<nav id="1st-nav"><ul><li>A</li><li>B</li></ul></nav>');
+    const item = document.querySelectorAll('li')[1];
+
+    expect(uniqueSelector(item)).toBe('#\\31 st-nav > ul:nth-child(1) > li:nth-child(2)');
+    expect(document.querySelector(uniqueSelector(item))).toBe(item);
+    expect(elementXPath(item)).toBe('//*[@id="1st-nav"]/ul/li[2]');
+  });
+
+  it('should not anchor at duplicated ids', () => {
+    const document = createDocument('<div id="dup"></div><div id="dup"><span></span></div>');
+    const span = document.querySelector('span')!;
+
+    expect(uniqueSelector(span)).toBe('html > body:nth-child(2) > div:nth-child(2) > span:nth-child(1)');
+    expect(elementXPath(span)).toBe('/html/body/div[2]/span');
+  });
+
+  it('should count ids once per document until the cache is cleared', () => {
+    const document = createDocument('<div id="panel"><span></span></div>');
+    const span = document.querySelector('span')!;
+    const querySpy = jest.spyOn(document, 'querySelectorAll');
+
+    expect(uniqueSelector(span)).toBe('#panel > span:nth-child(1)');
+    expect(elementXPath(span)).toBe('//*[@id="panel"]/span');
+    expect(querySpy).toHaveBeenCalledTimes(1);
+
+    document.body.insertAdjacentHTML('beforeend', '<div id="panel"></div>');
+    clearIdCache(document);
+    expect(uniqueSelector(span)).toBe('html > body:nth-child(2) > div:nth-child(1) > span:nth-child(1)');
+  });
+
+  it('should build XPaths that resolve to the element, including SVG', () => {
+    const document = createDocument('<div><svg><rect></rect><rect></rect></svg></div>');
+    const rect = document.querySelectorAll('rect')[1];
+    const xpath = elementXPath(rect);
+
+    expect(xpath).toBe('/html/body/div/*[local-name()="svg"]/*[local-name()="rect"][2]');
+    expect(document.evaluate(xpath, document, null, 9, null).singleNodeValue).toBe(rect);
+  });
+
+  it('should escape identifiers like CSS.escape', () => {
+    expect(cssEscape('hero')).toBe('hero');
+    expect(cssEscape('2col')).toBe('\\32 col');
+    expect(cssEscape('-')).toBe('\\-');
+    expect(cssEscape('-1a')).toBe('-\\31 a');
+    expect(cssEscape('a.b:c')).toBe('a\\.b\\:c');
+    expect(cssEscape('naïve')).toBe('naïve');
+  });
+
+  it('should leave out locations for documents without a registered locator', () => {
+    const document = createDocument('<img src="a.jpg">');
+    const context = elementContext(document.querySelector('img')!);
+
+    expect(context.location).toBeUndefined();
+    expect(context.selector).toBe('html > body:nth-child(2) > img:nth-child(1)');
+    expect(context.xpath).toBe('/html/body/img');
+  });
+
+  it('should be recorded by rules at detection time', async () => {
+    const dom = new JSDOM('<img src="a.jpg"><img src="a.jpg">');
+    const results = await imagesRule.check(dom.window.document, dom.window as unknown as Window, {});
+    const selectors = results.violations.filter(violation => violation.rule === 'img-alt').map(violation => violation.selector);
+
+    expect(selectors).toEqual([
+      'html > body:nth-child(2) > img:nth-child(1)',
+      'html > body:nth-child(2) > img:nth-child(2)',
+    ]);
+  });
+});
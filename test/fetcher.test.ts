@@ -0,0 +1,202 @@
+import http from 'http';
+import { AddressInfo, Socket } from 'net';
+import zlib from 'zlib';
+import { fetchPage, FetchError, NotHtmlError } from '../src/fetcher';
+import { scanUrl } from '../src/index';
+import { WCAGScanner } from '../src/scanner';
+
+type Handler = (req: http.IncomingMessage, res: http.ServerResponse) => void;
+
+const PAGE = '<!DOCTYPE html><html lang="en"><head><title>T</title></head><body><img src="logo.png"></body></html>';
+
+describe('fetchPage', () => {
+  const servers: http.Server[] = [];
+  const sockets: Socket[] = [];
+
+  const serve = async (handler: Handler): Promise<string> => {
+    const server = http.createServer(handler);
+    server.on('connection', socket => sockets.push(socket));
+    servers.push(server);
+    await new Promise<void>(resolve => server.listen(0, '127.0.0.1', resolve));
+    return `http://127.0.0.1:${(server.address() as AddressInfo).port}`;
+  };
+
+  const html = (res: http.ServerResponse, body: string | Buffer = PAGE, headers: http.OutgoingHttpHeaders = {}) => {
+    res.writeHead(200, { 'content-type': 'text/html; charset=utf-8', ...headers });
+    res.end(body);
+  };
+
+  afterEach(async () => {
+    sockets.splice(0).forEach(socket => socket.destroy());
+    await Promise.all(servers.splice(0).map(server => new Promise(resolve => server.close(resolve))));
+    jest.restoreAllMocks();
+  });
+
+  it('should fetch a page with custom headers and cookies', async () => {
+    let received: http.IncomingHttpHeaders = {};
+    const base = await serve((req, res) => {
+      received = req.headers;
+      html(res);
+    });
+
+    const page = await fetchPage(`${base}/page`, {
+      headers: { Authorization: 'Bearer token' },
+      cookies: { session: 'abc', theme: 'dark' },
+    });
+
+    expect(page.status).toBe(200);
+    expect(page.url).toBe(`${base}/page`);
+    expect(page.body).toBe(PAGE);
+    expect(received.authorization).toBe('Bearer token');
+    expect(received.cookie).toBe('session=abc; theme=dark');
+  });
+
+  it('should follow redirects and keep cookies they set', async () => {
+    let cookie: string | undefined;
+    const base = await serve((req, res) => {
+      if (req.url === '/login') {
+        res.writeHead(302, { location: '/home', 'set-cookie': 'session=xyz; HttpOnly' });
+        res.end();
+        return;
+      }
+      cookie = req.headers.cookie;
+      html(res);
+    });
+
+    const page = await fetchPage(`${base}/login`);
+
+    expect(page.url).toBe(`${base}/home`);
+    expect(page.redirects).toEqual([`${base}/login`]);
+    expect(cookie).toBe('session=xyz');
+  });
+
+  it('should not send credentials to another origin', async () => {
+    let received: http.IncomingHttpHeaders = {};
+    const other = await serve((req, res) => {
+      received = req.headers;
+      html(res);
+    });
+    const base = await serve((_req, res) => {
+      res.writeHead(301, { location: `${other}/landing` });
+      res.end();
+    });
+
+    const page = await fetchPage(base, { headers: { authorization: 'secret', 'x-trace': '1' }, cookies: 'session=abc' });
+
+    expect(page.url).toBe(`${other}/landing`);
+    expect(received.authorization).toBeUndefined();
+    expect(received.cookie).toBeUndefined();
+    expect(received['x-trace']).toBe('1');
+  });
+
+  it('should stop after too many redirects', async () => {
+    const base = await serve((req, res) => {
+      res.writeHead(302, { location: `${req.url}x` });
+      res.end();
+    });
+
+    await expect(fetchPage(`${base}/`, { maxRedirects: 2 })).rejects.toThrow('Too many redirects');
+  });
+
+  it('should time out slow responses', async () => {
+    const base = await serve(() => undefined);
+
+    await expect(fetchPage(base, { timeout: 50 })).rejects.toThrow(/timed out/);
+  });
+
+  it('should reject error statuses and non-HTML responses', async () => {
+    const base = await serve((req, res) => {
+      if (req.url === '/missing') {
+        res.writeHead(404, { 'content-type': 'text/html' });
+        res.end('Not found');
+        return;
+      }
+      res.writeHead(200, { 'content-type': 'application/json' });
+      res.end('{}');
+    });
+
+    const missing = fetchPage(`${base}/missing`);
+    await expect(missing).rejects.toBeInstanceOf(FetchError);
+    await expect(missing).rejects.toMatchObject({ status: 404 });
+    await expect(fetchPage(`${base}/data.json`)).rejects.toThrow('Expected an HTML page');
+  });
+
+  it('should not download the body of non-HTML responses', async () => {
+    // The body never ends; reading it would time out
+    const base = await serve((req, res) => {
+      res.writeHead(200, { 'content-type': 'application/pdf' });
+      res.write('%PDF-1.7');
+    });
+
+    await expect(fetchPage(`${base}/report`, { timeout: 2000 })).rejects.toBeInstanceOf(NotHtmlError);
+  });
+
+  it('should decode compressed and non-UTF-8 bodies', async () => {
+    const base = await serve((req, res) => {
+      if (req.url === '/gzip') {
+        html(res, zlib.gzipSync(Buffer.from(PAGE)), { 'content-encoding': 'gzip' });
+        return;
+      }
+      res.writeHead(200, { 'content-type': 'text/html; charset=iso-8859-1' });
+      res.end(Buffer.from([0x3c, 0x70, 0x3e, 0xe9, 0x3c, 0x2f, 0x70, 0x3e]));
+    });
+
+    expect((await fetchPage(`${base}/gzip`)).body).toBe(PAGE);
+    expect((await fetchPage(`${base}/latin1`)).body).toBe('<p>é</p>');
+  });
+
+  it('should reject bodies over maxBytes and bodies that cannot be decompressed', async () => {
+    const base = await serve((req, res) => {
+      if (req.url === '/bomb') {
+        html(res, zlib.gzipSync(Buffer.alloc(1024 * 1024)), { 'content-encoding': 'gzip' });
+      } else if (req.url === '/corrupt') {
+        html(res, 'not gzip', { 'content-encoding': 'gzip' });
+      } else {
+        html(res, PAGE.repeat(100));
+      }
+    });
+
+    await expect(fetchPage(`${base}/large`, { maxBytes: 1000 })).rejects.toThrow('Response body exceeds 1000 bytes');
+    await expect(fetchPage(`${base}/bomb`, { maxBytes: 10000 }))
+      .rejects.toThrow('Response body exceeds 10000 bytes when decompressed');
+    const corrupt = fetchPage(`${base}/corrupt`);
+    await expect(corrupt).rejects.toBeInstanceOf(FetchError);
+    await expect(corrupt).rejects.toThrow('Could not decompress the response body');
+  });
+
+  it('should scan a URL using the final URL as the base URL', async () => {
+    const base = await serve((req, res) => {
+      if (req.url === '/') {
+        res.writeHead(302, { location: '/docs/' });
+        res.end();
+        return;
+      }
+      html(res);
+    });
+    const loadSpy = jest.spyOn(WCAGScanner.prototype, 'loadHTML');
+
+    const results = await scanUrl(`${base}/`, { rules: ['images'], config: false });
+
+    expect(loadSpy).toHaveBeenCalledWith(PAGE, `${base}/docs/`);
+    expect(results.violations.some(v => v.rule === 'img-alt')).toBe(true);
+  });
+
+  it('should fetch same-origin scripts with the page credentials when running scripts', async () => {
+    let scriptCookie: string | undefined;
+    const base = await serve((req, res) => {
+      if (req.url === '/app.js') {
+        scriptCookie = req.headers.cookie;
+        res.writeHead(200, { 'content-type': 'text/javascript' });
+        res.end('document.body.innerHTML = \'<img src="logo.png">\';');
+        return;
+      }
+      html(res, '<!DOCTYPE html><html lang="en"><head><title>T</title></head><body>' +
+        '<script src="/app.js"></script><script src="https://cdn.invalid/lib.js"></script></body></html>');
+    });
+
+    const results = await scanUrl(`${base}/`, { rules: ['images'], config: false, runScripts: true, cookies: 'session=abc' });
+
+    expect(scriptCookie).toBe('session=abc');
+    expect(results.violations.some(v => v.rule === 'img-alt')).toBe(true);
+  });
+});
@@ -72,6 +72,21 @@ describe('Forms Rule', () => {
       expect(violation).toBeDefined();
     });
 
+    it('should detect labels that resolve to no text', async () => {
+      const html = '<form><label for="email"> </label><input type="email" id="email"><input type="text" aria-labelledby="missing"></form>';
+      const results = await formsRule.check(createDoc(html), createWin(html), {});
+      const violations = results.violations.filter(v => v.rule === 'form-label');
+      expect(violations).toHaveLength(2);
+      expect(violations[0].description).toBe('Form control label is empty or refers to a missing element');
+    });
+
+    it('should record the computed accessible name', async () => {
+      const html = '<form><label for="name">Full <b>name</b></label><input type="text" id="name"></form>';
+      const results = await formsRule.check(createDoc(html), createWin(html), {});
+      const pass = results.passes.find(p => p.rule === 'form-label');
+      expect(pass?.element?.accessibleName).toBe('Full name');
+    });
+
     it('should warn when placeholder is used instead of label', async () => {
       const html = '<form><input type="text" placeholder="Enter name"></form>';
       const results = await formsRule.check(createDoc(html), createWin(html), {});
@@ -177,4 +192,22 @@ describe('Forms Rule', () => {
       expect(warning).toBeUndefined();
     });
   });
+
+  describe('Accessible authentication', () => {
+    it('should flag password fields that block pasting', async () => {
+      const html = '<label>Password <input type="password" onpaste="return false"></label>';
+      const results = await formsRule.check(createDoc(html), createWin(html), {});
+      const violation = results.violations.find(v => v.rule === 'accessible-authentication');
+      expect(violation?.description).toBe('Authentication field blocks pasting');
+      expect(violation?.wcag).toEqual(['3.3.8']);
+    });
+
+    it('should warn when autocomplete is turned off', async () => {
+      const html = '<label>Code <input type="text" autocomplete="one-time-code"></label><label>Password <input type="password" autocomplete="off"></label>';
+      const results = await formsRule.check(createDoc(html), createWin(html), {});
+      expect(results.warnings.filter(w => w.rule === 'accessible-authentication').map(w => w.description))
+        .toEqual(['Authentication field turns off autocomplete']);
+      expect(results.passes.filter(p => p.rule === 'accessible-authentication')).toHaveLength(1);
+    });
+  });
 });
@@ -0,0 +1,79 @@
+import { JSDOM } from 'jsdom';
+import idReferencesRule from '../src/rules/idReferences';
+
+const check = (html: string) => {
+  const dom = new JSDOM(html);
+  return idReferencesRule.check(dom.window.document, dom.window as unknown as Window, {});
+};
+
+describe('ID references rule', () => {
+  it('should report references that do not resolve', async () => {
+    const results = await check(`
+      <span id="name">Name</span>
+      <input aria-labelledby="name missing">
+      <button aria-controls="menu">Menu</button>
+      <label for="email">Email</label>
+      <table><tr><th id="h">Head</th></tr><tr><td headers="h nope">1</td></tr></table>
+    `);
+    const violations = results.violations.filter(v => v.rule === 'id-reference-missing');
+
+    expect(violations.map(v => v.element?.attrName)).toEqual(['aria-controls', 'aria-labelledby', 'for', 'headers']);
+    expect(violations.find(v => v.element?.attrName === 'aria-labelledby')).toMatchObject({
+      impact: 'moderate',
+      description: 'aria-labelledby refers to an id that does not exist: "missing"',
+      wcag: ['1.3.1', '4.1.2'],
+    });
+    expect(violations.find(v => v.element?.attrName === 'for')?.impact).toBe('serious');
+  });
+
+  it('should pass references that resolve', async () => {
+    const results = await check(`
+      <p id="hint">Hint</p>
+      <label for="q">Search</label>
+      <input id="q" aria-describedby="hint">
+    `);
+
+    expect(results.violations).toHaveLength(0);
+    expect(results.passes.map(p => p.element?.attrName)).toEqual(['aria-describedby', 'for']);
+  });
+
+  it('should check usemap against map names', async () => {
+    const results = await check(`
+      <img src="a.png" alt="" usemap="#nav"><map name="nav"></map>
+      <img src="b.png" alt="" usemap="#gone">
+      <img src="c.png" alt="" usemap="nav">
+    `);
+    const violations = results.violations.filter(v => v.rule === 'id-reference-missing');
+
+    expect(violations.map(v => v.description)).toEqual([
+      'usemap refers to a map that does not exist: "gone"',
+      'usemap must start with "#": "nav"',
+    ]);
+  });
+
+  it('should flag duplicate ids, as violations when referenced', async () => {
+    const results = await check(`
+      <label for="email">Email</label>
+      <input id="email"><input id="email">
+      <div id="card"></div><div id="card"></div>
+    `);
+
+    expect(results.violations.find(v => v.rule === 'duplicate-id-referenced')?.description)
+      .toBe('id "email" is used by 2 elements and referenced by for');
+    expect(results.warnings.find(w => w.rule === 'duplicate-id')?.description)
+      .toBe('id "card" is used by 2 elements');
+  });
+
+  it('should warn about references to hidden elements only where that breaks them', async () => {
+    const results = await check(`
+      <span id="label" hidden>Quantity</span>
+      <input aria-labelledby="label">
+      <div role="listbox" aria-owns="opt"></div>
+      <div role="option" id="opt" style="display: none">One</div>
+    `);
+    const warnings = results.warnings.filter(w => w.rule === 'id-reference-hidden');
+
+    expect(warnings).toHaveLength(1);
+    expect(warnings[0].description).toBe('aria-owns refers to a hidden element: "opt"');
+  });
+});
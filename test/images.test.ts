@@ -54,7 +54,7 @@ describe('Images Rule', () => {
     expect(pass).toBeDefined();
   });
 
-  it('should warn about empty alt text on non-decorative images', async () => {
+  it('should ask for review of empty alt text on non-decorative images', async () => {
     const html = `
       <html>
         <body>
@@ -68,8 +68,9 @@ describe('Images Rule', () => {
     
     const results = await imagesRule.check(document, window, { level: 'AA' });
     
-    const warning = results.warnings.find(w => w.rule === 'img-alt-decorative');
-    expect(warning).toBeDefined();
+    const review = results.incomplete.find(i => i.rule === 'img-alt-decorative');
+    expect(review).toBeDefined();
+    expect(results.warnings.find(w => w.rule === 'img-alt-decorative')).toBeUndefined();
   });
 
   it('should pass empty alt text on decorative images', async () => {
@@ -168,7 +169,7 @@ describe('Images Rule', () => {
 
     const results = await imagesRule.check(document, window, { level: 'AA' });
 
-    const warning = results.warnings.find(w => w.rule === 'background-image');
-    expect(warning).toBeUndefined();
+    const review = results.incomplete.find(i => i.rule === 'background-image');
+    expect(review).toBeUndefined();
   });
 });
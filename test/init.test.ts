@@ -48,6 +48,29 @@ describe('initWcagOverlay', () => {
     expect(mockRender).toHaveBeenCalledTimes(1);
   });
 
+  it('merges shared config under explicit overlay options', async () => {
+    const mockRender = jest.fn();
+
+    jest.doMock('react-dom/client', () => ({
+      createRoot: () => ({ render: mockRender }),
+    }));
+
+    const { initWcagOverlay } = await import('../src/react/init');
+    initWcagOverlay({ level: 'AAA', config: { level: 'A', preset: 'full', verbose: true } });
+
+    const props = mockRender.mock.calls[0][0].props;
+    expect(props.level).toBe('AAA');
+    expect(props.preset).toBe('full');
+    expect(props.options).toEqual({ level: 'A', preset: 'full', verbose: true });
+    expect(props).not.toHaveProperty('config');
+  });
+
+  it('rejects an invalid shared config', async () => {
+    const { initWcagOverlay } = await import('../src/react/init');
+
+    expect(() => initWcagOverlay({ config: { level: 'B' } as never })).toThrow('Invalid wcag-scanner config');
+  });
+
   it('does not mount twice if called repeatedly', async () => {
     const mockRender = jest.fn();
     const mockCreateRoot = jest.fn((container?: unknown) => ({ render: mockRender, container }));
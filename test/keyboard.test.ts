@@ -1,5 +1,6 @@
 import { JSDOM } from 'jsdom';
 import keyboardRule from '../src/rules/keyboard';
+import { scanHtml } from '../src/index';
 
 const createDoc = (html: string) => new JSDOM(html).window.document;
 const createWin = (html: string) => new JSDOM(html).window as unknown as Window;
@@ -102,4 +103,62 @@ describe('Keyboard Rule', () => {
       expect(warning).toBeUndefined();
     });
   });
+
+  describe('Focus indicators', () => {
+    it('should ask for review when the focus outline is removed', async () => {
+      const html = '<button style="outline-style: none">Save</button><a href="/home">Home</a>';
+      const results = await keyboardRule.check(createDoc(html), createWin(html), {});
+      const reviews = results.incomplete.filter(i => i.rule === 'focus-visible');
+      expect(reviews).toHaveLength(1);
+      expect(reviews[0]).toMatchObject({ element: { tagName: 'button' }, wcag: ['2.4.7'] });
+      expect(reviews[0].review).toBe('Tab to the element and check that a visible focus indicator appears');
+      expect(results.warnings.find(w => w.rule === 'focus-visible')).toBeUndefined();
+    });
+  });
+
+  describe('WCAG 2.2 checks', () => {
+    it('should warn about targets smaller than 24x24px', async () => {
+      const html = '<button style="width: 16px; height: 16px">x</button><button style="width: 44px; height: 44px">Menu</button>';
+      const results = await keyboardRule.check(createDoc(html), createWin(html), {});
+      const warnings = results.warnings.filter(w => w.rule === 'target-size');
+      expect(warnings).toHaveLength(1);
+      expect(warnings[0].description).toBe('Target is 16x16px, smaller than 24x24px');
+      expect(warnings[0].wcag).toEqual(['2.5.8']);
+      expect(results.passes.find(p => p.rule === 'target-size')).toBeDefined();
+    });
+
+    it('should ask for review of targets without a measurable size', async () => {
+      // jsdom has no layout, so a button sized by its text cannot be measured
+      const html = '<button>Save</button>';
+      const results = await keyboardRule.check(createDoc(html), createWin(html), {});
+      const reviews = results.incomplete.filter(i => i.rule === 'target-size');
+      expect(reviews).toHaveLength(1);
+      expect(reviews[0].description).toBe('Target size could not be measured');
+      expect(results.warnings.find(w => w.rule === 'target-size')).toBeUndefined();
+      expect(results.passes.find(p => p.rule === 'target-size')).toBeUndefined();
+    });
+
+    it('should only report WCAG 2.2 checks with the wcag22 standard', async () => {
+      const html = '<button style="width: 16px; height: 16px">x</button>';
+      const byDefault = await scanHtml(html, { rules: ['keyboard'], config: false });
+      const wcag22 = await scanHtml(html, { rules: ['keyboard'], config: false, standard: 'wcag22' });
+
+      expect(byDefault.warnings.find(w => w.rule === 'target-size')).toBeUndefined();
+      expect(wcag22.warnings.find(w => w.rule === 'target-size')).toBeDefined();
+    });
+
+    it('should not check the size of links inside text', async () => {
+      const html = '<p>Read the <a href="/terms" style="width: 10px; height: 10px">terms</a> first.</p>';
+      const results = await keyboardRule.check(createDoc(html), createWin(html), {});
+      expect(results.warnings.find(w => w.rule === 'target-size')).toBeUndefined();
+    });
+
+    it('should ask for review of sticky content that can cover focus', async () => {
+      const html = '<header style="position: sticky; top: 0">Site</header><dialog style="position: fixed">Modal</dialog>';
+      const results = await keyboardRule.check(createDoc(html), createWin(html), {});
+      const reviews = results.incomplete.filter(i => i.rule === 'focus-not-obscured');
+      expect(reviews).toHaveLength(1);
+      expect(reviews[0].description).toBe('Sticky content may cover focused elements');
+    });
+  });
 });
@@ -0,0 +1,118 @@
+import {
+  applyLevel,
+  describeClauses,
+  describeLevel,
+  getRuleMetadata,
+  inStandard,
+  ruleAppliesAt,
+} from '../src/metadata';
+import { ScanResults } from '../src/types';
+
+describe('rule metadata', () => {
+  it('should give each criterion its level and the rule the lowest of them', () => {
+    expect(getRuleMetadata('form-label')).toEqual({
+      rule: 'form-label',
+      criteria: [
+        { id: '1.3.1', level: 'A', version: '2.0' },
+        { id: '2.4.6', level: 'AA', version: '2.0' },
+        { id: '3.3.2', level: 'A', version: '2.0' },
+        { id: '4.1.2', level: 'A', version: '2.0' },
+      ],
+      level: 'A',
+      bestPractice: false,
+    });
+    expect(getRuleMetadata('heading-h1')).toMatchObject({ level: 'AA', bestPractice: true });
+    expect(getRuleMetadata('img-dimensions')).toEqual({ rule: 'img-dimensions', criteria: [], level: undefined, bestPractice: true });
+    expect(getRuleMetadata('my-custom-rule')).toBeUndefined();
+    expect(getRuleMetadata('constructor')).toBeUndefined();
+  });
+
+  it('should apply rules up to the selected level', () => {
+    expect(ruleAppliesAt('heading-h1', { level: 'A' })).toBe(false);
+    expect(ruleAppliesAt('heading-h1', { level: 'AA' })).toBe(true);
+    expect(ruleAppliesAt('link-purpose', { level: 'AA' })).toBe(false);
+    expect(ruleAppliesAt('link-purpose', { level: 'AAA' })).toBe(true);
+    expect(ruleAppliesAt('img-alt', { level: 'A' })).toBe(true);
+    expect(ruleAppliesAt('link-purpose', {})).toBe(false);
+  });
+
+  it('should leave out best practices only when asked', () => {
+    expect(ruleAppliesAt('img-dimensions', { level: 'A' })).toBe(true);
+    expect(ruleAppliesAt('img-dimensions', { level: 'A', bestPractices: false })).toBe(false);
+    expect(ruleAppliesAt('my-custom-rule', { level: 'A', bestPractices: false })).toBe(true);
+  });
+
+  it('should filter results and record their level', () => {
+    const results: ScanResults = {
+      passes: [{ rule: 'html-lang', description: 'Language set' }],
+      violations: [
+        { rule: 'heading-h1', impact: 'serious', description: 'No h1', wcag: ['2.4.6'] },
+        { rule: 'color-contrast', impact: 'serious', description: 'Low contrast', wcag: ['1.4.6'] },
+        { rule: 'custom', impact: 'minor', description: 'Custom' },
+      ],
+      warnings: [{ rule: 'img-dimensions', impact: 'minor', description: 'No size' }],
+      incomplete: [],
+    };
+
+    const levelA = applyLevel(results, { level: 'A' });
+    expect(levelA.violations.map(v => v.rule)).toEqual(['custom']);
+    expect(levelA.passes[0].level).toBe('A');
+    expect(levelA.warnings[0]).toMatchObject({ bestPractice: true });
+    expect(levelA.warnings[0].level).toBeUndefined();
+
+    const levelAAA = applyLevel(results, { level: 'AAA' });
+    expect(levelAAA.violations.map(v => [v.rule, v.level, v.bestPractice])).toEqual([
+      ['heading-h1', 'AA', true],
+      ['color-contrast', 'AAA', undefined],
+      ['custom', undefined, undefined],
+    ]);
+  });
+
+  it('should include criteria by the version that added or removed them', () => {
+    expect(inStandard('2.5.8', 'wcag21')).toBe(false);
+    expect(inStandard('2.5.8', 'wcag22')).toBe(true);
+    expect(inStandard('1.3.4', 'wcag20')).toBe(false);
+    expect(inStandard('1.3.4', 'en301549')).toBe(true);
+    expect(inStandard('4.1.1', 'wcag21')).toBe(true);
+    expect(inStandard('4.1.1', 'wcag22')).toBe(false);
+    expect(inStandard('9.9.9', 'wcag22')).toBe(false);
+  });
+
+  it('should select rules by standard', () => {
+    expect(ruleAppliesAt('target-size', {})).toBe(false);
+    expect(ruleAppliesAt('target-size', { standard: 'wcag22' })).toBe(true);
+    expect(ruleAppliesAt('accessible-authentication', { standard: 'wcag22', level: 'A' })).toBe(false);
+    // EN 301 549 and Section 508 stop at AA whatever level is asked for
+    expect(ruleAppliesAt('link-purpose', { standard: 'wcag22', level: 'AAA' })).toBe(true);
+    expect(ruleAppliesAt('link-purpose', { standard: 'en301549', level: 'AAA' })).toBe(false);
+    expect(ruleAppliesAt('img-alt', { standard: 'section508' })).toBe(true);
+    expect(ruleAppliesAt('my-custom-rule', { standard: 'section508' })).toBe(true);
+  });
+
+  it('should label results with the standard\'s clauses', () => {
+    const results: ScanResults = {
+      passes: [],
+      violations: [{ rule: 'img-alt', impact: 'critical', description: 'No alt', wcag: ['1.1.1'] }],
+      warnings: [{ rule: 'form-label', impact: 'minor', description: 'Check label', wcag: ['4.1.2', '1.3.1'] }],
+      incomplete: [],
+    };
+
+    const en = applyLevel(results, { standard: 'en301549' });
+    expect(en.violations[0].clauses).toEqual(['9.1.1.1']);
+    expect(describeClauses(en.violations[0], 'en301549')).toBe('EN 301 549 9.1.1.1');
+    expect(describeClauses(en.warnings[0], 'en301549')).toBe('EN 301 549 9.4.1.2, 9.1.3.1');
+
+    const section508 = applyLevel(results, { standard: 'section508' });
+    expect(describeClauses(section508.violations[0], 'section508')).toBe('Section 508 E205.4 (1.1.1)');
+
+    expect(applyLevel(results, {}).violations[0].clauses).toBeUndefined();
+    expect(describeClauses({}, 'wcag21')).toBe('');
+  });
+
+  it('should describe levels for reports', () => {
+    expect(describeLevel({ level: 'AA' })).toBe('AA');
+    expect(describeLevel({ level: 'A', bestPractice: true })).toBe('A (best practice)');
+    expect(describeLevel({ bestPractice: true })).toBe('Best practice');
+    expect(describeLevel({})).toBe('');
+  });
+});
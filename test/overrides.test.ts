@@ -0,0 +1,83 @@
+import { applyRuleOverrides } from '../src/overrides';
+import { WCAGScanner } from '../src/scanner';
+import { validateConfig } from '../src/config';
+import { ScanResults } from '../src/types';
+
+describe('applyRuleOverrides', () => {
+  const results: ScanResults = {
+    passes: [{ rule: 'html-lang', description: 'Language set' }],
+    violations: [
+      { rule: 'heading-skip', impact: 'moderate', description: 'Skipped h2 to h4' },
+      { rule: 'html-lang', impact: 'serious', description: 'Missing lang' },
+    ],
+    warnings: [
+      { rule: 'landmark-navigation', impact: 'minor', description: 'No nav landmark' },
+    ],
+    incomplete: [
+      { rule: 'focus-visible', impact: 'moderate', description: 'Outline removed', reason: 'r', review: 'Tab to it' },
+    ],
+  };
+
+  it('should return the results unchanged without overrides', () => {
+    expect(applyRuleOverrides(results)).toBe(results);
+    expect(applyRuleOverrides(results, {})).toBe(results);
+  });
+
+  it('should drop disabled rule ids from every list', () => {
+    const updated = applyRuleOverrides(results, {
+      'html-lang': 'off',
+      'landmark-navigation': { enabled: false },
+      'focus-visible': 'off',
+    });
+
+    expect(updated.passes).toHaveLength(0);
+    expect(updated.violations.map(v => v.rule)).toEqual(['heading-skip']);
+    expect(updated.warnings).toHaveLength(0);
+    expect(updated.incomplete).toHaveLength(0);
+  });
+
+  it('should move results between violations and warnings', () => {
+    const updated = applyRuleOverrides(results, {
+      'heading-skip': { type: 'warning' },
+      'landmark-navigation': { type: 'violation', impact: 'serious' },
+    });
+
+    expect(updated.violations.map(v => [v.rule, v.impact])).toEqual([
+      ['html-lang', 'serious'],
+      ['landmark-navigation', 'serious'],
+    ]);
+    expect(updated.warnings.map(w => w.rule)).toEqual(['heading-skip']);
+  });
+
+  it('should change the impact without mutating the input', () => {
+    const updated = applyRuleOverrides(results, { 'html-lang': { impact: 'critical' }, 'focus-visible': { impact: 'minor' } });
+
+    expect(updated.violations[1].impact).toBe('critical');
+    expect(updated.incomplete[0].impact).toBe('minor');
+    expect(results.violations[1].impact).toBe('serious');
+  });
+
+  it('should be applied by the scanner', async () => {
+    const scanner = new WCAGScanner({
+      rules: ['structure'],
+      ruleOverrides: {
+        'html-lang': 'off',
+        'heading-skip': { type: 'warning', impact: 'minor' },
+      },
+    });
+    await scanner.loadHTML('<html><head><title>T</title></head><body><main><h1>A</h1><h3>B</h3></main></body></html>');
+
+    const results = await scanner.scan();
+
+    expect(results.violations.some(v => v.rule === 'html-lang')).toBe(false);
+    expect(results.violations.some(v => v.rule === 'heading-skip')).toBe(false);
+    expect(results.warnings.find(w => w.rule === 'heading-skip')?.impact).toBe('minor');
+  });
+
+  it('should validate overrides in config files', () => {
+    expect(() => validateConfig({ ruleOverrides: { 'html-lang': 'warning' } }, 'test')).toThrow('"html-lang"');
+    expect(() => validateConfig({ ruleOverrides: { 'html-lang': { impact: 'high' } } }, 'test')).toThrow('impact');
+    expect(() => validateConfig({ ruleOverrides: { 'html-lang': { severity: 'minor' } } }, 'test')).toThrow('unknown key');
+    expect(validateConfig({ ruleOverrides: { 'html-lang': 'off' } }, 'test')).toEqual({ ruleOverrides: { 'html-lang': 'off' } });
+  });
+});
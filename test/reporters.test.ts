@@ -1,7 +1,11 @@
 import jsonReporter from '../src/reporters/json';
 import htmlReporter from '../src/reporters/html';
 import consoleReporter from '../src/reporters/console';
-import { ScanResults } from '../src/types';
+import sarifReporter, { FINGERPRINT_KEY } from '../src/reporters/sarif';
+import junitReporter from '../src/reporters/junit';
+import markdownReporter from '../src/reporters/markdown';
+import { ImpactLevel, ScanResults } from '../src/types';
+import { summarizeSite } from '../src/crawler';
 
 const mockResults: ScanResults = {
   violations: [
@@ -32,9 +36,16 @@ const mockResults: ScanResults = {
       description: 'Document language is specified: en',
     },
   ],
+  incomplete: [],
 };
 
-const emptyResults: ScanResults = { violations: [], warnings: [], passes: [] };
+const emptyResults: ScanResults = { violations: [], warnings: [], passes: [], incomplete: [] };
+
+const locatedResults: ScanResults = {
+  ...mockResults,
+  url: `file://${process.cwd()}/public/index.html`,
+  violations: [{ ...mockResults.violations[0], location: { line: 12, column: 5, endLine: 12, endColumn: 38 } }],
+};
 
 describe('JSON Reporter', () => {
   it('should return valid JSON', () => {
@@ -85,10 +96,18 @@ describe('JSON Reporter', () => {
       }],
       warnings: [],
       passes: [],
+      incomplete: [],
     };
     const report = JSON.parse(jsonReporter.format(results, {}));
     expect(report.violations[0].snippet.length).toBeLessThanOrEqual(303); // 300 + '...'
   });
+
+  it('should give located results a file:line:col source', () => {
+    const report = JSON.parse(jsonReporter.format(locatedResults, {}));
+    expect(report.violations[0].source).toBe('public/index.html:12:5');
+    expect(report.violations[0].location).toEqual({ line: 12, column: 5, endLine: 12, endColumn: 38 });
+    expect(report.warnings[0].source).toBeUndefined();
+  });
 });
 
 describe('HTML Reporter', () => {
@@ -104,6 +123,11 @@ describe('HTML Reporter', () => {
     expect(output).toContain('1');
   });
 
+  it('should show where each located result is in the source', () => {
+    const output = htmlReporter.format(locatedResults, {});
+    expect(output).toContain('<code>public/index.html:12:5</code>');
+  });
+
   it('should include violation description', () => {
     const output = htmlReporter.format(mockResults, {});
     expect(output).toContain('Image is missing alt text');
@@ -117,6 +141,313 @@ describe('HTML Reporter', () => {
   it('should handle empty results without error', () => {
     expect(() => htmlReporter.format(emptyResults, {})).not.toThrow();
   });
+
+  it('should render a site report with per-rule and per-page rollups', () => {
+    const site = summarizeSite(
+      { 'https://example.com/': mockResults, 'https://example.com/about': emptyResults },
+      { 'https://example.com/broken': 'Request failed with status 500' }
+    );
+    const output = htmlReporter.formatSite(site, {});
+
+    expect(output).toContain('WCAG Accessibility Site Report');
+    expect(output).toContain('<td>img-alt</td>');
+    expect(output).toContain('https://example.com/about');
+    expect(output).toContain('Request failed with status 500');
+    expect(output).toContain('Image is missing alt text');
+  });
+});
+
+describe('JUnit Reporter', () => {
+  it('should report each rule id as a test case and violations as failures', () => {
+    const output = junitReporter.format({ ...mockResults, url: 'https://example.com/' });
+
+    expect(output).toContain('<testsuites name="wcag-scanner" tests="3" failures="1" errors="0" skipped="0">');
+    expect(output).toContain('<testsuite name="https://example.com/" tests="3" failures="1"');
+    expect(output).toContain('<testcase classname="https://example.com/" name="html-lang"/>');
+    expect(output).toContain('<failure message="1 violation(s): Image is missing alt text" type="critical">');
+    expect(output).toContain('Snippet: &lt;img id=&quot;hero&quot; src=&quot;photo.jpg&quot;&gt;');
+    expect(output).toContain('Help: Add an alt attribute to the image');
+    expect(output).toContain('WCAG: 1.1.1');
+    expect(output).toContain('<system-out>[moderate] Element has a positive tabindex');
+    expect(output).not.toContain('<skipped');
+  });
+
+  it('should mark warning-only rules as skipped when asked', () => {
+    const output = junitReporter.formatAll([
+      { name: 'a.html', results: mockResults },
+      { name: 'b.html', results: emptyResults },
+    ], { junitWarnings: 'skipped' });
+
+    expect(output).toContain('<testsuites name="wcag-scanner" tests="3" failures="1" errors="0" skipped="1">');
+    expect(output).toContain('<skipped message="1 warning(s) need manual review"/>');
+    expect(output).toContain('<testsuite name="b.html" tests="0" failures="0" errors="0" skipped="0">');
+  });
+
+  it('should escape markup and drop characters XML does not allow', () => {
+    const output = junitReporter.format({
+      ...emptyResults,
+      violations: [{ ...mockResults.violations[0], description: 'Bad <b>"alt"</b> & \u0007bell' }],
+    });
+
+    expect(output).toContain('message="1 violation(s): Bad &lt;b&gt;&quot;alt&quot;&lt;/b&gt; &amp; bell"');
+  });
+});
+
+describe('Markdown Reporter', () => {
+  const withHelpUrl: ScanResults = {
+    ...mockResults,
+    violations: [{ ...mockResults.violations[0], helpUrl: 'https://example.com/img-alt' }],
+  };
+
+  it('should render a summary table and a collapsible section per rule', () => {
+    const output = markdownReporter.format(withHelpUrl);
+
+    expect(output).toContain('| 🔴 Critical | 1 | 0 |');
+    expect(output).toContain('| 🟡 Moderate | 0 | 1 |');
+    expect(output).toContain('| **Total** | **1** | **1** |');
+    expect(output).toContain('<summary>🔴 <code>img-alt</code> — 1 violation(s): Image is missing alt text</summary>');
+    expect(output).toContain('[Learn more](https://example.com/img-alt)');
+    expect(output).toContain('```html\n<img id="hero" src="photo.jpg">\n```');
+    expect(output).toContain('### Warnings');
+    expect(output).not.toContain('truncated');
+  });
+
+  it('should use a longer fence when the snippet contains backticks', () => {
+    const output = markdownReporter.format({
+      ...emptyResults,
+      violations: [{ ...mockResults.violations[0], snippet: '<code>```</code>' }],
+    });
+
+    expect(output).toContain('````html\n<code>```</code>\n````');
+  });
+
+  it('should truncate to the size budget and say what was left out', () => {
+    const many: ScanResults = {
+      ...emptyResults,
+      violations: Array.from({ length: 200 }, (_, index) => ({
+        ...mockResults.violations[0],
+        rule: `rule-${index % 20}`,
+        snippet: `<img id="image-${index}" src="photo.jpg">`,
+      })),
+    };
+    const output = markdownReporter.format(many, { markdownMaxLength: 4000 });
+
+    expect(output.length).toBeLessThanOrEqual(4000);
+    expect(output).toContain('| **Total** | **200** | **0** |');
+    expect(output).toMatch(/Report truncated to fit 4000 characters: \d+ result\(s\) and \d+ more rule\(s\) not shown/);
+    // Every section that was started is closed
+    expect(output.split('<details>').length).toBe(output.split('</details>').length);
+  });
+
+  it('should give each page a heading in a multi-page report', () => {
+    const output = markdownReporter.formatAll([
+      { name: 'index.html', results: withHelpUrl },
+      { name: 'about.html', results: emptyResults },
+    ]);
+
+    expect(output).toContain('2 pages scanned');
+    expect(output).toContain('### index.html\n\n#### Violations');
+    expect(output).toContain('### about.html\n\n✅ No violations or warnings.');
+  });
+});
+
+describe('SARIF Reporter', () => {
+  const located: ScanResults = {
+    ...locatedResults,
+    violations: [{ ...locatedResults.violations[0], helpUrl: 'https://example.com/img-alt' }],
+  };
+
+  it('should produce a SARIF 2.1.0 log with one rule per rule id', () => {
+    const log = JSON.parse(sarifReporter.format(located, {}));
+    const rules = log.runs[0].tool.driver.rules;
+
+    expect(log.version).toBe('2.1.0');
+    expect(rules.map((rule: { id: string }) => rule.id)).toEqual(['img-alt', 'tabindex-positive']);
+    expect(rules[0]).toMatchObject({
+      helpUri: 'https://example.com/img-alt',
+      help: { text: 'Add an alt attribute to the image' },
+      properties: { wcag: ['1.1.1'], tags: ['accessibility', 'wcag1.1.1'] },
+    });
+  });
+
+  it('should map impacts to levels and point at the source line', () => {
+    const log = JSON.parse(sarifReporter.format(located, {}));
+    const [violation, warning] = log.runs[0].results;
+
+    expect(violation.level).toBe('error');
+    expect(warning.level).toBe('warning');
+    expect(violation.locations[0].physicalLocation).toEqual({
+      artifactLocation: { uri: 'public/index.html' },
+      region: { startLine: 12, startColumn: 5, endLine: 12, endColumn: 38, snippet: { text: '<img id="hero" src="photo.jpg">' } },
+    });
+    expect(violation.partialFingerprints[FINGERPRINT_KEY]).toMatch(/^[0-9a-f]{40}$/);
+  });
+
+  it('should combine several pages into one run', () => {
+    const log = JSON.parse(sarifReporter.formatAll([located, { ...emptyResults, url: 'https://example.com/' }, located]));
+
+    expect(log.runs).toHaveLength(1);
+    expect(log.runs[0].results).toHaveLength(4);
+    expect(log.runs[0].tool.driver.rules).toHaveLength(2);
+  });
+
+  it('should give a rule the same default level whatever the page order', () => {
+    const withImpact = (impact: ImpactLevel): ScanResults => ({
+      ...emptyResults,
+      violations: [{ rule: 'img-alt', impact, description: 'Missing alt' }],
+    });
+    const defaultLevel = (pages: ScanResults[]) =>
+      JSON.parse(sarifReporter.formatAll(pages)).runs[0].tool.driver.rules[0].defaultConfiguration.level;
+
+    expect(defaultLevel([withImpact('minor'), withImpact('critical')])).toBe('error');
+    expect(defaultLevel([withImpact('critical'), withImpact('minor')])).toBe('error');
+  });
+});
+
+describe('Result levels', () => {
+  const leveledResults: ScanResults = {
+    ...mockResults,
+    violations: [{ ...mockResults.violations[0], level: 'A' }],
+    warnings: [{ ...mockResults.warnings[0], rule: 'heading-h1-multiple', level: 'AA', bestPractice: true }],
+  };
+
+  it('should show each result\'s level in text reports', () => {
+    const consoleOutput = consoleReporter.format(leveledResults, {});
+    expect(consoleOutput).toContain('Level: A\n');
+    expect(consoleOutput).toContain('Level: AA (best practice)');
+    expect(htmlReporter.format(leveledResults, {})).toContain('AA (best practice)');
+    expect(junitReporter.format(leveledResults, {})).toContain('Level: A');
+    expect(markdownReporter.format(leveledResults, {})).toContain('Level AA (best practice)');
+  });
+
+  it('should tag SARIF rules with their level', () => {
+    const log = JSON.parse(sarifReporter.format(leveledResults, {}));
+    const [imgAlt, heading] = log.runs[0].tool.driver.rules;
+
+    expect(imgAlt.properties).toMatchObject({ wcagLevel: 'A', tags: ['accessibility', 'wcag1.1.1', 'wcag-a'] });
+    expect(heading.properties).toMatchObject({ wcagLevel: 'AA', bestPractice: true });
+    expect(heading.properties.tags).toContain('best-practice');
+  });
+
+  it('should label results with the chosen standard\'s clauses', () => {
+    const clauseResults: ScanResults = {
+      ...mockResults,
+      violations: [{ ...mockResults.violations[0], level: 'A', clauses: ['9.1.1.1'] }],
+    };
+    const options = { standard: 'en301549' as const };
+
+    expect(consoleReporter.format(clauseResults, options)).toContain('Clauses: EN 301 549 9.1.1.1');
+    expect(htmlReporter.format(clauseResults, options)).toContain('Standard: EN 301 549');
+    expect(junitReporter.format(clauseResults, options)).toContain('Clauses: EN 301 549 9.1.1.1');
+    expect(markdownReporter.format(clauseResults, options)).toContain('EN 301 549 9.1.1.1');
+
+    const log = JSON.parse(sarifReporter.format(clauseResults, options));
+    expect(log.runs[0].tool.driver.rules[0].properties.clauses).toBe('EN 301 549 9.1.1.1');
+    expect(log.runs[0].properties.standard).toBe('en301549');
+  });
+});
+
+describe('Results that need review', () => {
+  const reviewResults: ScanResults = {
+    ...emptyResults,
+    incomplete: [{
+      rule: 'focus-visible',
+      impact: 'moderate',
+      description: 'Element removes the focus outline',
+      element: { tagName: 'button', id: 'save' },
+      snippet: '<button id="save" style="outline-style: none">',
+      wcag: ['2.4.7'],
+      reason: 'Another focus indicator may replace the outline',
+      review: 'Tab to the element and check that a visible focus indicator appears',
+    }],
+  };
+
+  it('should list them in their own section of every text report', () => {
+    const consoleOutput = consoleReporter.format(reviewResults, {});
+    expect(consoleOutput).toContain('Needs review: 1');
+    expect(consoleOutput).toContain('NEEDS REVIEW');
+    expect(consoleOutput).toContain('Reason: Another focus indicator may replace the outline');
+
+    const html = htmlReporter.format(reviewResults, {});
+    expect(html).toContain('data-tab="incomplete">Needs review');
+    expect(html).toContain('Tab to the element and check that a visible focus indicator appears');
+
+    const markdown = markdownReporter.format(reviewResults, {});
+    expect(markdown).toContain('### Needs review');
+    expect(markdown).toContain('— 1 to review: Element removes the focus outline');
+    expect(markdown).toContain('🔍 1 need(s) review');
+
+    const report = JSON.parse(jsonReporter.format(reviewResults, {}));
+    expect(report.summary.incomplete).toBe(1);
+    expect(report.incomplete[0]).toMatchObject({ rule: 'focus-visible', reason: 'Another focus indicator may replace the outline' });
+  });
+
+  it('should never fail JUnit or SARIF checks', () => {
+    const junit = junitReporter.format(reviewResults, { junitWarnings: 'skipped' });
+    expect(junit).toContain('failures="0"');
+    expect(junit).toContain('<skipped message="1 incomplete result(s) need manual review"/>');
+    expect(junit).toContain('Needs review: [moderate] Element removes the focus outline');
+
+    const log = JSON.parse(sarifReporter.format(reviewResults, {}));
+    expect(log.runs[0].results[0]).toMatchObject({ ruleId: 'focus-visible', kind: 'review', level: 'none' });
+    expect(log.runs[0].tool.driver.rules[0].help.text).toBe('Tab to the element and check that a visible focus indicator appears');
+  });
+});
+
+describe('Rule errors', () => {
+  const erroredResults: ScanResults = {
+    ...emptyResults,
+    url: 'https://example.org/',
+    errors: [{ rule: 'contrast', message: 'Cannot read color', stack: 'TypeError: Cannot read color\n    at check (contrast.ts:10:5)' }],
+  };
+  const banner = 'Scan incomplete: 1 rule(s) failed (contrast), so their checks are missing from these results';
+
+  it('should show a scan incomplete banner in every text report', () => {
+    const consoleOutput = consoleReporter.format(erroredResults, {});
+    expect(consoleOutput).toContain(`⚠ ${banner}`);
+    expect(consoleOutput).toContain('contrast: Cannot read color');
+    expect(consoleOutput).not.toContain('at check');
+    expect(consoleReporter.format(erroredResults, { verbose: true })).toContain('at check (contrast.ts:10:5)');
+
+    const html = htmlReporter.format(erroredResults, {});
+    expect(html).toContain(`<div class="scan-incomplete" role="alert">\n          <strong>⚠ ${banner}</strong>`);
+    expect(html).toContain('<code>contrast</code>: Cannot read color');
+
+    const markdown = markdownReporter.format(erroredResults, {});
+    expect(markdown).toContain(`> [!WARNING]\n> **${banner}**\n>\n> - \`contrast\`: Cannot read color`);
+    expect(markdown.indexOf('[!WARNING]')).toBeLessThan(markdown.indexOf('| Impact'));
+
+    const report = JSON.parse(jsonReporter.format(erroredResults, {}));
+    expect(report.summary).toMatchObject({ errors: 1, complete: false });
+    expect(report.errors).toEqual(erroredResults.errors);
+    expect(JSON.parse(jsonReporter.format(emptyResults, {})).summary).toMatchObject({ errors: 0, complete: true });
+  });
+
+  it('should report rule errors as JUnit errors and SARIF tool notifications', () => {
+    const junit = junitReporter.format(erroredResults, {});
+    expect(junit).toContain('<testsuites name="wcag-scanner" tests="1" failures="0" errors="1" skipped="0">');
+    expect(junit).toContain('<error message="Scan incomplete: rule contrast failed: Cannot read color" type="RuleError">TypeError: Cannot read color');
+
+    const log = JSON.parse(sarifReporter.format(erroredResults, {}));
+    expect(log.runs[0].invocations).toEqual([{
+      executionSuccessful: false,
+      toolExecutionNotifications: [{
+        level: 'error',
+        message: { text: 'Scan incomplete: rule contrast failed: Cannot read color' },
+        descriptor: { id: 'contrast' },
+        locations: [{ physicalLocation: { artifactLocation: { uri: 'https://example.org/' } } }],
+        exception: { message: 'Cannot read color' },
+        properties: { stack: 'TypeError: Cannot read color\n    at check (contrast.ts:10:5)' },
+      }],
+    }]);
+    expect(JSON.parse(sarifReporter.format(emptyResults, {})).runs[0].invocations).toBeUndefined();
+  });
+
+  it('should show the banner on site reports', () => {
+    const site = summarizeSite({ 'https://example.org/': erroredResults });
+
+    expect(htmlReporter.formatSite(site, {})).toContain(`<strong>⚠ ${banner}</strong>`);
+  });
 });
 
 describe('Console Reporter', () => {
@@ -135,6 +466,11 @@ describe('Console Reporter', () => {
     expect(() => consoleReporter.format(emptyResults, {})).not.toThrow();
   });
 
+  it('should print file:line:col for located results', () => {
+    const output = consoleReporter.format(locatedResults, {});
+    expect(output).toContain('public/index.html:12:5');
+  });
+
   it('should only include the passes section in verbose mode', () => {
     const terseOutput = consoleReporter.format(mockResults, {});
     const verboseOutput = consoleReporter.format(mockResults, { verbose: true });
@@ -175,7 +511,8 @@ describe('Console Reporter', () => {
           help: 'Warning help'
         }
       ],
-      passes: []
+      passes: [],
+      incomplete: []
     };
 
     const output = consoleReporter.format(results, { verbose: true });
@@ -0,0 +1,212 @@
+import fs from 'fs';
+import http from 'http';
+import os from 'os';
+import path from 'path';
+import { AddressInfo } from 'net';
+import { scanFile, scanHtml, ScriptError } from '../src/index';
+import { WCAGScanner } from '../src/scanner';
+import { loadResource } from '../src/resources';
+
+const LOW_CONTRAST_CSS = 'body { background-color: rgb(255, 255, 255); } p { color: rgb(200, 200, 200); }';
+
+const page = (href: string) =>
+  `<!DOCTYPE html><html lang="en"><head><title>T</title><link rel="stylesheet" href="${href}"></head>` +
+  '<body><p>low contrast text</p></body></html>';
+
+// rgb(200, 200, 200) on white
+const STYLESHEET_RATIO = '1.67:1';
+
+const contrastViolations = (results: { violations: Array<{ rule: string; description: string }> }) =>
+  results.violations.filter(violation => violation.rule === 'color-contrast' && violation.description.includes(STYLESHEET_RATIO));
+
+describe('stylesheet loading', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wcag-styles-'));
+    fs.mkdirSync(path.join(dir, 'css'));
+    fs.writeFileSync(path.join(dir, 'css', 'site.css'), LOW_CONTRAST_CSS);
+    fs.writeFileSync(path.join(dir, 'page.html'), page('css/site.css'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should read linked stylesheets from disk relative to the scanned file', async () => {
+    const withoutStyles = await scanFile(path.join(dir, 'page.html'), { rules: ['contrast'], config: false });
+    const withStyles = await scanFile(path.join(dir, 'page.html'), { rules: ['contrast'], config: false, loadStylesheets: true });
+
+    expect(contrastViolations(withoutStyles)).toHaveLength(0);
+    expect(contrastViolations(withStyles)).toHaveLength(1);
+  });
+
+  it('should resolve other URLs through the resource loader', async () => {
+    const resourceLoader = jest.fn((url: string) => url.endsWith('/app.css') ? LOW_CONTRAST_CSS : null);
+
+    const results = await scanHtml(page('/assets/app.css'), {
+      rules: ['contrast'],
+      config: false,
+      baseUrl: 'https://example.com/docs/',
+      resourceLoader,
+    });
+
+    expect(resourceLoader).toHaveBeenCalledWith('https://example.com/assets/app.css');
+    expect(contrastViolations(results)).toHaveLength(1);
+  });
+
+  it('should scan anyway when the resource loader never settles', async () => {
+    const results = await scanHtml(page('/hanging.css'), {
+      rules: ['contrast'],
+      config: false,
+      baseUrl: 'https://example.com/',
+      resourceLoader: () => new Promise<string>(() => undefined),
+      settle: { timeout: 50 },
+    });
+
+    expect(contrastViolations(results)).toHaveLength(0);
+  });
+
+  it('should scan without a stylesheet that cannot be loaded', async () => {
+    const results = await scanHtml(page('/missing.css'), {
+      rules: ['contrast'],
+      config: false,
+      baseUrl: 'https://example.com/',
+      loadStylesheets: true,
+    });
+
+    expect(contrastViolations(results)).toHaveLength(0);
+    await expect(loadResource(`file://${path.join(dir, 'nope.css')}`)).resolves.toBeNull();
+  });
+});
+
+describe('script execution', () => {
+  const SPA = (script: string) =>
+    '<!DOCTYPE html><html lang="en"><head><title>App</title></head><body><div id="root"></div>' +
+    `<script>${script}</script></body></html>`;
+
+  const RENDER_IMAGE = "document.getElementById('root').innerHTML = '<img src=\"logo.png\">';";
+
+  const imageViolations = (results: { violations: Array<{ rule: string }> }) =>
+    results.violations.filter(violation => violation.rule === 'img-alt');
+
+  it('should only run page scripts when enabled', async () => {
+    const withoutScripts = await scanHtml(SPA(RENDER_IMAGE), { rules: ['images'], config: false });
+    const withScripts = await scanHtml(SPA(RENDER_IMAGE), { rules: ['images'], config: false, runScripts: true });
+
+    expect(imageViolations(withoutScripts)).toHaveLength(0);
+    expect(imageViolations(withScripts)).toHaveLength(1);
+  });
+
+  it('should wait for a selector rendered after a delay', async () => {
+    const results = await scanHtml(SPA(`setTimeout(() => { ${RENDER_IMAGE} }, 100);`), {
+      rules: ['images'],
+      config: false,
+      runScripts: true,
+      settle: { selector: '#root img', timeout: 2000 },
+    });
+
+    expect(imageViolations(results)).toHaveLength(1);
+  });
+
+  it('should scan anyway once the settle timeout is reached', async () => {
+    const results = await scanHtml(SPA(''), {
+      rules: ['images'],
+      config: false,
+      runScripts: true,
+      settle: { selector: '#never', timeout: 50 },
+    });
+
+    expect(results.violations).toHaveLength(0);
+  });
+
+  it('should reject an invalid settle selector instead of scanning', async () => {
+    const settle = { selector: '#root >>> img', timeout: 2000 };
+    await expect(scanHtml(SPA(RENDER_IMAGE), { rules: ['images'], config: false, runScripts: true, settle }))
+      .rejects.toThrow('Invalid settle selector: #root >>> img');
+
+    const scanner = new WCAGScanner({ config: false, runScripts: true, settle });
+    const close = jest.spyOn(scanner, 'close');
+    await expect(scanner.loadHTML(SPA(RENDER_IMAGE))).rejects.toThrow('Invalid settle selector');
+    // Once before loading, once for the page that never settled
+    expect(close).toHaveBeenCalledTimes(2);
+  });
+
+  it('should abort on script errors unless they are ignored', async () => {
+    const html = SPA(`${RENDER_IMAGE} throw new TypeError('boom');`);
+
+    await expect(scanHtml(html, { rules: ['images'], config: false, runScripts: true })).rejects.toBeInstanceOf(ScriptError);
+    await expect(scanHtml(html, { rules: ['images'], config: false, runScripts: true })).rejects.toThrow('boom');
+
+    const results = await scanHtml(html, { rules: ['images'], config: false, runScripts: true, ignoreScriptErrors: true });
+    expect(imageViolations(results)).toHaveLength(1);
+  });
+
+  it('should close the page when its scripts throw', async () => {
+    const scanner = new WCAGScanner({ config: false, runScripts: true });
+    const close = jest.spyOn(scanner, 'close');
+
+    await expect(scanner.loadHTML(SPA("setInterval(() => undefined, 10); throw new Error('boom');")))
+      .rejects.toBeInstanceOf(ScriptError);
+    // Once before loading, once for the failed page
+    expect(close).toHaveBeenCalledTimes(2);
+  });
+
+  it('should block requests made by page scripts', async () => {
+    const requested: string[] = [];
+    const server = http.createServer((req, res) => {
+      requested.push(req.url || '/');
+      res.end('ok');
+    });
+    await new Promise<void>(resolve => server.listen(0, '127.0.0.1', resolve));
+    const base = `http://127.0.0.1:${(server.address() as AddressInfo).port}`;
+
+    try {
+      // Renders an image once all three requests have failed
+      const results = await scanHtml(SPA(`
+        let failures = 0;
+        const failed = () => { if (++failures === 3) { ${RENDER_IMAGE} } };
+        const xhr = new XMLHttpRequest();
+        xhr.open('GET', '${base}/xhr');
+        xhr.onerror = failed;
+        xhr.send();
+        new WebSocket('${base.replace('http', 'ws')}/socket').onerror = failed;
+        new EventSource('${base}/events').onerror = failed;
+      `), {
+        rules: ['images'],
+        config: false,
+        runScripts: true,
+        baseUrl: `${base}/`,
+        settle: { selector: '#root img', timeout: 2000 },
+      });
+
+      expect(imageViolations(results)).toHaveLength(1);
+      expect(requested).toEqual([]);
+    } finally {
+      await new Promise(resolve => server.close(resolve));
+    }
+  });
+
+  it('should load external scripts from disk for files but not for web pages', async () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wcag-scripts-'));
+    try {
+      fs.writeFileSync(path.join(dir, 'app.js'), RENDER_IMAGE);
+      const html = '<!DOCTYPE html><html lang="en"><head><title>App</title></head><body><div id="root"></div>' +
+        '<script src="app.js"></script></body></html>';
+      fs.writeFileSync(path.join(dir, 'index.html'), html);
+
+      const fromFile = await scanFile(path.join(dir, 'index.html'), { rules: ['images'], config: false, runScripts: true });
+      const fromWeb = await scanHtml(html.replace('app.js', `file://${path.join(dir, 'app.js')}`), {
+        rules: ['images'],
+        config: false,
+        runScripts: true,
+        baseUrl: 'https://example.com/',
+      });
+
+      expect(imageViolations(fromFile)).toHaveLength(1);
+      expect(imageViolations(fromWeb)).toHaveLength(0);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
@@ -1,3 +1,4 @@
+import { RuleFailureError } from '../src/ruleErrors';
 import { WCAGScanner } from '../src/scanner';
 import path from 'path';
 import fs from 'fs';
@@ -65,7 +66,8 @@ describe('WCAGScanner', () => {
               snippet: '<img src="test.jpg">'
             }],
             passes: [],
-            warnings: []
+            warnings: [],
+            incomplete: []
           };
         }
       });
@@ -75,6 +77,22 @@ describe('WCAGScanner', () => {
       expect(results.violations.length).toBeGreaterThan(0);
       expect(results.violations[0].rule).toBe('img-alt');
     });
+
+    it('should record the source location of each element a rule reports', async () => {
+      const scanner = new WCAGScanner({ rules: ['images'], config: false });
+      await scanner.loadHTML('<main>\n  <img src="a.jpg">\n    <img src="a.jpg">\n</main>');
+
+      const results = await scanner.scan();
+      const locations = results.violations
+        .filter(violation => violation.rule === 'img-alt')
+        .map(violation => violation.location);
+
+      // Identical snippets are told apart because rules record the element they inspected
+      expect(locations).toEqual([
+        expect.objectContaining({ line: 2, column: 3, endLine: 2 }),
+        expect.objectContaining({ line: 3, column: 5, endLine: 3 }),
+      ]);
+    });
   });
 
   describe('rules', () => {
@@ -112,7 +130,8 @@ describe('WCAGScanner', () => {
               impact: 'moderate',
               description: 'Background image rule ran',
               element: { tagName: 'div' }
-            }]
+            }],
+            incomplete: []
           };
         }
       });
@@ -134,7 +153,8 @@ describe('WCAGScanner', () => {
               element: { tagName: 'h1' }
             }],
             violations: [],
-            warnings: []
+            warnings: [],
+            incomplete: []
           };
         }
       });
@@ -144,6 +164,34 @@ describe('WCAGScanner', () => {
       expect(results.passes.some(result => result.rule === 'structure')).toBe(true);
     });
 
+    it('should only report rule ids that apply at the selected level', async () => {
+      const html = '<html lang="en"><head><title>T</title></head><body><main><p><a href="/pricing">Read more</a></p></main></body></html>';
+
+      const levelA = new WCAGScanner({ rules: ['structure'], level: 'A', config: false });
+      await levelA.loadHTML(html);
+      const resultsA = await levelA.scan();
+
+      const levelAAA = new WCAGScanner({ rules: ['structure'], level: 'AAA', config: false });
+      await levelAAA.loadHTML(html);
+      const resultsAAA = await levelAAA.scan();
+
+      expect(resultsA.violations.some(v => v.rule === 'heading-h1')).toBe(false);
+      expect(resultsA.violations.some(v => v.rule === 'link-purpose')).toBe(false);
+      expect(resultsA.passes.find(p => p.rule === 'html-lang')?.level).toBe('A');
+      expect(resultsAAA.violations.find(v => v.rule === 'heading-h1')).toMatchObject({ level: 'AA', bestPractice: true });
+      expect(resultsAAA.violations.find(v => v.rule === 'link-purpose')?.level).toBe('AAA');
+    });
+
+    it('should leave out best practices when bestPractices is false', async () => {
+      const scanner = new WCAGScanner({ rules: ['structure'], bestPractices: false, config: false });
+      await scanner.loadHTML('<html lang="en"><head><title>T</title></head><body><h2>No h1</h2></body></html>');
+      const results = await scanner.scan();
+
+      expect(results.violations.some(v => v.rule === 'heading-h1')).toBe(false);
+      expect(results.violations.some(v => v.bestPractice)).toBe(false);
+      expect(results.passes.some(p => p.rule === 'html-lang')).toBe(true);
+    });
+
     it('should register and use custom rules', async () => {
       const scanner = new WCAGScanner();
       await scanner.loadHTML('<html><body><h1>Test</h1></body></html>');
@@ -158,7 +206,8 @@ describe('WCAGScanner', () => {
               element: { tagName: 'h1' }
             }],
             violations: [],
-            warnings: []
+            warnings: [],
+            incomplete: []
           };
         }
       });
@@ -172,6 +221,19 @@ describe('WCAGScanner', () => {
       expect(results.passes[0].rule).toBe('custom-rule');
     });
 
+    it('should accept custom rules that return no incomplete results', async () => {
+      const scanner = new WCAGScanner({ config: false, rules: ['legacy-rule'] });
+      await scanner.loadHTML('<html><body><h1>Test</h1></body></html>');
+      scanner.registerRule('legacy-rule', {
+        check: async () => ({ passes: [], violations: [], warnings: [] })
+      });
+
+      const results = await scanner.scan();
+
+      expect(results.incomplete).toEqual([]);
+      expect(results.errors).toEqual([]);
+    });
+
     it('should return early when the rules directory is missing', async () => {
       const scanner = new WCAGScanner();
       const existsSpy = jest.spyOn(fs, 'existsSync').mockReturnValue(false);
@@ -184,6 +246,18 @@ describe('WCAGScanner', () => {
       expect((scanner as any).rules.size).toBe(0);
     });
 
+    it('should find only rule modules in the rules directory', async () => {
+      const logSpy = jest.spyOn(console, 'log').mockImplementation(() => undefined);
+      const scanner = new WCAGScanner();
+
+      await scanner.loadRules();
+
+      expect([...(scanner as any).rules.keys()].sort()).toEqual([
+        'aria', 'backgroundImages', 'contrast', 'forms', 'idReferences', 'images', 'keyboard', 'structure',
+      ]);
+      expect(logSpy).not.toHaveBeenCalled();
+    });
+
     it('should load valid rules and continue past invalid rule modules', async () => {
       const validRulePath = path.join(rulesDir, '__tempValidRule.js');
       const invalidRulePath = path.join(rulesDir, '__tempInvalidRule.js');
@@ -207,7 +281,7 @@ describe('WCAGScanner', () => {
       expect(errorSpy).toHaveBeenCalledWith(expect.stringContaining('Error loading rule from __tempBrokenRule.js:'), expect.any(Error));
     });
 
-    it('should continue scanning when one rule throws', async () => {
+    it('should continue scanning when one rule throws and report the error', async () => {
       const scanner = new WCAGScanner({ rules: ['ok-rule', 'bad-rule'] });
       const errorSpy = jest.spyOn(console, 'error').mockImplementation(() => undefined);
       await scanner.loadHTML('<html><body><h1>Test</h1></body></html>');
@@ -216,7 +290,8 @@ describe('WCAGScanner', () => {
         check: async () => ({
           passes: [{ rule: 'ok-rule', description: 'ok' }],
           violations: [],
-          warnings: []
+          warnings: [],
+          incomplete: []
         })
       });
 
@@ -230,7 +305,29 @@ describe('WCAGScanner', () => {
 
       expect(results.passes).toHaveLength(1);
       expect(results.passes[0].rule).toBe('ok-rule');
-      expect(errorSpy).toHaveBeenCalledWith('Error running rule bad-rule:', expect.any(Error));
+      expect(results.errors).toEqual([{
+        rule: 'bad-rule',
+        message: 'rule failed',
+        stack: expect.stringContaining('Error: rule failed'),
+      }]);
+      expect(scanner.getResults().errors).toHaveLength(1);
+      expect(errorSpy).not.toHaveBeenCalled();
+    });
+
+    it('should fail the scan when a rule throws and failOnRuleError is set', async () => {
+      const scanner = new WCAGScanner({ rules: ['bad-rule'], failOnRuleError: true });
+      await scanner.loadHTML('<html><body><h1>Test</h1></body></html>');
+      scanner.registerRule('bad-rule', {
+        check: async () => {
+          throw 'not an Error';
+        }
+      });
+
+      const error = await scanner.scan().catch(caught => caught);
+
+      expect(error).toBeInstanceOf(RuleFailureError);
+      expect(error.message).toBe('1 rule(s) failed: bad-rule: not an Error');
+      expect(error.errors).toEqual([{ rule: 'bad-rule', message: 'not an Error' }]);
     });
   });
 });
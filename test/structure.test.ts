@@ -111,6 +111,29 @@ describe('Structure Rule', () => {
       const results = await structureRule.check(createDoc(html), createWin(html), {});
       expect(results.warnings.some(w => w.rule === 'landmark-complementary-name')).toBe(true);
     });
+
+    it('should not count aria-labelledby pointing at a missing id as a landmark name', async () => {
+      const html = `
+        <html lang="en">
+          <head><title>T</title></head>
+          <body>
+            <main><h1>T</h1></main>
+            <aside aria-labelledby="missing"></aside>
+            <aside aria-label="Related links"></aside>
+          </body>
+        </html>
+      `;
+      const results = await structureRule.check(createDoc(html), createWin(html), {});
+      const warnings = results.warnings.filter(w => w.rule === 'landmark-complementary-name');
+      expect(warnings).toHaveLength(1);
+      expect(warnings[0].snippet).toContain('aria-labelledby="missing"');
+    });
+
+    it('should treat a heading with only a named image as non-empty', async () => {
+      const html = '<html lang="en"><head><title>T</title></head><body><h1><img src="logo.png" alt="Acme"></h1></body></html>';
+      const results = await structureRule.check(createDoc(html), createWin(html), {});
+      expect(results.violations.some(v => v.rule === 'heading-empty')).toBe(false);
+    });
   });
 
   describe('Document structure', () => {
@@ -277,4 +300,37 @@ describe('Structure Rule', () => {
       expect(results.warnings.some(w => w.rule === 'table-header-scope')).toBe(true);
     });
   });
+  describe('AAA checks', () => {
+    it('should flag links whose text does not describe their purpose', async () => {
+      const html = `
+        <html lang="en"><head><title>T</title></head><body><main><h1>T</h1>
+          <a href="/pricing">Read more</a>
+          <a href="/docs">Click here!</a>
+          <a href="/pricing">Read more about pricing</a>
+          <a href="/hidden" hidden>More</a>
+        </main></body></html>
+      `;
+      const results = await structureRule.check(createDoc(html), createWin(html), {});
+      const violations = results.violations.filter(v => v.rule === 'link-purpose');
+
+      expect(violations.map(v => v.description)).toEqual([
+        'Link text does not describe its purpose: "Read more"',
+        'Link text does not describe its purpose: "Click here!"',
+      ]);
+      expect(violations[0].wcag).toEqual(['2.4.9']);
+    });
+
+    it('should warn about abbreviations without an expansion', async () => {
+      const html = `
+        <html lang="en"><head><title>T</title></head><body><main><h1>T</h1>
+          <p><abbr title="Web Content Accessibility Guidelines">WCAG</abbr> and <abbr>ARIA</abbr></p>
+        </main></body></html>
+      `;
+      const results = await structureRule.check(createDoc(html), createWin(html), {});
+
+      expect(results.passes.some(p => p.description === 'Abbreviation "WCAG" has an expansion')).toBe(true);
+      expect(results.warnings.find(w => w.rule === 'abbr-expansion')?.description)
+        .toBe('Abbreviation "ARIA" has no expansion');
+    });
+  });
 });
@@ -0,0 +1,110 @@
+import { JSDOM } from 'jsdom';
+import { applySuppressions, collectSuppressions, locateResultElement } from '../src/suppressions';
+import { WCAGScanner } from '../src/scanner';
+import { ScanResults } from '../src/types';
+
+describe('suppressions', () => {
+  const load = (html: string): Document => new JSDOM(html).window.document;
+
+  describe('collectSuppressions', () => {
+    it('should read disable comments and ignore attributes', () => {
+      const document = load(`
+        <body>
+          <!-- wcag-scanner-disable-next-line img-alt, img-dimensions -->
+          <img id="logo" src="logo.png">
+          <!-- wcag-scanner-disable-next-line -->
+          <p id="all">Text</p>
+          <section id="cards" data-wcag-ignore="color-contrast"><p>Card</p></section>
+          <div id="everything" data-wcag-ignore></div>
+          <!-- an ordinary comment -->
+          <!-- wcag-scanner-disable-next-lines -->
+          <p id="typo">Text</p>
+        </body>
+      `);
+
+      const suppressions = collectSuppressions(document);
+
+      expect(suppressions.map(s => [s.element.id, s.rules, s.source])).toEqual([
+        ['logo', ['img-alt', 'img-dimensions'], 'comment'],
+        ['all', null, 'comment'],
+        ['cards', ['color-contrast'], 'attribute'],
+        ['everything', null, 'attribute'],
+      ]);
+    });
+  });
+
+  describe('locateResultElement', () => {
+    it('should find elements by id or by their reported snippet', () => {
+      const document = load('<body><img src="a.png"><img src="b.png" alt=""><div id="x"></div></body>');
+
+      expect(locateResultElement({ rule: 'r', description: '', element: { id: 'x' } }, document)?.id).toBe('x');
+      expect(locateResultElement({ rule: 'r', description: '', snippet: '<img src="b.png" alt="">' }, document))
+        .toBe(document.querySelectorAll('img')[1]);
+      expect(locateResultElement({ rule: 'r', description: '', snippet: '<img src="b.p...' }, document))
+        .toBe(document.querySelectorAll('img')[1]);
+      expect(locateResultElement({ rule: 'r', description: '', snippet: '<img src="c.png">' }, document)).toBeNull();
+    });
+
+    it('should prefer the recorded XPath and not guess between identical snippets', () => {
+      const document = load('<body><p><img src="a.png"></p><p><img src="a.png"></p></body>');
+      const second = document.querySelectorAll('img')[1];
+
+      expect(locateResultElement({ rule: 'r', description: '', xpath: '/html/body/p[2]/img', snippet: '<img src="a.png">' }, document))
+        .toBe(second);
+      expect(locateResultElement({ rule: 'r', description: '', snippet: '<img src="a.png">' }, document)).toBeNull();
+    });
+  });
+
+  describe('applySuppressions', () => {
+    it('should move matching violations and warnings to the suppressed list', () => {
+      const document = load(`
+        <body>
+          <div data-wcag-ignore="img-alt"><span><img src="nested.png"></span></div>
+          <img src="other.png">
+        </body>
+      `);
+      const results: ScanResults = {
+        passes: [],
+        violations: [
+          { rule: 'img-alt', impact: 'critical', description: 'nested', snippet: '<img src="nested.png">' },
+          { rule: 'img-alt', impact: 'critical', description: 'other', snippet: '<img src="other.png">' },
+        ],
+        warnings: [
+          { rule: 'img-dimensions', impact: 'minor', description: 'nested', snippet: '<img src="nested.png">' },
+        ],
+        incomplete: [],
+      };
+
+      const updated = applySuppressions(results, document);
+
+      expect(updated.violations.map(v => v.description)).toEqual(['other']);
+      expect(updated.warnings).toHaveLength(1);
+      expect(updated.suppressed).toEqual([
+        expect.objectContaining({ rule: 'img-alt', description: 'nested', type: 'violation', suppressedBy: 'attribute' }),
+      ]);
+    });
+
+    it('should leave results untouched when the page has no suppressions', () => {
+      const results: ScanResults = { passes: [], violations: [], warnings: [], incomplete: [] };
+      expect(applySuppressions(results, load('<p>Hi</p>'))).toBe(results);
+    });
+
+    it('should be applied by the scanner', async () => {
+      const scanner = new WCAGScanner({ rules: ['images'], config: false });
+      await scanner.loadHTML(`
+        <html lang="en"><body>
+          <!-- wcag-scanner-disable-next-line img-alt -->
+          <img src="decorative.png">
+          <img src="content.png">
+        </body></html>
+      `);
+
+      const results = await scanner.scan();
+
+      expect(results.violations.filter(v => v.rule === 'img-alt').map(v => v.snippet)).toEqual(['<img src="content.png">']);
+      expect(results.suppressed).toEqual([
+        expect.objectContaining({ rule: 'img-alt', snippet: '<img src="decorative.png">', suppressedBy: 'comment' }),
+      ]);
+    });
+  });
+});
@@ -0,0 +1,63 @@
+import { JSDOM } from 'jsdom';
+import { fontSizeInPixels, fontWeightValue, isLargeText } from '../src/utils/typography';
+
+describe('typography', () => {
+  const setup = (html: string) => {
+    const dom = new JSDOM(html);
+    const window = dom.window as unknown as Window;
+    const find = (selector: string) => dom.window.document.querySelector(selector)!;
+    return { window, find };
+  };
+
+  it('should resolve absolute and relative font sizes through ancestors', () => {
+    const { window, find } = setup(`
+      <div style="font-size: 12pt"><p id="em" style="font-size: 1.5em">a</p><span id="inherit">b</span></div>
+      <div style="font-size: large"><p id="percent" style="font-size: 150%">c</p></div>
+      <p id="mm" style="font-size: 10mm">d</p>
+      <p id="smaller" style="font-size: smaller">e</p>
+    `);
+
+    expect(fontSizeInPixels(find('#em'), window)).toBe(24);
+    expect(fontSizeInPixels(find('#inherit'), window)).toBe(16);
+    expect(fontSizeInPixels(find('#percent'), window)).toBe(27);
+    expect(fontSizeInPixels(find('#mm'), window)).toBeCloseTo(37.8, 1);
+    expect(fontSizeInPixels(find('#smaller'), window)).toBeCloseTo(13.33, 2);
+  });
+
+  it('should resolve rem against the root element', () => {
+    const { window, find } = setup(`
+      <html style="font-size: 62.5%"><body style="font-size: 3em"><p style="font-size: 2.4rem">a</p></body></html>
+    `);
+
+    expect(fontSizeInPixels(find('p'), window)).toBe(24);
+  });
+
+  it('should use default heading sizes when no size is computed', () => {
+    const { window, find } = setup('<h2>Title</h2><h4>Small title</h4>');
+
+    expect(fontSizeInPixels(find('h2'), window)).toBe(24);
+    expect(fontSizeInPixels(find('h4'), window)).toBe(16);
+  });
+
+  it('should resolve inherited, bold and relative font weights', () => {
+    const { window, find } = setup(`
+      <strong><span id="strong">a</span></strong>
+      <div style="font-weight: 300"><span id="bolder" style="font-weight: bolder">b</span></div>
+      <b><span id="lighter" style="font-weight: lighter">c</span></b>
+      <p id="numeric" style="font-weight: 600">d</p>
+    `);
+
+    expect(fontWeightValue(find('#strong'), window)).toBe(700);
+    expect(fontWeightValue(find('#bolder'), window)).toBe(400);
+    expect(fontWeightValue(find('#lighter'), window)).toBe(400);
+    expect(fontWeightValue(find('#numeric'), window)).toBe(600);
+  });
+
+  it('should classify large text as 18pt, or 14pt bold', () => {
+    expect(isLargeText(24, 400)).toBe(true);
+    expect(isLargeText(23.9, 400)).toBe(false);
+    expect(isLargeText((14 * 4) / 3, 700)).toBe(true);
+    expect(isLargeText((14 * 4) / 3, 600)).toBe(false);
+    expect(isLargeText(18.5, 700)).toBe(false);
+  });
+});